@@ -217,6 +217,15 @@ t_aggspec::agg_str() const {
         case AGGTYPE_STANDARD_DEVIATION: {
             return "stddev";
         }
+        case AGGTYPE_WEIGHTED_SUM: {
+            return "weighted_sum";
+        }
+        case AGGTYPE_PERCENTILE: {
+            return "percentile";
+        }
+        case AGGTYPE_COUNT_WHERE: {
+            return "count_where";
+        }
         default: {
             PSP_COMPLAIN_AND_ABORT("Unknown agg type");
             return "unknown";
@@ -343,7 +352,8 @@ t_aggspec::get_output_specs(const t_schema& schema) const {
             rval[0].m_type = coltype;
             return rval;
         }
-        case AGGTYPE_COUNT: {
+        case AGGTYPE_COUNT:
+        case AGGTYPE_COUNT_WHERE: {
             return mk_col_name_type_vec(name(), DTYPE_INT64);
         }
         case AGGTYPE_MEAN:
@@ -358,7 +368,9 @@ t_aggspec::get_output_specs(const t_schema& schema) const {
         case AGGTYPE_SCALED_ADD:
         case AGGTYPE_SCALED_MUL:
         case AGGTYPE_VARIANCE:
-        case AGGTYPE_STANDARD_DEVIATION: {
+        case AGGTYPE_STANDARD_DEVIATION:
+        case AGGTYPE_WEIGHTED_SUM:
+        case AGGTYPE_PERCENTILE: {
             return mk_col_name_type_vec(name(), DTYPE_FLOAT64);
         }
         case AGGTYPE_UDF_COMBINER:
@@ -448,6 +448,12 @@ str_to_aggtype(const std::string& str) {
         return t_aggtype::AGGTYPE_COUNT;
     } else if (str == "weighted mean" || str == "weighted_mean") {
         return t_aggtype::AGGTYPE_WEIGHTED_MEAN;
+    } else if (str == "weighted sum" || str == "weighted_sum") {
+        return t_aggtype::AGGTYPE_WEIGHTED_SUM;
+    } else if (str == "percentile") {
+        return t_aggtype::AGGTYPE_PERCENTILE;
+    } else if (str == "count where" || str == "count_where") {
+        return t_aggtype::AGGTYPE_COUNT_WHERE;
     } else if (str == "unique") {
         return t_aggtype::AGGTYPE_UNIQUE;
     } else if (str == "any") {
@@ -464,7 +470,7 @@ str_to_aggtype(const std::string& str) {
         return t_aggtype::AGGTYPE_DOMINANT;
     } else if (str == "first by index" || str == "first") {
         return t_aggtype::AGGTYPE_FIRST;
-    } else if (str == "last by index") {
+    } else if (str == "last by index" || str == "last by" || str == "max by") {
         return t_aggtype::AGGTYPE_LAST_BY_INDEX;
     } else if (str == "last minus first") {
         return t_aggtype::AGGTYPE_LAST_MINUS_FIRST;
@@ -204,6 +204,9 @@ t_config::setup(const std::vector<std::string>& detail_columns,
             case AGGTYPE_DISTINCT_LEAF:
             case AGGTYPE_VARIANCE:
             case AGGTYPE_STANDARD_DEVIATION:
+            case AGGTYPE_WEIGHTED_SUM:
+            case AGGTYPE_PERCENTILE:
+            case AGGTYPE_COUNT_WHERE:
                 m_has_pkey_agg = true;
                 break;
             default:
@@ -83,7 +83,11 @@ t_dtree_ctx::build_aggregates() {
 
         std::vector<std::shared_ptr<const t_column>> icolumns;
         for (const auto& d : deps) {
-            icolumns.push_back(tbl->get_const_column(d.name()));
+            // Scalar dependencies (e.g. the `p` of `percentile`) are
+            // parameters, not columns.
+            if (d.type() == DEPTYPE_COLUMN) {
+                icolumns.push_back(tbl->get_const_column(d.name()));
+            }
         }
 
         auto output_col = m_aggregates->get_column(aggspec.name());
@@ -77,7 +77,10 @@ extract_aggregate(const t_aggspec& aggspec, const t_column* aggcol,
         case AGGTYPE_DISTINCT_COUNT:
         case AGGTYPE_DISTINCT_LEAF:
         case AGGTYPE_VARIANCE:
-        case AGGTYPE_STANDARD_DEVIATION: {
+        case AGGTYPE_STANDARD_DEVIATION:
+        case AGGTYPE_WEIGHTED_SUM:
+        case AGGTYPE_PERCENTILE:
+        case AGGTYPE_COUNT_WHERE: {
             t_tscalar rval = aggcol->get_scalar(ridx);
             return rval;
         } break;
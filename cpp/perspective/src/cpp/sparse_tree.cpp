@@ -1079,6 +1079,80 @@ t_stree::update_agg_table(t_uindex nidx, t_agg_update_info& info,
                 dst->set_valid(dst_ridx, valid);
                 new_value.set(nr / dr);
             } break;
+            case AGGTYPE_WEIGHTED_SUM: {
+                old_value.set(dst->get_scalar(dst_ridx));
+                auto pkeys = get_pkeys(nidx);
+
+                double sum = 0;
+                std::vector<t_tscalar> values;
+                std::vector<t_tscalar> weights;
+
+                read_column_from_gstate(gstate, expression_master_table,
+                    spec.get_dependencies()[0].name(), pkeys, values);
+
+                read_column_from_gstate(gstate, expression_master_table,
+                    spec.get_dependencies()[1].name(), pkeys, weights);
+
+                auto weights_it = weights.begin();
+                auto values_it = values.begin();
+
+                for (; weights_it != weights.end() && values_it != values.end();
+                     ++weights_it, ++values_it) {
+                    if (weights_it->is_valid() && values_it->is_valid()
+                        && !weights_it->is_nan() && !values_it->is_nan()) {
+                        sum += weights_it->to_double() * values_it->to_double();
+                    }
+                }
+
+                new_value.set(sum);
+                dst->set_scalar(dst_ridx, new_value);
+            } break;
+            case AGGTYPE_PERCENTILE: {
+                old_value.set(dst->get_scalar(dst_ridx));
+                auto pkeys = get_pkeys(nidx);
+                std::vector<double> values;
+
+                read_column_from_gstate(gstate, expression_master_table,
+                    spec.get_dependencies()[0].name(), pkeys, values, false);
+
+                // Linearly interpolated between the closest ranks, as
+                // numpy's default `percentile()`.
+                double p = std::min(std::max(
+                    spec.get_dependencies()[1].imm().to_double(), 0.0), 100.0);
+                if (values.empty()) {
+                    dst->set_valid(dst_ridx, false);
+                } else {
+                    std::sort(values.begin(), values.end());
+                    double rank = (p / 100.0) * (values.size() - 1);
+                    auto lower = static_cast<t_uindex>(std::floor(rank));
+                    auto upper = std::min(
+                        static_cast<t_uindex>(std::ceil(rank)),
+                        static_cast<t_uindex>(values.size() - 1));
+                    double value = values[lower]
+                        + (rank - lower) * (values[upper] - values[lower]);
+
+                    new_value.set(value);
+                    dst->set_scalar(dst_ridx, new_value);
+                }
+            } break;
+            case AGGTYPE_COUNT_WHERE: {
+                old_value.set(dst->get_scalar(dst_ridx));
+                auto pkeys = get_pkeys(nidx);
+                std::vector<t_tscalar> conditions;
+
+                read_column_from_gstate(gstate, expression_master_table,
+                    spec.get_dependencies()[1].name(), pkeys, conditions);
+
+                std::int64_t count = 0;
+                for (const auto& condition : conditions) {
+                    if (condition.is_valid() && condition.as_bool()) {
+                        count++;
+                    }
+                }
+
+                new_value.set(count);
+                dst->set_scalar(dst_ridx, new_value);
+            } break;
             case AGGTYPE_UNIQUE: {
                 auto pkeys = get_pkeys(nidx);
                 old_value.set(dst->get_scalar(dst_ridx));
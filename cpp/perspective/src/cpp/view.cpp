@@ -1383,7 +1383,8 @@ View<CTX_T>::_map_aggregate_types(
         if (agg.name() == name) {
             switch (agg.agg()) {
                 case AGGTYPE_DISTINCT_COUNT:
-                case AGGTYPE_COUNT: {
+                case AGGTYPE_COUNT:
+                case AGGTYPE_COUNT_WHERE: {
                     return "integer";
                 } break;
                 case AGGTYPE_MEAN:
@@ -1392,7 +1393,9 @@ View<CTX_T>::_map_aggregate_types(
                 case AGGTYPE_PCT_SUM_PARENT:
                 case AGGTYPE_PCT_SUM_GRAND_TOTAL:
                 case AGGTYPE_VARIANCE:
-                case AGGTYPE_STANDARD_DEVIATION: {
+                case AGGTYPE_STANDARD_DEVIATION:
+                case AGGTYPE_WEIGHTED_SUM:
+                case AGGTYPE_PERCENTILE: {
                     return "float";
                 } break;
                 default: {
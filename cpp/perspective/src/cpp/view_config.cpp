@@ -11,6 +11,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 #include <perspective/view_config.h>
+#include <cstdlib>
 
 namespace perspective {
 
@@ -314,13 +315,10 @@ t_view_config::fill_aggspecs(std::shared_ptr<t_schema> schema) {
                 // use `UNIQUE`
                 agg_type = t_aggtype::AGGTYPE_UNIQUE;
             } else if (m_aggregates.count(column) > 0) {
-                auto col = m_aggregates.at(column);
-                if (col.at(0) == "weighted mean") {
-                    dependencies.push_back(t_dep(col.at(1), DEPTYPE_COLUMN));
-                    agg_type = AGGTYPE_WEIGHTED_MEAN;
-                } else {
-                    agg_type = str_to_aggtype(col.at(0));
-                }
+                m_aggspecs.push_back(
+                    parse_aggspec(column, m_aggregates.at(column)));
+                m_aggregate_names.push_back(column);
+                continue;
             } else {
                 t_dtype dtype = schema->get_dtype(column);
                 agg_type = _get_default_aggregate(dtype);
@@ -381,35 +379,80 @@ t_view_config::get_aggregate_index(const std::string& column) const {
 void
 t_view_config::make_aggspec(const std::string& column,
     const std::vector<std::string>& aggregate, t_dtype dtype) {
-    t_aggtype agg_type;
-    t_aggspec aggspec;
+    if (m_column_only) {
+        std::vector<t_dep> dependencies{t_dep(column, DEPTYPE_COLUMN)};
+        m_aggspecs.push_back(
+            t_aggspec(column, t_aggtype::AGGTYPE_ANY, dependencies));
+    } else {
+        m_aggspecs.push_back(parse_aggspec(column, aggregate));
+    }
+
+    m_aggregate_names.push_back(column);
+}
+
+t_aggspec
+t_view_config::parse_aggspec(const std::string& column,
+    const std::vector<std::string>& aggregate) const {
+    const std::string& name = aggregate.at(0);
+    t_aggtype agg_type = str_to_aggtype(name);
 
     // Maximum of 2 dependencies, based on the aggregate type
     std::vector<t_dep> dependencies{t_dep(column, DEPTYPE_COLUMN)};
     dependencies.reserve(2);
 
-    if (m_column_only) {
-        agg_type = t_aggtype::AGGTYPE_ANY;
-    } else {
-        if (aggregate.at(0) == "weighted mean") {
-            dependencies.push_back(t_dep(aggregate.at(1), DEPTYPE_COLUMN));
-            agg_type = AGGTYPE_WEIGHTED_MEAN;
-        } else {
-            agg_type = str_to_aggtype(aggregate.at(0));
+    auto param = [&]() -> const std::string& {
+        if (aggregate.size() < 2) {
+            std::stringstream ss;
+            ss << "Aggregate `" << name << "` requires a parameter"
+               << std::endl;
+            PSP_COMPLAIN_AND_ABORT(ss.str());
         }
-    }
 
-    if (agg_type == AGGTYPE_FIRST || agg_type == AGGTYPE_LAST_BY_INDEX
-        || agg_type == AGGTYPE_LAST_MINUS_FIRST) {
-        dependencies.push_back(t_dep("psp_okey", DEPTYPE_COLUMN));
-        aggspec = t_aggspec(
-            column, column, agg_type, dependencies, SORTTYPE_ASCENDING);
-    } else {
-        aggspec = t_aggspec(column, agg_type, dependencies);
-    }
+        return aggregate.at(1);
+    };
+
+    switch (agg_type) {
+        case AGGTYPE_WEIGHTED_MEAN:
+        case AGGTYPE_WEIGHTED_SUM:
+        case AGGTYPE_COUNT_WHERE: {
+            // The weights column, or for `count where` a boolean (expression)
+            // column.
+            dependencies.push_back(t_dep(param(), DEPTYPE_COLUMN));
+            return t_aggspec(column, agg_type, dependencies);
+        }
+        case AGGTYPE_PERCENTILE: {
+            const std::string& value = param();
+            char* end = nullptr;
+            double p = std::strtod(value.c_str(), &end);
+            if (value.empty() || *end != '\0' || !(p >= 0 && p <= 100)) {
+                std::stringstream ss;
+                ss << "Aggregate `" << name << "` requires a percentile "
+                   << "between 0 and 100, found `" << value << "`"
+                   << std::endl;
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
 
-    m_aggspecs.push_back(aggspec);
-    m_aggregate_names.push_back(column);
+            dependencies.push_back(t_dep(mktscalar(p)));
+            return t_aggspec(column, agg_type, dependencies);
+        }
+        case AGGTYPE_FIRST:
+        case AGGTYPE_LAST_BY_INDEX:
+        case AGGTYPE_LAST_MINUS_FIRST: {
+            // `last by` and `max by` are `last by index`, ordered by their
+            // parameter column rather than by the primary key.
+            if (name == "last by" || name == "max by") {
+                dependencies.push_back(t_dep(param(), DEPTYPE_COLUMN));
+            } else {
+                dependencies.push_back(t_dep("psp_okey", DEPTYPE_COLUMN));
+            }
+
+            return t_aggspec(
+                column, column, agg_type, dependencies, SORTTYPE_ASCENDING);
+        }
+        default: {
+            return t_aggspec(column, agg_type, dependencies);
+        }
+    }
 }
 
-} // end namespace perspective
\ No newline at end of file
+} // end namespace perspective
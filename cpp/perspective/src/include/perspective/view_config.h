@@ -170,6 +170,19 @@ private:
     void make_aggspec(const std::string& column,
         const std::vector<std::string>& aggregate, t_dtype dtype);
 
+    /**
+     * @brief Create the `t_aggspec` for an aggregate of the form
+     * `["name"]` or `["name", "param"]`, adding the dependencies its
+     * parameter requires - a column for e.g. `["weighted mean", "col"]`, or a
+     * scalar for `["percentile", "95"]`.
+     *
+     * @param column
+     * @param aggregate
+     * @return t_aggspec
+     */
+    t_aggspec parse_aggspec(const std::string& column,
+        const std::vector<std::string>& aggregate) const;
+
     // containers for primitive data that does not need transformation into
     // abstractions
     std::vector<std::string> m_row_pivots;
@@ -72,6 +72,11 @@ export class HTMLPerspectiveViewerDatagridPluginElement extends HTMLElement {
         return getDefaultConfig.call(this);
     }
 
+    /** opt-in to column labels, descriptions and units in headers */
+    get supports_column_attributes() {
+        return true;
+    }
+
     async draw(view) {
         return await draw.call(this, view);
     }
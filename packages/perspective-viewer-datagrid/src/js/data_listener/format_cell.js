@@ -11,6 +11,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 import { FormatterCache } from "./formatter_cache";
+import { DEFAULT_TIME_ZONE_SYMBOL } from "../model";
 
 const FORMAT_CACHE = new FormatterCache();
 
@@ -29,15 +30,20 @@ export function format_cell(
     plugins = {},
     use_table_schema = false
 ) {
-    if (val === null) {
-        return "-";
-    }
-
     const type =
         (use_table_schema && this._table_schema[title]) ||
         this._schema[title] ||
         "string";
-    const plugin = plugins[title] || {};
+    let plugin = plugins[title] || {};
+    const time_zone = plugins[DEFAULT_TIME_ZONE_SYMBOL];
+    if (type === "datetime" && time_zone && plugin.timeZone === undefined) {
+        plugin = { ...plugin, timeZone: time_zone };
+    }
+
+    if (val === null) {
+        return (type === "boolean" && plugin?.null_text) || "-";
+    }
+
     const is_numeric = type === "integer" || type === "float";
     if (is_numeric && plugin?.number_fg_mode === "bar") {
         const a = Math.max(
@@ -67,6 +73,12 @@ export function format_cell(
         const anchor = document.createElement("i");
         anchor.textContent = val;
         return anchor;
+    } else if (plugin?.format === "text" && type === "boolean") {
+        return val
+            ? plugin.true_text ?? "true"
+            : plugin.false_text ?? "false";
+    } else if (plugin?.format === "checkbox" && type === "boolean") {
+        return val ? "check_box" : "check_box_outline_blank";
     } else {
         const formatter = FORMAT_CACHE.get(type, plugin);
         return formatter ? formatter.format(val) : val;
@@ -29,8 +29,17 @@ export default function getDefaultConfig() {
                 number_bg_mode: "disabled",
                 fixed: column_type === "float" ? 2 : 0,
             };
+        } else if (column_type === "bool") {
+            type_default = {
+                true_color: this.model._pos_fg_color[0],
+                false_color: this.model._neg_fg_color[0],
+                null_color: this.model._color[0],
+                true_text: "true",
+                false_text: "false",
+                null_text: "-",
+            };
         } else {
-            // date, datetime, string, boolean
+            // date, datetime, string
             type_default = {
                 color: this.model._color[0],
                 bg_color: this.model._color[0],
@@ -11,3 +11,9 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 export const PRIVATE_PLUGIN_SYMBOL = Symbol("Plugin Symbol");
+
+/**
+ * Key of the viewer's default time zone in the column config record stored at
+ * `PRIVATE_PLUGIN_SYMBOL`, used for datetime columns with no `timeZone`.
+ */
+export const DEFAULT_TIME_ZONE_SYMBOL = Symbol("Default Time Zone Symbol");
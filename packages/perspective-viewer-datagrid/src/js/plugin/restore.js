@@ -12,7 +12,7 @@
 
 import { restore_column_size_overrides } from "../model/column_overrides.js";
 import { toggle_edit_mode, toggle_scroll_lock } from "../model/toolbar.js";
-import { PRIVATE_PLUGIN_SYMBOL } from "../model";
+import { PRIVATE_PLUGIN_SYMBOL, DEFAULT_TIME_ZONE_SYMBOL } from "../model";
 import { make_color_record } from "../color_utils.js";
 
 /**
@@ -53,6 +53,12 @@ export function restore(token) {
                 col_config.color = make_color_record(col_config.color);
             }
 
+            for (const key of ["true_color", "false_color", "null_color"]) {
+                if (col_config?.[key]) {
+                    col_config[key] = make_color_record(col_config[key]);
+                }
+            }
+
             if (Object.keys(col_config).length === 0) {
                 delete token.columns[col];
             }
@@ -69,5 +75,14 @@ export function restore(token) {
 
     const datagrid = this.regular_table;
     restore_column_size_overrides.call(this, overrides, true);
-    datagrid[PRIVATE_PLUGIN_SYMBOL] = token.columns;
+
+    // The viewer's time zone is only sent with column styles, so is retained
+    // across a `restore()` of plugin state alone.
+    const columns = token.columns || {};
+    columns[DEFAULT_TIME_ZONE_SYMBOL] =
+        "time_zone" in token
+            ? token.time_zone ?? undefined
+            : datagrid[PRIVATE_PLUGIN_SYMBOL]?.[DEFAULT_TIME_ZONE_SYMBOL];
+
+    datagrid[PRIVATE_PLUGIN_SYMBOL] = columns;
 }
@@ -43,6 +43,12 @@ export function save() {
                 config.color = config.color[0];
             }
 
+            for (const key of ["true_color", "false_color", "null_color"]) {
+                if (config?.[key]) {
+                    config[key] = config[key][0];
+                }
+            }
+
             token.columns[col] = config;
         }
 
@@ -10,6 +10,8 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+import { PRIVATE_PLUGIN_SYMBOL } from "../model";
+
 function get_psp_type(metadata) {
     if (metadata.x >= 0) {
         return this._column_types[metadata.x];
@@ -53,7 +55,10 @@ export function style_selected_column(regularTable, selectedColumn) {
             });
             let zipped = titles.map((title, i) => [title, editBtns[i]]);
             zipped.forEach(([title, editBtn]) => {
-                let open = title.innerText === selectedColumn;
+                const metadata = regularTable.getMeta(title);
+                const column_name =
+                    metadata?.column_header?.[this._config.split_by.length];
+                let open = column_name === selectedColumn;
                 title.classList.toggle("psp-menu-open", open);
                 editBtn.classList.toggle("psp-menu-open", open);
             });
@@ -158,5 +163,33 @@ function style_column_header_row(regularTable, col_headers, is_menu_row) {
             regularTable._column_sizes?.override[metadata.size_key] !==
                 undefined
         );
+
+        if (!is_menu_row && !is_corner) {
+            style_column_label(regularTable, td, column_name);
+        }
+    }
+}
+
+/**
+ * Replace a column header's text with its display label and units, if the
+ * `<perspective-viewer>` has provided any for this column.  The header's
+ * metadata still refers to the column by name.
+ */
+function style_column_label(regularTable, td, column_name) {
+    const attributes = regularTable[PRIVATE_PLUGIN_SYMBOL]?.[column_name];
+    const label = attributes?.label ?? column_name;
+    const text = attributes?.units ? `${label} (${attributes.units})` : label;
+    const node = Array.from(td.childNodes).find(
+        (x) => x.nodeType === Node.TEXT_NODE
+    );
+
+    if (node && text !== undefined && node.nodeValue !== text) {
+        node.nodeValue = text;
+    }
+
+    if (attributes?.description) {
+        td.setAttribute("title", attributes.description);
+    } else {
+        td.removeAttribute("title");
     }
 }
@@ -10,14 +10,27 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+import {
+    rgbaToRgb,
+    infer_foreground_from_background,
+} from "../../color_utils.js";
+
 export function cell_style_boolean(plugin, td, metadata) {
-    const [hex] =
+    const [hex, r, g, b] =
         metadata.user === true
-            ? this._pos_fg_color
+            ? plugin?.true_color ?? this._pos_fg_color
             : metadata.user === false
-            ? this._neg_fg_color
-            : ["", 0, 0, 0, ""];
+            ? plugin?.false_color ?? this._neg_fg_color
+            : plugin?.null_color ?? ["", 0, 0, 0, ""];
 
-    td.style.backgroundColor = "";
-    td.style.color = hex;
+    if (plugin?.bool_color_mode === "background" && hex !== "") {
+        const source = this._plugin_background;
+        td.style.color = infer_foreground_from_background(
+            rgbaToRgb([r, g, b, 1], source)
+        );
+        td.style.backgroundColor = hex;
+    } else {
+        td.style.backgroundColor = "";
+        td.style.color = hex;
+    }
 }
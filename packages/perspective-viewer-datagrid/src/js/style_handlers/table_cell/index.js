@@ -54,7 +54,9 @@ export function table_cell_style_listener(regularTable) {
 
             td.classList.toggle(
                 "psp-bool-type",
-                type === "boolean" && metadata.user !== null
+                type === "boolean" &&
+                    metadata.user !== null &&
+                    plugin?.format !== "text"
             );
 
             const is_th = td.tagName === "TH";
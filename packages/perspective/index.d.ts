@@ -55,7 +55,12 @@ declare module "@finos/perspective" {
         | "sum not null"
         | "unique"
         | "var"
-        | ["weighted mean", ColumnName];
+        | ["weighted mean", ColumnName]
+        | ["weighted sum", ColumnName]
+        | ["percentile", number]
+        | ["max by", ColumnName]
+        | ["last by", ColumnName]
+        | ["count where", ColumnName];
 
     export type FilterOp =
         | "<"
@@ -180,6 +185,11 @@ declare module "@finos/perspective" {
         get_index(): Promise<string | null>;
         get_limit(): Promise<number | null>;
         get_num_views(): Promise<number>;
+        get_features(): Promise<TableFeatures>;
+    };
+
+    export type TableFeatures = {
+        aggregates: { [type in Type]?: Array<string> };
     };
 
     /**** perspective ****/
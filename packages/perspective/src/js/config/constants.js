@@ -55,6 +55,7 @@ const NUMBER_AGGREGATES = [
     "count",
     "distinct count",
     "dominant",
+    "first",
     "first by index",
     "last by index",
     "last minus first",
@@ -75,6 +76,12 @@ const NUMBER_AGGREGATES = [
     "sum not null",
     "unique",
     "var",
+    "weighted mean",
+    "weighted sum",
+    "percentile",
+    "max by",
+    "last by",
+    "count where",
 ];
 
 const STRING_AGGREGATES = [
@@ -83,22 +90,54 @@ const STRING_AGGREGATES = [
     "distinct count",
     "distinct leaf",
     "dominant",
+    "first",
     "first by index",
     "join",
     "last by index",
     "last",
+    "last by",
+    "max by",
+    "count where",
+    "median",
+    "unique",
+];
+
+const DATETIME_AGGREGATES = [
+    "any",
+    "count",
+    "distinct count",
+    "distinct leaf",
+    "dominant",
+    "first",
+    "first by index",
+    "high",
+    "join",
+    "last by index",
+    "last",
+    "last by",
+    "max by",
+    "count where",
+    "low",
+    "median",
     "unique",
 ];
 
 const BOOLEAN_AGGREGATES = [
+    "and",
     "any",
     "count",
     "distinct count",
     "distinct leaf",
     "dominant",
+    "first",
     "first by index",
     "last by index",
     "last",
+    "last by",
+    "max by",
+    "count where",
+    "mean",
+    "or",
     "unique",
 ];
 
@@ -121,8 +160,8 @@ export const TYPE_AGGREGATES = {
     float: NUMBER_AGGREGATES,
     integer: NUMBER_AGGREGATES,
     boolean: BOOLEAN_AGGREGATES,
-    datetime: STRING_AGGREGATES,
-    date: STRING_AGGREGATES,
+    datetime: DATETIME_AGGREGATES,
+    date: DATETIME_AGGREGATES,
 };
 
 export const FILTER_OPERATORS = {
@@ -1267,6 +1267,19 @@ export default function (Module) {
         return this.limit;
     };
 
+    /**
+     * Returns the features supported by this {@link module:perspective~table},
+     * so clients can offer only what the engine can compute.
+     *
+     * @returns {Object} An object whose `aggregates` field maps each column
+     * type to the names of the aggregates supported for that type.
+     */
+    table.prototype.get_features = function () {
+        return {
+            aggregates: defaults.TYPE_AGGREGATES,
+        };
+    };
+
     /**
      * Remove all rows in this {@link module:perspective~table} while preserving
      * the schema and construction options.
@@ -1747,6 +1760,15 @@ export default function (Module) {
         config.split_by = config.split_by || [];
         config.aggregates = config.aggregates || {};
         config.filter = config.filter || [];
+
+        // Aggregate parameters are parsed by the engine as strings, e.g. the
+        // `95` in `["percentile", 95]`.
+        config.aggregates = Object.fromEntries(
+            Object.entries(config.aggregates).map(([name, agg]) => [
+                name,
+                Array.isArray(agg) ? agg.map(String) : agg,
+            ])
+        );
         config.sort = config.sort || [];
         config.expressions = config.expressions || [];
 
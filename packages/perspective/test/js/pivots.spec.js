@@ -201,6 +201,211 @@ const std = (nums) => {
             table.delete();
         });
 
+        test("['z'], weighted sum", async function () {
+            var table = await perspective.table(data2);
+            var view = await table.view({
+                group_by: ["z"],
+                aggregates: { x: ["weighted sum", "y"] },
+                columns: ["x"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], x: 17 },
+                { __ROW_PATH__: [false], x: 10 },
+                { __ROW_PATH__: [true], x: 7 },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], percentile", async function () {
+            var table = await perspective.table(data2);
+            var view = await table.view({
+                group_by: ["z"],
+                aggregates: { x: ["percentile", 75] },
+                columns: ["x"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], x: 3.25 },
+                { __ROW_PATH__: [false], x: 3.5 },
+                { __ROW_PATH__: [true], x: 2.5 },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], percentile 0 and 100 are min and max", async function () {
+            var table = await perspective.table(data2);
+            var view = await table.view({
+                group_by: ["z"],
+                aggregates: { x: ["percentile", 0], y: ["percentile", 100] },
+                columns: ["x", "y"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], x: 1, y: 2 },
+                { __ROW_PATH__: [false], x: 2, y: 2 },
+                { __ROW_PATH__: [true], x: 1, y: 2 },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], percentile with a non-numeric parameter throws", async function () {
+            expect.assertions(1);
+            var table = await perspective.table(data2);
+            try {
+                await table.view({
+                    group_by: ["z"],
+                    aggregates: { x: ["percentile", "abc"] },
+                    columns: ["x"],
+                });
+            } catch (error) {
+                expect(error.message).toEqual(
+                    "Abort(): Aggregate `percentile` requires a percentile between 0 and 100, found `abc`\n"
+                );
+            }
+
+            table.delete();
+        });
+
+        test("['z'], percentile out of range throws", async function () {
+            expect.assertions(1);
+            var table = await perspective.table(data2);
+            try {
+                await table.view({
+                    group_by: ["z"],
+                    aggregates: { x: ["percentile", 150] },
+                    columns: ["x"],
+                });
+            } catch (error) {
+                expect(error.message).toEqual(
+                    "Abort(): Aggregate `percentile` requires a percentile between 0 and 100, found `150`\n"
+                );
+            }
+
+            table.delete();
+        });
+
+        test("['z'], max by", async function () {
+            var table = await perspective.table(data_7);
+            var view = await table.view({
+                group_by: ["z"],
+                aggregates: { x: ["max by", "w"] },
+                columns: ["x"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], x: 4 },
+                { __ROW_PATH__: [false], x: 4 },
+                { __ROW_PATH__: [true], x: 3 },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], last by", async function () {
+            var table = await perspective.table(data_7);
+            var view = await table.view({
+                group_by: ["z"],
+                aggregates: { y: ["last by", "w"] },
+                columns: ["y"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], y: "d" },
+                { __ROW_PATH__: [false], y: "d" },
+                { __ROW_PATH__: [true], y: "c" },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], count where", async function () {
+            var table = await perspective.table(data2);
+            var view = await table.view({
+                group_by: ["z"],
+                expressions: ['// cond\n"x" > 1'],
+                aggregates: { x: ["count where", "cond"] },
+                columns: ["x"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], x: 3 },
+                { __ROW_PATH__: [false], x: 2 },
+                { __ROW_PATH__: [true], x: 1 },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            expect(await view.schema()).toEqual({ x: "integer" });
+            view.delete();
+            table.delete();
+        });
+
+        test("['g'], mean of a boolean column is its fraction true", async function () {
+            var table = await perspective.table({
+                g: ["a", "a", "a", "b", "b"],
+                b: [true, true, false, null, false],
+            });
+            var view = await table.view({
+                group_by: ["g"],
+                aggregates: { b: "mean" },
+                columns: ["b"],
+            });
+            var answer = {
+                __ROW_PATH__: [[], ["a"], ["b"]],
+                b: [0.5, 2 / 3, 0],
+            };
+            let result = await view.to_columns();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], count where with no matching rows", async function () {
+            var table = await perspective.table(data2);
+            var view = await table.view({
+                group_by: ["z"],
+                expressions: ['// cond\n"x" > 10'],
+                aggregates: { x: ["count where", "cond"] },
+                columns: ["x"],
+            });
+            var answer = [
+                { __ROW_PATH__: [], x: 0 },
+                { __ROW_PATH__: [false], x: 0 },
+                { __ROW_PATH__: [true], x: 0 },
+            ];
+            let result = await view.to_json();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
+        test("['z'], count where with a split_by", async function () {
+            var table = await perspective.table(data2);
+            var view = await table.view({
+                group_by: ["z"],
+                split_by: ["y"],
+                expressions: ['// cond\n"x" > 1'],
+                aggregates: { x: ["count where", "cond"] },
+                columns: ["x"],
+            });
+            var answer = {
+                __ROW_PATH__: [[], [false], [true]],
+                "1|x": [1, 1, 0],
+                "2|x": [2, 1, 1],
+            };
+            let result = await view.to_columns();
+            expect(result).toEqual(answer);
+            view.delete();
+            table.delete();
+        });
+
         test("['z'], weighted mean on a table created from schema should return valid values after update", async function () {
             const table = await perspective.table({
                 x: "integer",
@@ -1710,6 +1915,42 @@ const std = (nums) => {
         });
     });
 
+    test.describe("Aggregate catalog", function () {
+        const PARAMETERS = {
+            "weighted mean": "w",
+            "weighted sum": "w",
+            percentile: 50,
+            "max by": "w",
+            "last by": "w",
+            "count where": "cond",
+        };
+
+        test("Every aggregate in `get_features()` creates a view", async function () {
+            const table = await perspective.table(data_8);
+
+            const schema = await table.schema();
+            const features = table.get_features();
+            for (const [column, type] of Object.entries(schema)) {
+                for (const name of features.aggregates[type]) {
+                    const aggregate =
+                        name in PARAMETERS ? [name, PARAMETERS[name]] : name;
+                    const view = await table.view({
+                        group_by: ["y"],
+                        expressions: ['// cond\n"x" > 1'],
+                        aggregates: { [column]: aggregate },
+                        columns: [column],
+                    });
+
+                    const result = await view.to_columns();
+                    expect(result[column].length).toEqual(5);
+                    view.delete();
+                }
+            }
+
+            table.delete();
+        });
+    });
+
     test.describe("Aggregates with nulls", function () {
         test("mean", async function () {
             var table = await perspective.table([
@@ -59,6 +59,16 @@ def validate_split_by(split_by):
     return _validate_pivots(split_by)
 
 
+_PARAMETERIZED_AGGREGATES = (
+    "weighted mean",
+    "weighted sum",
+    "percentile",
+    "max by",
+    "last by",
+    "count where",
+)
+
+
 def validate_aggregates(aggregates):
     if aggregates is None:
         return {}
@@ -70,11 +80,14 @@ def validate_aggregates(aggregates):
                 if v not in Aggregate.options():
                     raise PerspectiveError("Unrecognized aggregate: %s", v)
             elif isinstance(v, list):
-                # Parse weighted mean aggregate in ["weighted mean", "COLUMN"]
-                if len(v) == 2 and v[0] == "weighted mean":
+                # Parse parameterized aggregates, e.g. ["weighted mean", "COLUMN"]
+                # or ["percentile", 95], whose parameter the engine reads as a
+                # string.
+                if len(v) == 2 and v[0] in _PARAMETERIZED_AGGREGATES:
+                    aggregates[k] = [v[0], str(v[1])]
                     continue
                 raise PerspectiveError(
-                    "Unrecognized aggregate in incorrect syntax for weighted mean: %s - Syntax should be: ['weighted mean', 'COLUMN']",
+                    "Unrecognized aggregate in incorrect syntax: %s - Syntax should be: ['weighted mean', 'COLUMN']",
                     v,
                 )
             else:
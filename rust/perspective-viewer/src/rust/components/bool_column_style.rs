@@ -0,0 +1,335 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen::*;
+use web_sys::*;
+use yew::prelude::*;
+use yew::*;
+
+use super::containers::radio_list::RadioList;
+use super::containers::radio_list_item::RadioListItem;
+use super::form::color_selector::*;
+use super::modal::{ModalLink, SetModalLink};
+use super::style::LocalStyle;
+use crate::config::*;
+use crate::utils::WeakScope;
+use crate::*;
+
+/// Which of the three possible values of a boolean cell a text or color
+/// setting applies to.
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub enum BoolState {
+    True,
+    False,
+    Null,
+}
+
+pub enum BoolColumnStyleMsg {
+    Reset(BoolColumnStyleConfig),
+    FormatEnabled(bool),
+    FormatChanged(BoolFormatMode),
+    TextChanged(BoolState, String),
+    ColorModeEnabled(bool),
+    ColorModeChanged(BoolColorMode),
+    ColorChanged(BoolState, String),
+}
+
+#[derive(Properties)]
+pub struct BoolColumnStyleProps {
+    #[cfg_attr(test, prop_or_default)]
+    pub config: Option<BoolColumnStyleConfig>,
+
+    #[cfg_attr(test, prop_or_default)]
+    pub default_config: BoolColumnStyleDefaultConfig,
+
+    #[prop_or_default]
+    pub on_change: Callback<BoolColumnStyleConfig>,
+
+    #[prop_or_default]
+    pub weak_link: WeakScope<BoolColumnStyle>,
+}
+
+impl ModalLink<BoolColumnStyle> for BoolColumnStyleProps {
+    fn weak_link(&self) -> &'_ WeakScope<BoolColumnStyle> {
+        &self.weak_link
+    }
+}
+
+impl PartialEq for BoolColumnStyleProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+/// The `ColumnStyle` component stores its UI state privately in its own struct,
+/// rather than its props (which has two version of this data itself, the
+/// JSON serializable config record and the defaults record).
+pub struct BoolColumnStyle {
+    config: BoolColumnStyleConfig,
+    default_config: BoolColumnStyleDefaultConfig,
+}
+
+impl BoolColumnStyle {
+    /// When this config has changed, we must signal the wrapper element.
+    fn dispatch_config(&self, ctx: &Context<Self>) {
+        ctx.props().on_change.emit(self.config.clone());
+    }
+
+    fn text_field(&mut self, state: BoolState) -> &mut Option<String> {
+        match state {
+            BoolState::True => &mut self.config.true_text,
+            BoolState::False => &mut self.config.false_text,
+            BoolState::Null => &mut self.config.null_text,
+        }
+    }
+
+    fn color_field(&mut self, state: BoolState) -> &mut Option<String> {
+        match state {
+            BoolState::True => &mut self.config.true_color,
+            BoolState::False => &mut self.config.false_color,
+            BoolState::Null => &mut self.config.null_color,
+        }
+    }
+
+    /// Generate a text input row for the display text of a `BoolState`.
+    fn text_input_row(
+        &self,
+        ctx: &Context<Self>,
+        state: BoolState,
+        title: &str,
+        disabled: bool,
+    ) -> Html {
+        let (value, default) = match state {
+            BoolState::True => (&self.config.true_text, &self.default_config.true_text),
+            BoolState::False => (&self.config.false_text, &self.default_config.false_text),
+            BoolState::Null => (&self.config.null_text, &self.default_config.null_text),
+        };
+
+        let onchange = ctx.link().callback(move |event: Event| {
+            let input = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+            BoolColumnStyleMsg::TextChanged(state, input.value())
+        });
+
+        html! {
+            <div class="row section">
+                <span class="indent">{ title }</span>
+                <input
+                    class="parameter"
+                    type="text"
+                    { disabled }
+                    placeholder={ default.to_owned() }
+                    value={ value.clone().unwrap_or_default() }
+                    { onchange } />
+            </div>
+        }
+    }
+
+    /// Generate a color selector row for a `BoolState`.
+    fn color_select_row(&self, ctx: &Context<Self>, state: BoolState, title: &str) -> Html {
+        let on_color = ctx
+            .link()
+            .callback(move |color| BoolColumnStyleMsg::ColorChanged(state, color));
+
+        let color = match state {
+            BoolState::True => (&self.config.true_color, &self.default_config.true_color),
+            BoolState::False => (&self.config.false_color, &self.default_config.false_color),
+            BoolState::Null => (&self.config.null_color, &self.default_config.null_color),
+        };
+
+        let color = color.0.clone().unwrap_or_else(|| color.1.to_owned());
+        let color_props = props!(ColorProps { color, on_color });
+        if self.config.bool_color_mode.is_some() {
+            html! {
+                <div class="row section">
+                    <span class="indent">{ title }</span>
+                    <ColorSelector ..color_props />
+                </div>
+            }
+        } else {
+            html! {}
+        }
+    }
+}
+
+impl Component for BoolColumnStyle {
+    type Message = BoolColumnStyleMsg;
+    type Properties = BoolColumnStyleProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.set_modal_link();
+        Self {
+            config: ctx.props().config.clone().unwrap_or_default(),
+            default_config: ctx.props().default_config.clone(),
+        }
+    }
+
+    // Always re-render when config changes.
+    fn changed(&mut self, ctx: &Context<Self>, _old: &Self::Properties) -> bool {
+        let mut new_config = ctx.props().config.clone().unwrap_or_default();
+        if self.config != new_config {
+            std::mem::swap(&mut self.config, &mut new_config);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            BoolColumnStyleMsg::Reset(config) => {
+                self.config = config;
+                true
+            }
+            BoolColumnStyleMsg::FormatEnabled(val) => {
+                if val {
+                    self.config.format = Some(BoolFormatMode::default());
+                } else {
+                    self.config.format = None;
+                    self.config.true_text = None;
+                    self.config.false_text = None;
+                }
+
+                self.dispatch_config(ctx);
+                true
+            }
+            BoolColumnStyleMsg::FormatChanged(val) => {
+                self.config.format = Some(val);
+                self.dispatch_config(ctx);
+                true
+            }
+            BoolColumnStyleMsg::TextChanged(state, text) => {
+                *self.text_field(state) = Some(text).filter(|x| !x.is_empty());
+                self.dispatch_config(ctx);
+                true
+            }
+            BoolColumnStyleMsg::ColorModeEnabled(enabled) => {
+                if enabled {
+                    self.config.bool_color_mode = Some(BoolColorMode::default());
+                } else {
+                    self.config.bool_color_mode = None;
+                    self.config.true_color = None;
+                    self.config.false_color = None;
+                    self.config.null_color = None;
+                }
+
+                self.dispatch_config(ctx);
+                true
+            }
+            BoolColumnStyleMsg::ColorModeChanged(mode) => {
+                self.config.bool_color_mode = Some(mode);
+                self.dispatch_config(ctx);
+                true
+            }
+            BoolColumnStyleMsg::ColorChanged(state, color) => {
+                *self.color_field(state) = Some(color);
+                self.dispatch_config(ctx);
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let format_enabled_oninput = ctx.link().callback(move |event: InputEvent| {
+            let input = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+            BoolColumnStyleMsg::FormatEnabled(input.checked())
+        });
+
+        let format_mode_selected = self.config.format.unwrap_or_default();
+        let format_mode_changed = ctx.link().callback(BoolColumnStyleMsg::FormatChanged);
+        let color_enabled_oninput = ctx.link().callback(move |event: InputEvent| {
+            let input = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+            BoolColumnStyleMsg::ColorModeEnabled(input.checked())
+        });
+
+        let selected_color_mode = self.config.bool_color_mode.unwrap_or_default();
+        let color_mode_changed = ctx.link().callback(BoolColumnStyleMsg::ColorModeChanged);
+        let text_disabled = self.config.format != Some(BoolFormatMode::Text);
+        html_template! {
+            <LocalStyle href={ css!("column-style") } />
+            <div id="column-style-container" class="bool-column-style-container">
+                <div class="column-style-label">
+                    <label class="indent">{ "Format" }</label>
+                </div>
+                <div class="section">
+                    <input
+                        type="checkbox"
+                        oninput={ format_enabled_oninput }
+                        checked={ self.config.format.is_some() } />
+
+                    <RadioList<BoolFormatMode>
+                        class="indent"
+                        disabled={ self.config.format.is_none() }
+                        selected={ format_mode_selected }
+                        on_change={ format_mode_changed } >
+
+                        <RadioListItem<BoolFormatMode>
+                            value={ BoolFormatMode::Icon }>
+                            <span>{ "Icon" }</span>
+                        </RadioListItem<BoolFormatMode>>
+                        <RadioListItem<BoolFormatMode>
+                            value={ BoolFormatMode::Checkbox }>
+                            <span>{ "Checkbox" }</span>
+                        </RadioListItem<BoolFormatMode>>
+                        <RadioListItem<BoolFormatMode>
+                            value={ BoolFormatMode::Text }>
+                            <span>{ "Text" }</span>
+                        </RadioListItem<BoolFormatMode>>
+                    </RadioList<BoolFormatMode>>
+                </div>
+                <div class="column-style-label">
+                    <label class="indent">{ "Labels" }</label>
+                </div>
+                { self.text_input_row(ctx, BoolState::True, "True", text_disabled) }
+                { self.text_input_row(ctx, BoolState::False, "False", text_disabled) }
+                { self.text_input_row(ctx, BoolState::Null, "Null", false) }
+                <div class="column-style-label">
+                    <label class="indent">{ "Color" }</label>
+                </div>
+                <div class="section">
+                    <input
+                        type="checkbox"
+                        oninput={ color_enabled_oninput }
+                        checked={ self.config.bool_color_mode.is_some() } />
+
+                    <RadioList<BoolColorMode>
+                        class="indent"
+                        name="bool-color-radio-list"
+                        disabled={ self.config.bool_color_mode.is_none() }
+                        selected={ selected_color_mode }
+                        on_change={ color_mode_changed } >
+
+                        <RadioListItem<BoolColorMode>
+                            value={ BoolColorMode::Foreground }>
+                            <span>{ "Foreground" }</span>
+                        </RadioListItem<BoolColorMode>>
+                        <RadioListItem<BoolColorMode>
+                            value={ BoolColorMode::Background }>
+                            <span>{ "Background" }</span>
+                        </RadioListItem<BoolColorMode>>
+                    </RadioList<BoolColorMode>>
+                </div>
+                { self.color_select_row(ctx, BoolState::True, "True") }
+                { self.color_select_row(ctx, BoolState::False, "False") }
+                { self.color_select_row(ctx, BoolState::Null, "Null") }
+            </div>
+        }
+    }
+}
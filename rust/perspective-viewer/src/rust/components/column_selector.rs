@@ -17,6 +17,7 @@ mod config_selector;
 mod empty_column;
 mod expression_toolbar;
 mod filter_column;
+mod filter_values_checklist;
 mod inactive_column;
 mod pivot_column;
 mod sort_column;
@@ -74,6 +75,7 @@ impl PartialEq for ColumnSelectorProps {
 pub enum ColumnSelectorMsg {
     TableLoaded,
     ViewCreated,
+    ColumnAttributesChanged,
     HoverActiveIndex(Option<usize>),
     Drag(DragEffect),
     DragEnd,
@@ -85,7 +87,7 @@ use ColumnSelectorMsg::*;
 /// A `ColumnSelector` controls the `columns` field of the `ViewConfig`,
 /// deriving its options from the table columns and `ViewConfig` expressions.
 pub struct ColumnSelector {
-    _subscriptions: [Subscription; 5],
+    _subscriptions: [Subscription; 6],
     named_row_count: usize,
     drag_container: DragDropContainer,
     column_dropdown: ColumnDropDownElement,
@@ -106,6 +108,16 @@ impl Component for ColumnSelector {
             ctx.props().session.view_created.add_listener(cb)
         };
 
+        let attributes_sub = {
+            let cb = ctx
+                .link()
+                .callback(|_| ColumnSelectorMsg::ColumnAttributesChanged);
+            ctx.props()
+                .renderer
+                .column_attributes_changed
+                .add_listener(cb)
+        };
+
         let drop_sub = {
             let cb = ctx.link().callback(ColumnSelectorMsg::Drop);
             ctx.props().dragdrop.drop_received.add_listener(cb)
@@ -136,7 +148,14 @@ impl Component for ColumnSelector {
 
         let column_dropdown = ColumnDropDownElement::new(ctx.props().session.clone());
         Self {
-            _subscriptions: [table_sub, view_sub, drop_sub, drag_sub, dragend_sub],
+            _subscriptions: [
+                table_sub,
+                view_sub,
+                attributes_sub,
+                drop_sub,
+                drag_sub,
+                dragend_sub,
+            ],
             named_row_count,
             drag_container,
             column_dropdown,
@@ -146,7 +165,7 @@ impl Component for ColumnSelector {
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Drag(DragEffect::Move(DragTarget::Active)) => false,
-            Drag(_) | DragEnd | TableLoaded => true,
+            Drag(_) | DragEnd | TableLoaded | ColumnAttributesChanged => true,
             ViewCreated => {
                 let named = maybe! {
                     let plugin =
@@ -171,6 +190,7 @@ impl Component for ColumnSelector {
                     .props()
                     .dragdrop
                     .get_drag_column()
+                    .filter(|_| ctx.props().dragdrop.get_drag_target().is_some())
                     .and_then(|x| config.columns.iter().position(|z| z.as_ref() == Some(&x)));
 
                 if min_cols
@@ -238,12 +258,19 @@ impl Component for ColumnSelector {
             active_classes.push("is-aggregated");
         }
 
+        let having_size = if config.group_by.is_empty() {
+            0.0
+        } else {
+            56.0
+        };
+
         let size = 28.0f64.mul_add(
             (config.group_by.len()
                 + config.split_by.len()
                 + config.filter.len()
+                + config.having.len()
                 + config.sort.len()) as f64,
-            224.0,
+            224.0 + having_size,
         );
 
         let config_selector = html_nested! {
@@ -253,6 +280,7 @@ impl Component for ColumnSelector {
                     session={ &ctx.props().session }
                     renderer={ &ctx.props().renderer }
                     onselect={ onselect.clone() }
+                    on_open_expr_panel={ &ctx.props().on_open_expr_panel }
                     ondragenter={ ctx.link().callback(|()| ViewCreated)}>
                 </ConfigSelector>
             </ScrollPanelItem>
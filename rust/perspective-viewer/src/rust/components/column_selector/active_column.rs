@@ -307,11 +307,16 @@ impl Component for ActiveColumn {
                     let event_name = name.to_owned();
                     let dragdrop = ctx.props().dragdrop.clone();
                     move |event: DragEvent| {
+                        // Holding a modifier key drops a copy of this column,
+                        // which may be aggregated differently.
+                        let effect = if event.alt_key() || event.ctrl_key() {
+                            DragEffect::Copy
+                        } else {
+                            DragEffect::Move(DragTarget::Active)
+                        };
+
                         dragdrop.set_drag_image(&event).unwrap();
-                        dragdrop.notify_drag_start(
-                            event_name.to_string(),
-                            DragEffect::Move(DragTarget::Active),
-                        );
+                        dragdrop.notify_drag_start(event_name.to_string(), effect);
 
                         MouseLeave(false)
                     }
@@ -323,6 +328,13 @@ impl Component for ActiveColumn {
                     .callback(|event: MouseEvent| MouseEnter(event.which() == 0));
 
                 let is_expression = ctx.props().session.metadata().is_column_expression(&name);
+                let display_name = ctx.props().renderer.get_column_display_name(&name);
+                let title = ctx
+                    .props()
+                    .renderer
+                    .get_column_attributes(&name)
+                    .and_then(|x| x.description);
+
                 let mut class = ctx.props().renderer.metadata().mode.css();
                 if self.is_required {
                     class.push("required");
@@ -359,8 +371,8 @@ impl Component for ActiveColumn {
                                     </AggregateSelector>
                                 }
 
-                                <span class={ format!("column_name {}", col_type) }>
-                                    { name.clone() }
+                                <span class={ format!("column_name {}", col_type) } { title }>
+                                    { display_name }
                                 </span>
 
                                 if !ctx.props().is_aggregated {
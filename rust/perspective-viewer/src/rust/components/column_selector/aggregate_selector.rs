@@ -10,6 +10,9 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::fmt::Display;
+
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
 use crate::components::containers::select::*;
@@ -37,13 +40,41 @@ impl PartialEq for AggregateSelectorProps {
     }
 }
 
+/// An entry in the aggregate dropdown.  Parameterized aggregates are listed
+/// once, and their parameter is chosen by a separate input.
+#[derive(Clone, PartialEq)]
+pub enum AggregateOption {
+    Single(SingleAggregate),
+    Multi(MultiAggregate),
+}
+
+impl Display for AggregateOption {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(x) => write!(fmt, "{}", x),
+            Self::Multi(x) => write!(fmt, "{}", x.prefix()),
+        }
+    }
+}
+
+impl From<&Aggregate> for AggregateOption {
+    fn from(value: &Aggregate) -> Self {
+        match value {
+            Aggregate::SingleAggregate(x) => Self::Single(*x),
+            Aggregate::MultiAggregate(x, _) => Self::Multi(*x),
+        }
+    }
+}
+
 pub enum AggregateSelectorMsg {
-    SetAggregate(Aggregate),
+    SetAggregate(AggregateOption),
+    SetParam(String),
 }
 
 pub struct AggregateSelector {
-    aggregates: Vec<SelectItem<Aggregate>>,
+    aggregates: Vec<Aggregate>,
     aggregate: Option<Aggregate>,
+    param_error: Option<String>,
 }
 
 impl Component for AggregateSelector {
@@ -54,53 +85,83 @@ impl Component for AggregateSelector {
         let mut selector = Self {
             aggregates: vec![],
             aggregate: ctx.props().aggregate.clone(),
+            param_error: None,
         };
 
-        selector.aggregates = selector.get_dropdown_aggregates(ctx);
+        selector.aggregates = selector.get_aggregates(ctx);
         selector
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            AggregateSelectorMsg::SetAggregate(aggregate) => {
-                self.set_aggregate(ctx, aggregate);
+            AggregateSelectorMsg::SetAggregate(AggregateOption::Single(x)) => {
+                self.set_aggregate(ctx, Aggregate::SingleAggregate(x));
                 false
             }
+            AggregateSelectorMsg::SetAggregate(AggregateOption::Multi(x)) => {
+                let current = self.selected_aggregate(ctx);
+                let aggregate = if current.multi_aggregate() == Some(x) {
+                    Some(current)
+                } else {
+                    self.aggregates
+                        .iter()
+                        .find(|y| y.multi_aggregate() == Some(x))
+                        .cloned()
+                };
+
+                if let Some(aggregate) = aggregate {
+                    self.set_aggregate(ctx, aggregate);
+                }
+
+                false
+            }
+            AggregateSelectorMsg::SetParam(param) => {
+                if let Some(x) = self.selected_aggregate(ctx).multi_aggregate() {
+                    match x.parse_param(&param) {
+                        Ok(param) => {
+                            self.param_error = None;
+                            self.set_aggregate(ctx, Aggregate::MultiAggregate(x, param));
+                        }
+                        Err(err) => self.param_error = Some(err),
+                    }
+                }
+
+                true
+            }
         }
     }
 
     fn changed(&mut self, ctx: &Context<Self>, _old: &Self::Properties) -> bool {
-        self.aggregates = self.get_dropdown_aggregates(ctx);
+        self.aggregates = self.get_aggregates(ctx);
+        self.param_error = None;
         true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let callback = ctx.link().callback(AggregateSelectorMsg::SetAggregate);
-        let selected_agg = ctx
-            .props()
-            .aggregate
-            .clone()
-            .or_else(|| {
-                ctx.props()
-                    .session
-                    .metadata()
-                    .get_column_table_type(&ctx.props().column)
-                    .map(|x| x.default_aggregate())
-            })
-            .unwrap();
+        let selected_agg = self.selected_aggregate(ctx);
+        let values = self.get_dropdown_aggregates();
+        let param = match &selected_agg {
+            Aggregate::MultiAggregate(x, param) => Some(self.view_param(ctx, x, param)),
+            Aggregate::SingleAggregate(_) => None,
+        };
+
+        let class = classes!(
+            "aggregate-selector-wrapper",
+            param.is_some().then_some("parameterized")
+        );
 
-        let values = self.aggregates.clone();
         html_template! {
             <LocalStyle href={ css!("aggregate-selector") } />
-            <div class="aggregate-selector-wrapper">
-                <Select<Aggregate>
+            <div { class }>
+                <Select<AggregateOption>
                     class={ "aggregate-selector" }
                     values={ values }
-                    label="weighted mean"
-                    selected={ selected_agg }
+                    selected={ AggregateOption::from(&selected_agg) }
                     on_select={ callback }>
 
-                </Select<Aggregate>>
+                </Select<AggregateOption>>
+                { for param }
             </div>
         }
     }
@@ -109,47 +170,108 @@ impl Component for AggregateSelector {
 impl AggregateSelector {
     pub fn set_aggregate(&mut self, ctx: &Context<Self>, aggregate: Aggregate) {
         self.aggregate = Some(aggregate.clone());
-        let mut aggregates = ctx.props().session.get_view_config().aggregates.clone();
-        aggregates.insert(ctx.props().column.clone(), aggregate);
-        let config = ViewConfigUpdate {
-            aggregates: Some(aggregates),
-            ..ViewConfigUpdate::default()
-        };
+        let config = ctx
+            .props()
+            .session
+            .create_aggregate_update(&ctx.props().column, aggregate);
 
         ApiFuture::spawn(ctx.props().update_and_render(config));
     }
 
-    pub fn get_dropdown_aggregates(&self, ctx: &Context<Self>) -> Vec<SelectItem<Aggregate>> {
-        let aggregates = ctx
-            .props()
+    fn selected_aggregate(&self, ctx: &Context<Self>) -> Aggregate {
+        ctx.props()
+            .aggregate
+            .clone()
+            .or_else(|| {
+                ctx.props()
+                    .session
+                    .metadata()
+                    .get_column_default_aggregate(&ctx.props().column)
+            })
+            .unwrap()
+    }
+
+    fn get_aggregates(&self, ctx: &Context<Self>) -> Vec<Aggregate> {
+        ctx.props()
             .session
             .metadata()
             .get_column_aggregates(&ctx.props().column)
             .expect("Bad Aggs")
-            .collect::<Vec<_>>();
+            .collect::<Vec<_>>()
+    }
 
-        let multi_aggregates = aggregates
+    fn get_dropdown_aggregates(&self) -> Vec<SelectItem<AggregateOption>> {
+        let mut options = self
+            .aggregates
             .iter()
-            .filter(|x| matches!(x, Aggregate::MultiAggregate(_, _)))
-            .cloned()
+            .map(AggregateOption::from)
             .collect::<Vec<_>>();
 
-        let multi_aggregates2 = if !multi_aggregates.is_empty() {
-            vec![SelectItem::OptGroup(
-                "weighted mean".into(),
-                multi_aggregates,
-            )]
-        } else {
-            vec![]
-        };
+        options.dedup();
+        options.into_iter().map(SelectItem::Option).collect()
+    }
 
-        let s = aggregates
-            .iter()
-            .filter(|x| matches!(x, Aggregate::SingleAggregate(_)))
-            .cloned()
-            .map(SelectItem::Option)
-            .chain(multi_aggregates2);
+    /// The input for a `MultiAggregate`'s parameter, either a column picker or
+    /// a text field depending on its `AggregateParamKind`.
+    fn view_param(&self, ctx: &Context<Self>, agg: &MultiAggregate, param: &str) -> Html {
+        match agg.param_kind() {
+            AggregateParamKind::Column => {
+                let values = self
+                    .aggregates
+                    .iter()
+                    .filter_map(|x| match x {
+                        Aggregate::MultiAggregate(y, column) if y == agg => {
+                            Some(SelectItem::Option(column.clone()))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                let on_select = ctx.link().callback(AggregateSelectorMsg::SetParam);
+                html! {
+                    <Select<String>
+                        class={ "aggregate-param-selector" }
+                        { values }
+                        selected={ param.to_owned() }
+                        { on_select }>
 
-        s.collect::<Vec<_>>()
+                    </Select<String>>
+                }
+            }
+            kind => {
+                let onchange = ctx.link().callback(|event: Event| {
+                    let value = event
+                        .target()
+                        .unwrap()
+                        .unchecked_into::<web_sys::HtmlInputElement>()
+                        .value();
+
+                    AggregateSelectorMsg::SetParam(value)
+                });
+
+                let (input_type, placeholder) = match kind {
+                    AggregateParamKind::Number => ("number", "0 - 100"),
+                    _ => ("text", "condition"),
+                };
+
+                let class = classes!(
+                    "aggregate-param-input",
+                    self.param_error.is_some().then_some("invalid")
+                );
+
+                html! {
+                    <input
+                        { class }
+                        type={ input_type }
+                        min="0"
+                        max="100"
+                        step="any"
+                        { placeholder }
+                        title={ self.param_error.clone() }
+                        value={ param.to_owned() }
+                        { onchange }/>
+                }
+            }
+        }
     }
 }
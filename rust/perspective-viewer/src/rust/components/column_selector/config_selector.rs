@@ -21,6 +21,7 @@ use super::sort_column::*;
 use super::InPlaceColumn;
 use crate::components::containers::dragdrop_list::*;
 use crate::components::style::LocalStyle;
+use crate::components::viewer::ColumnLocator;
 use crate::config::*;
 use crate::custom_elements::{ColumnDropDownElement, FilterDropDownElement};
 use crate::dragdrop::*;
@@ -30,12 +31,13 @@ use crate::session::*;
 use crate::utils::*;
 use crate::*;
 
-#[derive(Properties)]
+#[derive(Clone, Properties)]
 pub struct ConfigSelectorProps {
     pub session: Session,
     pub renderer: Renderer,
     pub dragdrop: DragDrop,
     pub onselect: Callback<()>,
+    pub on_open_expr_panel: Callback<ColumnLocator>,
 
     #[prop_or_default]
     pub ondragenter: Callback<()>,
@@ -59,7 +61,9 @@ pub enum ConfigSelectorMsg {
     Close(usize, DragTarget),
     SetFilterValue(usize, String),
     TransposePivots,
+    Bucket(DragTarget, usize, BucketOption),
     ViewCreated,
+    ColumnAttributesChanged,
     New(DragTarget, InPlaceColumn),
 }
 
@@ -67,13 +71,14 @@ pub enum ConfigSelectorMsg {
 pub struct ConfigSelector {
     filter_dropdown: FilterDropDownElement,
     column_dropdown: ColumnDropDownElement,
-    _subscriptions: [Rc<Subscription>; 4],
+    _subscriptions: [Rc<Subscription>; 5],
 }
 
 struct GroupByContext {}
 struct SplitByContext {}
 struct SortDragContext {}
 struct FilterDragContext {}
+struct HavingDragContext {}
 
 impl DragContext<ConfigSelectorMsg> for GroupByContext {
     fn dragenter(index: usize) -> ConfigSelectorMsg {
@@ -163,10 +168,33 @@ impl DragContext<ConfigSelectorMsg> for FilterDragContext {
     }
 }
 
+impl DragContext<ConfigSelectorMsg> for HavingDragContext {
+    fn dragenter(index: usize) -> ConfigSelectorMsg {
+        ConfigSelectorMsg::DragOver(index, DragTarget::Having)
+    }
+
+    fn close(index: usize) -> ConfigSelectorMsg {
+        ConfigSelectorMsg::Close(index, DragTarget::Having)
+    }
+
+    fn dragleave() -> ConfigSelectorMsg {
+        ConfigSelectorMsg::DragLeave(DragTarget::Having)
+    }
+
+    fn create(col: InPlaceColumn) -> ConfigSelectorMsg {
+        ConfigSelectorMsg::New(DragTarget::Having, col)
+    }
+
+    fn is_self_move(target: DragTarget) -> bool {
+        target == DragTarget::Having
+    }
+}
+
 type GroupBySelector = DragDropList<ConfigSelector, PivotColumn, GroupByContext>;
 type SplitBySelector = DragDropList<ConfigSelector, PivotColumn, SplitByContext>;
 type SortSelector = DragDropList<ConfigSelector, SortColumn, SortDragContext>;
 type FilterSelector = DragDropList<ConfigSelector, FilterColumn, FilterDragContext>;
+type HavingSelector = DragDropList<ConfigSelector, FilterColumn, HavingDragContext>;
 
 impl Component for ConfigSelector {
     type Message = ConfigSelectorMsg;
@@ -189,9 +217,19 @@ impl Component for ConfigSelector {
         let cb = ctx.link().callback(|_| ConfigSelectorMsg::ViewCreated);
         let view_sub = Rc::new(ctx.props().session.view_created.add_listener(cb));
 
+        let cb = ctx
+            .link()
+            .callback(|_| ConfigSelectorMsg::ColumnAttributesChanged);
+        let attributes_sub = Rc::new(
+            ctx.props()
+                .renderer
+                .column_attributes_changed
+                .add_listener(cb),
+        );
+
         let filter_dropdown = FilterDropDownElement::new(ctx.props().session.clone());
         let column_dropdown = ColumnDropDownElement::new(ctx.props().session.clone());
-        let _subscriptions = [drop_sub, view_sub, drag_sub, dragend_sub];
+        let _subscriptions = [drop_sub, view_sub, drag_sub, dragend_sub, attributes_sub];
         Self {
             filter_dropdown,
             column_dropdown,
@@ -201,7 +239,9 @@ impl Component for ConfigSelector {
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            ConfigSelectorMsg::DragStart(_) | ConfigSelectorMsg::ViewCreated => true,
+            ConfigSelectorMsg::DragStart(_)
+            | ConfigSelectorMsg::ViewCreated
+            | ConfigSelectorMsg::ColumnAttributesChanged => true,
             ConfigSelectorMsg::DragEnd => true,
             ConfigSelectorMsg::DragOver(index, action) => {
                 let should_render = ctx.props().dragdrop.notify_drag_enter(action, index);
@@ -264,6 +304,18 @@ impl Component for ConfigSelector {
                 ctx.props().onselect.emit(());
                 false
             }
+            ConfigSelectorMsg::Close(index, DragTarget::Having) => {
+                let mut having = ctx.props().session.get_view_config().having.clone();
+                having.remove(index);
+                let config = ViewConfigUpdate {
+                    having: Some(having),
+                    ..ViewConfigUpdate::default()
+                };
+
+                ApiFuture::spawn(ctx.props().update_and_render(config));
+                ctx.props().onselect.emit(());
+                false
+            }
             ConfigSelectorMsg::Close(..) => false,
             ConfigSelectorMsg::Drop(column, action, effect, index)
                 if action != DragTarget::Active =>
@@ -299,6 +351,28 @@ impl Component for ConfigSelector {
                 ctx.props().onselect.emit(());
                 false
             }
+            ConfigSelectorMsg::Bucket(target, index, bucket) => {
+                clone!(ctx.props().session);
+                let props = ctx.props().clone();
+                ApiFuture::spawn(async move {
+                    let update = match bucket {
+                        BucketOption::None => session.create_remove_bucket_update(target, index),
+                        BucketOption::Date(units) => {
+                            session.create_date_bucket_update(target, index, &units)
+                        }
+                        BucketOption::Bins(count) => {
+                            session
+                                .create_numeric_bins_update(target, index, count)
+                                .await?
+                        }
+                    };
+
+                    props.update_and_render(update).await
+                });
+
+                ctx.props().onselect.emit(());
+                false
+            }
             ConfigSelectorMsg::SetFilterValue(index, input) => {
                 let mut filter = ctx.props().session.get_view_config().filter.clone();
                 let update = if matches!(filter[index].1, FilterOp::In | FilterOp::NotIn) {
@@ -365,6 +439,23 @@ impl Component for ConfigSelector {
                 ctx.props().onselect.emit(());
                 false
             }
+            ConfigSelectorMsg::New(DragTarget::Having, InPlaceColumn::Column(col)) => {
+                let mut view_config = ctx.props().session.get_view_config().clone();
+                view_config.having.push(Filter(
+                    col,
+                    FilterOp::LT,
+                    FilterTerm::Scalar(Scalar::Null),
+                ));
+
+                let update = ViewConfigUpdate {
+                    having: Some(view_config.having),
+                    ..ViewConfigUpdate::default()
+                };
+
+                ApiFuture::spawn(ctx.props().update_and_render(update));
+                ctx.props().onselect.emit(());
+                false
+            }
             ConfigSelectorMsg::New(DragTarget::Sort, InPlaceColumn::Column(col)) => {
                 let mut view_config = ctx.props().session.get_view_config().clone();
                 view_config.sort.push(Sort(col, SortDir::Asc));
@@ -423,6 +514,24 @@ impl Component for ConfigSelector {
                 ctx.props().onselect.emit(());
                 false
             }
+            ConfigSelectorMsg::New(DragTarget::Having, InPlaceColumn::Expression(col)) => {
+                let mut view_config = ctx.props().session.get_view_config().clone();
+                view_config.having.push(Filter(
+                    col.clone(),
+                    FilterOp::LT,
+                    FilterTerm::Scalar(Scalar::Null),
+                ));
+                view_config.expressions.push(col);
+                let update = ViewConfigUpdate {
+                    having: Some(view_config.having),
+                    expressions: Some(view_config.expressions),
+                    ..ViewConfigUpdate::default()
+                };
+
+                ApiFuture::spawn(ctx.props().update_and_render(update));
+                ctx.props().onselect.emit(());
+                false
+            }
             ConfigSelectorMsg::New(DragTarget::Sort, InPlaceColumn::Expression(col)) => {
                 let mut view_config = ctx.props().session.get_view_config().clone();
                 view_config.sort.push(Sort(col.clone(), SortDir::Asc));
@@ -437,7 +546,7 @@ impl Component for ConfigSelector {
                 ctx.props().onselect.emit(());
                 false
             }
-            ConfigSelectorMsg::New(DragTarget::Active, _) => false,
+            ConfigSelectorMsg::New(DragTarget::Active | DragTarget::CustomOrder, _) => false,
         }
     }
 
@@ -451,11 +560,16 @@ impl Component for ConfigSelector {
         let config = ctx.props().session.get_view_config();
         let transpose = ctx.link().callback(|_| ConfigSelectorMsg::TransposePivots);
         let column_dropdown = self.column_dropdown.clone();
-        let class = if ctx.props().dragdrop.get_drag_column().is_some() {
-            "dragdrop-highlight"
-        } else {
-            ""
-        };
+        let mut class = classes!();
+        if ctx.props().dragdrop.get_drag_column().is_some() {
+            class.push("dragdrop-highlight");
+        }
+
+        // `having` is resolved against the first `group_by` level only, which
+        // the label calls out when there is more than one level.
+        if config.group_by.len() > 1 {
+            class.push("having-first-level");
+        }
 
         let dragend = Callback::from({
             let dragdrop = ctx.props().dragdrop.clone();
@@ -489,12 +603,25 @@ impl Component for ConfigSelector {
                     is_dragover={ ctx.props().dragdrop.is_dragover(DragTarget::GroupBy) }
                     dragdrop={ &ctx.props().dragdrop }>
                     {
-                        for config.group_by.iter().map(|group_by| {
+                        for config.group_by.iter().enumerate().map(|(idx, group_by)| {
+                            let on_bucket = ctx.link().callback(move |bucket| {
+                                ConfigSelectorMsg::Bucket(DragTarget::GroupBy, idx, bucket)
+                            });
+
+                            let (buckets, bucket) =
+                                BucketOption::for_column(&ctx.props().session, group_by).unzip();
+
                             html_nested! {
                                 <PivotColumn
                                     dragdrop={ &ctx.props().dragdrop }
                                     action={ DragTarget::GroupBy }
-                                    column={ group_by.clone() }>
+                                    column={ group_by.clone() }
+                                    attributes={
+                                        ctx.props().renderer.get_column_attributes(group_by).unwrap_or_default()
+                                    }
+                                    { buckets }
+                                    bucket={ bucket.unwrap_or_default() }
+                                    { on_bucket }>
                                 </PivotColumn>
                             }
                         })
@@ -519,12 +646,25 @@ impl Component for ConfigSelector {
                     is_dragover={ ctx.props().dragdrop.is_dragover(DragTarget::SplitBy) }
                     dragdrop={ &ctx.props().dragdrop }>
                     {
-                        for config.split_by.iter().map(|split_by| {
+                        for config.split_by.iter().enumerate().map(|(idx, split_by)| {
+                            let on_bucket = ctx.link().callback(move |bucket| {
+                                ConfigSelectorMsg::Bucket(DragTarget::SplitBy, idx, bucket)
+                            });
+
+                            let (buckets, bucket) =
+                                BucketOption::for_column(&ctx.props().session, split_by).unzip();
+
                             html_nested! {
                                 <PivotColumn
                                     dragdrop={ &ctx.props().dragdrop }
                                     action={ DragTarget::SplitBy }
-                                    column={ split_by.clone() }>
+                                    column={ split_by.clone() }
+                                    attributes={
+                                        ctx.props().renderer.get_column_attributes(split_by).unwrap_or_default()
+                                    }
+                                    { buckets }
+                                    bucket={ bucket.unwrap_or_default() }
+                                    { on_bucket }>
                                 </PivotColumn>
                             }
                         })
@@ -549,6 +689,7 @@ impl Component for ConfigSelector {
                                     session={ &ctx.props().session }
                                     renderer={ &ctx.props().renderer }
                                     dragdrop={ &ctx.props().dragdrop }
+                                    on_open_expr_panel={ &ctx.props().on_open_expr_panel }
                                     sort={ sort.clone() }>
                                 </SortColumn>
                             }
@@ -560,7 +701,7 @@ impl Component for ConfigSelector {
                     name="filter"
                     allow_duplicates=true
                     parent={ ctx.link().clone() }
-                    column_dropdown={ column_dropdown }
+                    column_dropdown={ column_dropdown.clone() }
                     exclude={ config.filter.iter().map(|x| x.0.clone()).collect::<HashSet<_>>() }
                     dragdrop={ &ctx.props().dragdrop }
                     is_dragover={ ctx.props().dragdrop.is_dragover(DragTarget::Filter).map(|(index, name)| {
@@ -586,6 +727,36 @@ impl Component for ConfigSelector {
                     }
                 </FilterSelector>
 
+                if !config.group_by.is_empty() {
+                    <HavingSelector
+                        name="having"
+                        allow_duplicates=true
+                        parent={ ctx.link().clone() }
+                        column_dropdown={ column_dropdown }
+                        exclude={ config.having.iter().map(|x| x.0.clone()).collect::<HashSet<_>>() }
+                        dragdrop={ &ctx.props().dragdrop }
+                        is_dragover={ ctx.props().dragdrop.is_dragover(DragTarget::Having).map(|(index, name)| {
+                            (index, Filter(name, FilterOp::LT, FilterTerm::Scalar(Scalar::Null)))
+                        }) }>
+                        {
+                            for config.having.iter().enumerate().map(|(idx, having)| {
+                                html_nested! {
+                                    <FilterColumn
+                                        idx={ idx }
+                                        is_having=true
+                                        filter_dropdown={ &self.filter_dropdown }
+                                        session={ &ctx.props().session }
+                                        renderer={ &ctx.props().renderer }
+                                        dragdrop={ &ctx.props().dragdrop }
+                                        filter={ having.clone() }
+                                        on_keydown={ Callback::noop() }>
+                                    </FilterColumn>
+                                }
+                            })
+                        }
+                    </HavingSelector>
+                }
+
             </div>
         }
     }
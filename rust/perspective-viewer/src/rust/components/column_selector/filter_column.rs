@@ -10,11 +10,15 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use chrono::{NaiveDate, TimeZone, Utc};
+use std::fmt::Display;
+
+use chrono::{TimeZone, Utc};
+use itertools::Itertools;
 use wasm_bindgen::JsCast;
 use web_sys::*;
 use yew::prelude::*;
 
+use super::filter_values_checklist::*;
 use crate::components::containers::dragdrop_list::*;
 use crate::components::containers::select::*;
 use crate::components::style::LocalStyle;
@@ -24,21 +28,44 @@ use crate::dragdrop::*;
 use crate::model::*;
 use crate::renderer::*;
 use crate::session::*;
-use crate::utils::{posix_to_utc_str, str_to_utc_posix, ApiFuture};
+use crate::utils::{posix_to_utc_str, ApiFuture};
 use crate::*;
 
 /// A control for a single filter condition.
 pub struct FilterColumn {
     input: String,
     input_ref: NodeRef,
+    show_checklist: bool,
+    error: Option<String>,
 }
 
 #[derive(Debug)]
 pub enum FilterColumnMsg {
     FilterInput((usize, String), String),
+    FilterCommit(String),
+    BetweenInput(usize, String),
+    ToggleChecklist,
+    ChecklistChange(Vec<Scalar>),
+    ChecklistPaste(Vec<String>),
     Close,
     FilterOpSelect(FilterOp),
     FilterKeyDown(u32),
+    TopNBy(String),
+    TopNAggregate(Aggregate),
+    RelativeDateSelect(RelativeDateOption),
+}
+
+/// A `RelativeDate` preset for the `<select>`, or a custom absolute date.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RelativeDateOption(Option<RelativeDate>);
+
+impl Display for RelativeDateOption {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(x) => write!(fmt, "{}", x),
+            None => write!(fmt, "custom"),
+        }
+    }
 }
 
 #[derive(Properties, Clone)]
@@ -50,11 +77,18 @@ pub struct FilterColumnProps {
     pub session: Session,
     pub renderer: Renderer,
     pub dragdrop: DragDrop,
+
+    /// Is this a filter in `ViewConfig::having`, rather than `filter`?
+    #[prop_or_default]
+    pub is_having: bool,
 }
 
 impl PartialEq for FilterColumnProps {
     fn eq(&self, rhs: &Self) -> bool {
-        self.idx == rhs.idx && self.filter == rhs.filter && self.on_keydown == rhs.on_keydown
+        self.idx == rhs.idx
+            && self.filter == rhs.filter
+            && self.on_keydown == rhs.on_keydown
+            && self.is_having == rhs.is_having
     }
 }
 
@@ -71,26 +105,98 @@ impl DragDropListItemProps for FilterColumnProps {
 impl FilterColumnProps {
     /// Does this filter item get a "suggestions" auto-complete modal?
     fn is_suggestable(&self) -> bool {
-        (self.filter.1 == FilterOp::EQ
-            || self.filter.1 == FilterOp::NE
-            || self.filter.1 == FilterOp::In)
+        !self.is_having
+            && matches!(
+                self.filter.1,
+                FilterOp::EQ
+                    | FilterOp::NE
+                    | FilterOp::EqIgnoreCase
+                    | FilterOp::NeIgnoreCase
+                    | FilterOp::In
+            )
             && self.get_filter_type() == Some(Type::String)
     }
 
-    /// Get this filter's type, e.g. the type of the column.
+    /// Get this filter's type, e.g. the type of the column, or of its
+    /// aggregate for a `having` filter.
     fn get_filter_type(&self) -> Option<Type> {
-        self.session
-            .metadata()
-            .get_column_table_type(&self.filter.0)
+        let metadata = self.session.metadata();
+        if self.is_having {
+            metadata
+                .get_column_view_type(&self.filter.0)
+                .or_else(|| metadata.get_column_table_type(&self.filter.0))
+        } else {
+            metadata.get_column_table_type(&self.filter.0)
+        }
+    }
+
+    /// The label for this filter's column, which for a `having` filter is
+    /// the aggregate it is applied to, e.g. `sum(Sales)`.
+    fn get_column_label(&self) -> String {
+        let name = &self.filter.0;
+        if self.is_having {
+            let aggregate = self
+                .session
+                .get_view_config()
+                .aggregates
+                .get(name)
+                .cloned()
+                .or_else(|| self.session.metadata().get_column_default_aggregate(name));
+
+            if let Some(aggregate) = aggregate {
+                return format!("{}({})", aggregate, name);
+            }
+        }
+
+        name.to_owned()
+    }
+
+    /// The filter list this filter belongs to.
+    fn get_filters(&self) -> Vec<Filter> {
+        let config = self.session.get_view_config();
+        if self.is_having {
+            config.having.clone()
+        } else {
+            config.filter.clone()
+        }
+    }
+
+    /// Create an update which replaces the filter list this filter belongs to.
+    fn create_filters_update(&self, filters: Vec<Filter>) -> ViewConfigUpdate {
+        if self.is_having {
+            ViewConfigUpdate {
+                having: Some(filters),
+                ..ViewConfigUpdate::default()
+            }
+        } else {
+            ViewConfigUpdate {
+                filter: Some(filters),
+                ..ViewConfigUpdate::default()
+            }
+        }
     }
 
     // Get the string value, suitable for the `value` field of a `FilterColumns`'s
     // `<input>`.
     fn get_filter_input(&self) -> Option<String> {
+        match &self.filter.2 {
+            FilterTerm::Scalar(x) => self.format_filter_scalar(x),
+            FilterTerm::Array(xs) => Some(
+                xs.iter()
+                    .filter_map(|x| self.format_filter_scalar(x))
+                    .join(","),
+            ),
+            _ if self.get_filter_type()? == Type::Bool => Some("true".to_owned()),
+            x => Some(format!("{}", x)),
+        }
+    }
+
+    /// Format a `Scalar` term of this filter for an `<input>` of this filter's
+    /// type.
+    fn format_filter_scalar(&self, x: &Scalar) -> Option<String> {
         let filter_type = self.get_filter_type()?;
-        match (&filter_type, &self.filter.2) {
-            (Type::Date, FilterTerm::Scalar(Scalar::Float(x)))
-            | (Type::Date, FilterTerm::Scalar(Scalar::DateTime(x))) => {
+        match (&filter_type, x) {
+            (Type::Date, Scalar::Float(x) | Scalar::DateTime(x)) => {
                 if *x > 0_f64 {
                     Some(
                         Utc.timestamp_opt(*x as i64 / 1000, (*x as u32 % 1000) * 1000)
@@ -102,34 +208,90 @@ impl FilterColumnProps {
                     None
                 }
             }
-            (Type::Datetime, FilterTerm::Scalar(Scalar::Float(x) | Scalar::DateTime(x))) => {
-                posix_to_utc_str(*x).ok()
-            }
-            (Type::Bool, FilterTerm::Scalar(Scalar::Bool(x))) => {
-                Some((if *x { "true" } else { "false" }).to_owned())
+            (Type::Datetime, Scalar::Float(x) | Scalar::DateTime(x)) => {
+                posix_to_utc_str(*x, self.session.get_time_zone().as_deref()).ok()
             }
+            (Type::Bool, Scalar::Bool(x)) => Some((if *x { "true" } else { "false" }).to_owned()),
             (Type::Bool, _) => Some("true".to_owned()),
             (_, x) => Some(format!("{}", x)),
         }
     }
 
+    /// Does this filter item get a relative date preset `<select>`?
+    fn is_relative_date_selectable(&self) -> bool {
+        !self.is_having
+            && !self.filter.1.is_top_n()
+            && !matches!(
+                self.filter.1,
+                FilterOp::IsNull | FilterOp::IsNotNull | FilterOp::Between
+            )
+            && matches!(self.get_filter_type(), Some(Type::Date | Type::Datetime))
+    }
+
+    /// Update the term of this filter to a `RelativeDate`, or clear it for a
+    /// custom absolute date.  Periods such as `"today"` select `==`, while
+    /// instants such as `"now"` keep an existing comparison.
+    ///
+    /// # Arguments
+    /// - `relative` The new `RelativeDate`, if any.
+    fn update_relative_date(&self, relative: Option<RelativeDate>) {
+        let mut filter = self.get_filters();
+        let filter_column = &mut filter.get_mut(self.idx).expect("Filter on no column");
+        match relative {
+            Some(relative) => {
+                filter_column.2 = FilterTerm::Relative(RelativeDateTerm(relative));
+                if !relative.is_instant() {
+                    filter_column.1 = FilterOp::EQ;
+                } else if !matches!(
+                    filter_column.1,
+                    FilterOp::GT | FilterOp::GTE | FilterOp::LT | FilterOp::LTE
+                ) {
+                    filter_column.1 = FilterOp::LT;
+                }
+            }
+            None if filter_column.2.is_relative() => {
+                filter_column.2 = FilterTerm::Scalar(Scalar::Null);
+            }
+            None => return,
+        }
+
+        let update = self.create_filters_update(filter);
+        ApiFuture::spawn(self.update_and_render(update));
+    }
+
     /// Get the allowed `FilterOp`s for this filter.
     fn get_filter_ops(&self) -> Vec<FilterOp> {
+        let mut ops = self.get_filter_type_ops();
+        if self.is_having {
+            ops.retain(|x| !x.is_top_n());
+        }
+
+        ops
+    }
+
+    fn get_filter_type_ops(&self) -> Vec<FilterOp> {
         match self.get_filter_type() {
             Some(Type::String) => vec![
                 FilterOp::EQ,
                 FilterOp::NE,
+                FilterOp::EqIgnoreCase,
+                FilterOp::NeIgnoreCase,
                 FilterOp::GT,
                 FilterOp::GTE,
                 FilterOp::LT,
                 FilterOp::LTE,
                 FilterOp::BeginsWith,
+                FilterOp::NotBeginsWith,
                 FilterOp::Contains,
+                FilterOp::NotContains,
                 FilterOp::EndsWith,
+                FilterOp::Matches,
                 FilterOp::In,
                 FilterOp::NotIn,
                 FilterOp::IsNotNull,
                 FilterOp::IsNull,
+                FilterOp::TopN,
+                FilterOp::BottomN,
             ],
             Some(Type::Bool) => {
                 vec![FilterOp::EQ, FilterOp::IsNull, FilterOp::IsNotNull]
@@ -141,8 +303,11 @@ impl FilterColumnProps {
                 FilterOp::GTE,
                 FilterOp::LT,
                 FilterOp::LTE,
+                FilterOp::Between,
                 FilterOp::IsNotNull,
                 FilterOp::IsNull,
+                FilterOp::TopN,
+                FilterOp::BottomN,
             ],
             _ => vec![],
         }
@@ -153,86 +318,225 @@ impl FilterColumnProps {
     /// # Arguments
     /// - `op` The new `FilterOp`.
     fn update_filter_op(&self, op: FilterOp) {
-        let mut filter = self.session.get_view_config().filter.clone();
+        let mut filter = self.get_filters();
         let filter_column = &mut filter.get_mut(self.idx).expect("Filter on no column");
+        if op.is_top_n() && !filter_column.1.is_top_n() {
+            filter_column.2 = FilterTerm::TopN(TopNFilter {
+                n: 10,
+                by: self.get_default_top_n_by(),
+                aggregate: None,
+            });
+        } else if op == FilterOp::Between && filter_column.1 != FilterOp::Between {
+            let lo = match &filter_column.2 {
+                FilterTerm::Scalar(x) => x.clone(),
+                _ => Scalar::Null,
+            };
+
+            filter_column.2 = FilterTerm::Array(vec![lo, Scalar::Null]);
+        } else if (!op.is_top_n() && filter_column.1.is_top_n())
+            || (op != FilterOp::Between && filter_column.1 == FilterOp::Between)
+        {
+            filter_column.2 = FilterTerm::Scalar(Scalar::Null);
+        }
+
         filter_column.1 = op;
-        let update = ViewConfigUpdate {
-            filter: Some(filter),
-            ..ViewConfigUpdate::default()
-        };
+        let update = self.create_filters_update(filter);
 
         ApiFuture::spawn(self.update_and_render(update));
     }
 
-    /// Update the filter value from the string input read from the DOM.
+    /// The initial `by` column of a new "top n" filter, the first numeric
+    /// column in the `View`, or the filter column itself if there is none.
+    fn get_default_top_n_by(&self) -> String {
+        let metadata = self.session.metadata();
+        self.session
+            .get_view_config()
+            .columns
+            .iter()
+            .flatten()
+            .find(|x| {
+                matches!(
+                    metadata.get_column_table_type(x),
+                    Some(Type::Integer | Type::Float)
+                )
+            })
+            .cloned()
+            .unwrap_or_else(|| self.filter.0.clone())
+    }
+
+    /// The columns a "top n" filter may be ranked by.
+    fn get_top_n_by_columns(&self) -> Vec<String> {
+        let metadata = self.session.metadata();
+        metadata
+            .get_table_columns()
+            .into_iter()
+            .flatten()
+            .chain(metadata.get_expression_columns())
+            .cloned()
+            .collect()
+    }
+
+    /// Update the term of this "top n" filter.
     ///
     /// # Arguments
-    /// - `val` The new filter value.
-    fn update_filter_input(&self, val: String) {
-        let mut filter = self.session.get_view_config().filter.clone();
+    /// - `f` A function which modifies the `TopNFilter`.
+    fn update_top_n(&self, f: impl FnOnce(&mut TopNFilter)) {
+        let mut filter = self.get_filters();
         let filter_column = &mut filter.get_mut(self.idx).expect("Filter on no column");
-        let filter_input = match filter_column.1 {
-            FilterOp::NotIn | FilterOp::In => Some(FilterTerm::Array(
-                val.split(',')
-                    .map(|x| Scalar::String(x.trim().to_owned()))
-                    .collect(),
-            )),
-            _ => match self.get_filter_type() {
-                Some(Type::String) => Some(FilterTerm::Scalar(Scalar::String(val))),
-                Some(Type::Integer) => {
-                    if val.is_empty() {
-                        None
-                    } else if let Ok(num) = val.parse::<f64>() {
-                        Some(FilterTerm::Scalar(Scalar::Float(num.floor())))
-                    } else {
-                        None
-                    }
-                }
-                Some(Type::Float) => {
-                    if val.is_empty() {
-                        None
-                    } else if let Ok(num) = val.parse::<f64>() {
-                        Some(FilterTerm::Scalar(Scalar::Float(num)))
-                    } else {
-                        None
-                    }
-                }
-                Some(Type::Date) => match NaiveDate::parse_from_str(&val, "%Y-%m-%d") {
-                    Ok(ref posix) => posix
-                        .and_hms_opt(0, 0, 0)
-                        .map(|x| FilterTerm::Scalar(Scalar::DateTime(x.timestamp_millis() as f64))),
-                    _ => None,
-                },
-                Some(Type::Datetime) => match str_to_utc_posix(&val) {
-                    Ok(x) => Some(FilterTerm::Scalar(Scalar::DateTime(x))),
-                    _ => None,
-                },
-                Some(Type::Bool) => Some(FilterTerm::Scalar(match val.as_str() {
-                    "true" => Scalar::Bool(true),
-                    _ => Scalar::Bool(false),
-                })),
-
-                // shouldn't be reachable ..
-                _ => None,
-            },
+        if let FilterTerm::TopN(top_n) = &mut filter_column.2 {
+            let old = top_n.clone();
+            f(top_n);
+            if *top_n != old {
+                let update = self.create_filters_update(filter);
+
+                ApiFuture::spawn(self.update_and_render(update));
+            }
+        }
+    }
+
+    /// Parse the string input read from the DOM as a `Scalar` term of this
+    /// filter's type.
+    ///
+    /// # Arguments
+    /// - `val` The input string.
+    fn parse_filter_scalar(&self, val: &str) -> Result<Scalar, String> {
+        let ty = self.get_filter_type().ok_or("Unknown column type")?;
+        let time_zone = self.session.get_time_zone();
+        parse_filter_scalar(val, ty, time_zone.as_deref())
+    }
+
+    /// Update one bound of this `between` filter from the string input read
+    /// from the DOM.  An empty input leaves that side of the range open.
+    ///
+    /// # Arguments
+    /// - `bound` `0` for the lower bound, `1` for the upper.
+    /// - `val` The new bound value.
+    fn update_between_input(&self, bound: usize, val: String) -> Result<(), String> {
+        let scalar = if val.trim().is_empty() {
+            Scalar::Null
+        } else {
+            self.parse_filter_scalar(&val)?
         };
 
-        if let Some(input) = filter_input {
-            if input != filter_column.2 {
-                filter_column.2 = input;
-                let update = ViewConfigUpdate {
-                    filter: Some(filter),
-                    ..ViewConfigUpdate::default()
-                };
+        let mut filter = self.get_filters();
+        let filter_column = &mut filter.get_mut(self.idx).expect("Filter on no column");
+        if let FilterTerm::Array(xs) = &mut filter_column.2 {
+            xs.resize(2, Scalar::Null);
+            if xs[bound] != scalar {
+                xs[bound] = scalar;
+                let update = self.create_filters_update(filter);
 
                 ApiFuture::spawn(self.update_and_render(update));
             }
         }
+
+        Ok(())
+    }
+
+    /// Replace the values of this `in` or `not in` filter.
+    ///
+    /// # Arguments
+    /// - `values` The new values.
+    fn update_in_values(&self, values: Vec<Scalar>) {
+        let mut filter = self.get_filters();
+        let filter_column = &mut filter.get_mut(self.idx).expect("Filter on no column");
+        let term = FilterTerm::Array(values);
+        if term != filter_column.2 {
+            filter_column.2 = term;
+            let update = self.create_filters_update(filter);
+
+            ApiFuture::spawn(self.update_and_render(update));
+        }
+    }
+
+    /// Add pasted values (e.g. one per line) to this `in` or `not in`
+    /// filter.  Values which are invalid for this filter's type are skipped,
+    /// and reported as an error.
+    ///
+    /// # Arguments
+    /// - `lines` The pasted values.
+    fn paste_in_values(&self, lines: Vec<String>) -> Result<(), String> {
+        let mut values = match &self.filter.2 {
+            FilterTerm::Array(xs) => xs.clone(),
+            _ => vec![],
+        };
+
+        let mut errors = vec![];
+        for line in lines {
+            match self.parse_filter_scalar(&line) {
+                Ok(value) if !values.contains(&value) => values.push(value),
+                Ok(_) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+
+        self.update_in_values(values);
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            n => Err(format!("{} (and {} more)", errors.remove(0), n - 1)),
+        }
+    }
+
+    /// Update the filter value from the string input read from the DOM, via
+    /// `parse_filter_input()`.  Input which changes the filter's op (e.g. a
+    /// range `1..5`, which becomes `between`) is only applied when `commit`
+    /// is set, so the `<input>` is not replaced while the user is typing.
+    ///
+    /// # Arguments
+    /// - `val` The new filter value.
+    /// - `commit` Whether the input is complete, e.g. on "Enter" or blur.
+    fn update_filter_input(&self, val: String, commit: bool) -> Result<(), String> {
+        if self.filter.1.is_top_n() {
+            return match val.trim().parse::<u32>() {
+                Ok(n) => {
+                    self.update_top_n(|top_n| top_n.n = n.max(1));
+                    Ok(())
+                }
+                Err(_) => Err(format!("\"{}\" is not a positive integer", val.trim())),
+            };
+        }
+
+        let Some(ty) = self.get_filter_type() else {
+            return Ok(());
+        };
+
+        let time_zone = self.session.get_time_zone();
+        let Some((op, term)) = parse_filter_input(&val, ty, self.filter.1, time_zone.as_deref())? else {
+            return Ok(());
+        };
+
+        if op != self.filter.1 && !commit {
+            return Ok(());
+        }
+
+        let mut filter = self.get_filters();
+        let filter_column = &mut filter.get_mut(self.idx).expect("Filter on no column");
+        if op != filter_column.1 || term != filter_column.2 {
+            filter_column.1 = op;
+            filter_column.2 = term;
+            let update = self.create_filters_update(filter);
+
+            ApiFuture::spawn(self.update_and_render(update));
+        }
+
+        Ok(())
     }
 }
 
 type FilterOpSelector = Select<FilterOp>;
 
+impl FilterColumn {
+    /// Show (or clear) the inline error for the result of an input, returning
+    /// whether the error has changed.
+    fn set_error(&mut self, result: Result<(), String>) -> bool {
+        let error = result.err();
+        let changed = error != self.error;
+        self.error = error;
+        changed
+    }
+}
+
 impl Component for FilterColumn {
     type Message = FilterColumnMsg;
     type Properties = FilterColumnProps;
@@ -244,11 +548,18 @@ impl Component for FilterColumn {
             .get_filter_input()
             .unwrap_or_else(|| "".to_owned());
         let input_ref = NodeRef::default();
-        if ctx.props().get_filter_type() == Some(Type::Bool) {
-            ctx.props().update_filter_input(input.clone());
-        }
+        let error = if ctx.props().get_filter_type() == Some(Type::Bool) {
+            ctx.props().update_filter_input(input.clone(), true).err()
+        } else {
+            None
+        };
 
-        Self { input, input_ref }
+        Self {
+            input,
+            input_ref,
+            show_checklist: false,
+            error,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: FilterColumnMsg) -> bool {
@@ -278,8 +589,10 @@ impl Component for FilterColumn {
                     );
                 }
 
-                ctx.props().update_filter_input(input);
-                false
+                self.set_error(ctx.props().update_filter_input(input, false))
+            }
+            FilterColumnMsg::FilterCommit(input) => {
+                self.set_error(ctx.props().update_filter_input(input, true))
             }
             FilterColumnMsg::FilterKeyDown(40) => {
                 if ctx.props().is_suggestable() {
@@ -314,8 +627,40 @@ impl Component for FilterColumn {
             }
             FilterColumnMsg::FilterOpSelect(op) => {
                 ctx.props().update_filter_op(op);
+                self.error = None;
+                true
+            }
+            FilterColumnMsg::TopNBy(by) => {
+                ctx.props().update_top_n(|top_n| {
+                    top_n.by = by;
+                    top_n.aggregate = None;
+                });
+
+                false
+            }
+            FilterColumnMsg::TopNAggregate(aggregate) => {
+                ctx.props()
+                    .update_top_n(|top_n| top_n.aggregate = Some(aggregate));
+                false
+            }
+            FilterColumnMsg::BetweenInput(bound, input) => {
+                self.set_error(ctx.props().update_between_input(bound, input))
+            }
+            FilterColumnMsg::ToggleChecklist => {
+                self.show_checklist = !self.show_checklist;
                 true
             }
+            FilterColumnMsg::ChecklistChange(values) => {
+                ctx.props().update_in_values(values);
+                false
+            }
+            FilterColumnMsg::ChecklistPaste(lines) => {
+                self.set_error(ctx.props().paste_in_values(lines))
+            }
+            FilterColumnMsg::RelativeDateSelect(RelativeDateOption(relative)) => {
+                ctx.props().update_relative_date(relative);
+                false
+            }
         }
     }
 
@@ -332,11 +677,7 @@ impl Component for FilterColumn {
         let idx = ctx.props().idx;
         let filter = ctx.props().filter.clone();
         let column = filter.0.to_owned();
-        let col_type = ctx
-            .props()
-            .session
-            .metadata()
-            .get_column_table_type(&column);
+        let col_type = ctx.props().get_filter_type();
 
         let select = ctx.link().callback(FilterColumnMsg::FilterOpSelect);
 
@@ -368,10 +709,15 @@ impl Component for FilterColumn {
         let dragstart = Callback::from({
             let event_name = ctx.props().filter.0.to_owned();
             let dragdrop = ctx.props().dragdrop.clone();
+            let target = if ctx.props().is_having {
+                DragTarget::Having
+            } else {
+                DragTarget::Filter
+            };
+
             move |event: DragEvent| {
                 dragdrop.set_drag_image(&event).unwrap();
-                dragdrop
-                    .notify_drag_start(event_name.to_string(), DragEffect::Move(DragTarget::Filter))
+                dragdrop.notify_drag_start(event_name.to_string(), DragEffect::Move(target))
             }
         });
 
@@ -381,28 +727,73 @@ impl Component for FilterColumn {
         });
 
         let type_class = match col_type {
-            Some(Type::Float) | Some(Type::Integer) => "num-filter",
-            Some(Type::String) => "string-filter",
-            _ => "",
+            _ if filter.1.is_top_n() => "num-filter",
+            Some(Type::Bool) | None => "",
+            Some(_) => "string-filter",
         };
 
-        let input_elem = match col_type {
-            Some(Type::Integer) => html! {
+        let commit = ctx.link().callback(|event: Event| {
+            FilterColumnMsg::FilterCommit(
+                event
+                    .target()
+                    .unwrap()
+                    .unchecked_into::<HtmlInputElement>()
+                    .value(),
+            )
+        });
+
+        let placeholder = match col_type {
+            Some(Type::Integer | Type::Float) => "e.g. 10k, 5%, 1..5",
+            Some(Type::Date) => "e.g. 2024-01-05, today",
+            Some(Type::Datetime) => "e.g. 2024-01-05 13:00, now - 15m",
+            _ => "Value",
+        };
+
+        let between_input = |bound: usize| {
+            let value = match &filter.2 {
+                FilterTerm::Array(xs) => xs
+                    .get(bound)
+                    .and_then(|x| ctx.props().format_filter_scalar(x)),
+                _ => None,
+            };
+
+            let oninput = ctx.link().callback(move |event: InputEvent| {
+                FilterColumnMsg::BetweenInput(
+                    bound,
+                    event
+                        .target()
+                        .unwrap()
+                        .unchecked_into::<HtmlInputElement>()
+                        .value(),
+                )
+            });
+
+            html! {
                 <input
-                    type="number"
-                    placeholder="Value"
-                    class="num-filter"
-                    step="1"
-                    ref={ noderef.clone() }
-                    onkeydown={ keydown }
-                    value={ self.input.clone() }
-                    oninput={ input }/>
+                    type="text"
+                    placeholder={ if bound == 0 { "Min" } else { "Max" } }
+                    class="between-filter"
+                    spellcheck="false"
+                    value={ value.unwrap_or_default() }
+                    oninput={ oninput }/>
+            }
+        };
+
+        let input_elem = match col_type {
+            _ if filter.1 == FilterOp::Between => html! {
+                <>
+                    { between_input(0) }
+                    <span class="top-n-label">{ "and" }</span>
+                    { between_input(1) }
+                </>
             },
-            Some(Type::Float) => html! {
+            _ if filter.1.is_top_n() => html! {
                 <input
                     type="number"
-                    placeholder="Value"
+                    placeholder="N"
                     class="num-filter"
+                    min="1"
+                    step="1"
                     ref={ noderef.clone() }
                     onkeydown={ keydown }
                     value={ self.input.clone() }
@@ -421,27 +812,21 @@ impl Component for FilterColumn {
                     onkeydown={ keydown }
                     onfocus={ focus }
                     onblur={ blur }
+                    onchange={ commit }
                     value={ self.input.clone() }
                     oninput={ input }/>
             },
-            Some(Type::Date) => html! {
+            Some(Type::Integer | Type::Float | Type::Date | Type::Datetime) => html! {
                 <input
-                    type="date"
-                    placeholder="Value"
-                    class="date-filter"
-                    ref={ noderef.clone() }
-                    onkeydown={ keydown }
-                    value={ self.input.clone() }
-                    oninput={ input }/>
-            },
-            Some(Type::Datetime) => html! {
-                <input
-                    type="datetime-local"
-                    placeholder="Value"
-                    class="datetime-filter"
-                    step="0.001"
+                    type="text"
+                    size="4"
+                    placeholder={ placeholder }
+                    class="string-filter"
+                    spellcheck="false"
+                    onInput="this.parentNode.dataset.value=this.value"
                     ref={ noderef.clone() }
                     onkeydown={ keydown }
+                    onchange={ commit }
                     value={ self.input.clone() }
                     oninput={ input }/>
             },
@@ -459,6 +844,13 @@ impl Component for FilterColumn {
             }
         };
 
+        let error = match &self.error {
+            Some(error) => html! {
+                <span class="filter-input-error">{ error }</span>
+            },
+            None => html! {},
+        };
+
         let filter_ops = ctx
             .props()
             .get_filter_ops()
@@ -466,6 +858,90 @@ impl Component for FilterColumn {
             .map(SelectItem::Option)
             .collect::<Vec<_>>();
 
+        let top_n_controls = match &filter.2 {
+            FilterTerm::TopN(top_n) if filter.1.is_top_n() => {
+                let by_columns = ctx
+                    .props()
+                    .get_top_n_by_columns()
+                    .into_iter()
+                    .map(SelectItem::Option)
+                    .collect::<Vec<_>>();
+
+                let metadata = ctx.props().session.metadata();
+                let aggregate = top_n
+                    .aggregate
+                    .clone()
+                    .or_else(|| metadata.get_column_default_aggregate(&top_n.by));
+
+                let aggregates = metadata
+                    .get_column_aggregates(&top_n.by)
+                    .into_iter()
+                    .flatten()
+                    .map(SelectItem::Option)
+                    .collect::<Vec<_>>();
+
+                html! {
+                    <>
+                        <span class="top-n-label">{ "by" }</span>
+                        if let Some(aggregate) = aggregate {
+                            <Select<Aggregate>
+                                class="filterop-selector"
+                                values={ aggregates }
+                                selected={ aggregate }
+                                on_select={ ctx.link().callback(FilterColumnMsg::TopNAggregate) }>
+                            </Select<Aggregate>>
+                        }
+                        <Select<String>
+                            class="filterop-selector"
+                            values={ by_columns }
+                            selected={ top_n.by.clone() }
+                            on_select={ ctx.link().callback(FilterColumnMsg::TopNBy) }>
+                        </Select<String>>
+                    </>
+                }
+            }
+            _ => html! {},
+        };
+
+        let is_in = !ctx.props().is_having && matches!(filter.1, FilterOp::In | FilterOp::NotIn);
+        let toggle_checklist = ctx.link().callback(|_| FilterColumnMsg::ToggleChecklist);
+        let selected_values = match &filter.2 {
+            FilterTerm::Array(xs) => xs.clone(),
+            _ => vec![],
+        };
+
+        let relative_date_select = if ctx.props().is_relative_date_selectable() {
+            let selected = match &filter.2 {
+                FilterTerm::Relative(RelativeDateTerm(x)) => RelativeDateOption(Some(*x)),
+                _ => RelativeDateOption(None),
+            };
+
+            let mut presets = RELATIVE_DATE_PRESETS
+                .iter()
+                .map(|x| RelativeDateOption(Some(*x)))
+                .collect::<Vec<_>>();
+
+            if selected.0.is_some() && !presets.contains(&selected) {
+                presets.push(selected);
+            }
+
+            let values = std::iter::once(RelativeDateOption(None))
+                .chain(presets)
+                .map(SelectItem::Option)
+                .collect::<Vec<_>>();
+
+            html! {
+                <Select<RelativeDateOption>
+                    class="filterop-selector relative-date-selector"
+                    values={ values }
+                    selected={ selected }
+                    on_select={ ctx.link().callback(FilterColumnMsg::RelativeDateSelect) }>
+                </Select<RelativeDateOption>>
+            }
+        } else {
+            html! {}
+        };
+
         html! {
             <div
                 class="pivot-column-draggable"
@@ -476,7 +952,7 @@ impl Component for FilterColumn {
                 <LocalStyle href={ css!("filter-item") } />
                 <div class="pivot-column-border">
                     <span class="column_name string">
-                        { filter.0.to_owned() }
+                        { ctx.props().get_column_label() }
                     </span>
                     <FilterOpSelector
                         class="filterop-selector"
@@ -484,19 +960,39 @@ impl Component for FilterColumn {
                         selected={ filter.1 }
                         on_select={ select }>
                     </FilterOpSelector>
+                    { relative_date_select }
 
                     if !matches!(&filter.1, FilterOp::IsNotNull | FilterOp::IsNull) {
-                        if col_type == Some(Type::Bool) {
+                        if (col_type == Some(Type::Bool) && !filter.1.is_top_n())
+                            || filter.1 == FilterOp::Between
+                        {
                             { input_elem }
                         } else {
                             <label
                                 class={ format!("input-sizer {}", type_class) }
-                                data-value={ format!("{}", filter.2) }>
+                                data-value={ self.input.clone() }>
                                 { input_elem }
                             </label>
                         }
                     }
+                    { top_n_controls }
+                    { error }
+                    if is_in {
+                        <span
+                            class={ if self.show_checklist { "filter-values-toggle open" } else { "filter-values-toggle" } }
+                            onmousedown={ toggle_checklist }>
+                        </span>
+                    }
                 </div>
+                if is_in && self.show_checklist {
+                    <FilterValuesChecklist
+                        column={ filter.0.clone() }
+                        selected={ selected_values }
+                        on_change={ ctx.link().callback(FilterColumnMsg::ChecklistChange) }
+                        on_paste={ ctx.link().callback(FilterColumnMsg::ChecklistPaste) }
+                        session={ &ctx.props().session }>
+                    </FilterValuesChecklist>
+                }
             </div>
         }
     }
@@ -0,0 +1,243 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen::JsCast;
+use web_sys::*;
+use yew::prelude::*;
+
+use crate::config::*;
+use crate::session::*;
+use crate::*;
+
+/// The number of distinct values fetched per page.
+const PAGE_SIZE: usize = 50;
+
+#[derive(Properties, Clone)]
+pub struct FilterValuesChecklistProps {
+    pub column: String,
+    pub selected: Vec<Scalar>,
+    pub on_change: Callback<Vec<Scalar>>,
+
+    /// Called with the lines of a multi-line paste into the search `<input>`,
+    /// which should be added to the selection.
+    pub on_paste: Callback<Vec<String>>,
+    pub session: Session,
+}
+
+impl PartialEq for FilterValuesChecklistProps {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.column == rhs.column && self.selected == rhs.selected
+    }
+}
+
+pub enum FilterValuesChecklistMsg {
+    Search(String),
+    LoadMore,
+    Fetched(String, ColumnValuesQuery, ColumnValues),
+    FetchError(String, ColumnValuesQuery, String),
+    Toggle(Scalar),
+    Paste(ClipboardEvent),
+}
+
+/// A searchable, paged list of a column's distinct values (with row counts),
+/// each with a checkbox which toggles its membership in an `in` or `not in`
+/// filter's term.
+pub struct FilterValuesChecklist {
+    search: String,
+    values: Vec<ColumnValue>,
+    total: usize,
+    error: Option<String>,
+}
+
+impl FilterValuesChecklist {
+    fn fetch(&self, ctx: &Context<Self>, offset: usize) {
+        let query = ColumnValuesQuery {
+            search: self.search.clone(),
+            offset,
+            limit: Some(PAGE_SIZE),
+        };
+
+        clone!(ctx.props().session, ctx.props().column);
+        ctx.link().send_future_batch(async move {
+            match session.get_column_values(&column, &query).await {
+                Ok(values) => vec![FilterValuesChecklistMsg::Fetched(column, query, values)],
+                Err(err) => {
+                    let err = JsValue::from(err);
+                    let message = err
+                        .dyn_ref::<js_sys::Error>()
+                        .map(|x| String::from(x.message()))
+                        .or_else(|| err.as_string())
+                        .unwrap_or_else(|| format!("{:?}", err));
+
+                    vec![FilterValuesChecklistMsg::FetchError(column, query, message)]
+                }
+            }
+        });
+    }
+}
+
+impl Component for FilterValuesChecklist {
+    type Message = FilterValuesChecklistMsg;
+    type Properties = FilterValuesChecklistProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let this = Self {
+            search: "".to_owned(),
+            values: vec![],
+            total: 0,
+            error: None,
+        };
+
+        this.fetch(ctx, 0);
+        this
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().column != old_props.column {
+            self.search.clear();
+            self.values.clear();
+            self.total = 0;
+            self.error = None;
+            self.fetch(ctx, 0);
+        }
+
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            FilterValuesChecklistMsg::Search(search) => {
+                self.search = search;
+                self.fetch(ctx, 0);
+                false
+            }
+            FilterValuesChecklistMsg::LoadMore => {
+                self.fetch(ctx, self.values.len());
+                false
+            }
+            FilterValuesChecklistMsg::Fetched(column, query, values) => {
+                // Responses to a stale column or search, or for a page already
+                // loaded, are ignored.
+                if column != ctx.props().column
+                    || query.search != self.search
+                    || (query.offset != 0 && query.offset != self.values.len())
+                {
+                    return false;
+                }
+
+                if query.offset == 0 {
+                    self.values.clear();
+                }
+
+                self.values.extend(values.values);
+                self.total = values.total;
+                self.error = None;
+                true
+            }
+            FilterValuesChecklistMsg::FetchError(column, query, error) => {
+                if column != ctx.props().column || query.search != self.search {
+                    return false;
+                }
+
+                self.error = Some(error);
+                true
+            }
+            FilterValuesChecklistMsg::Toggle(value) => {
+                let mut selected = ctx.props().selected.clone();
+                if let Some(idx) = selected.iter().position(|x| *x == value) {
+                    selected.remove(idx);
+                } else {
+                    selected.push(value);
+                }
+
+                ctx.props().on_change.emit(selected);
+                false
+            }
+            FilterValuesChecklistMsg::Paste(event) => {
+                let text = event
+                    .clipboard_data()
+                    .and_then(|x| x.get_data("text").ok())
+                    .unwrap_or_default();
+
+                if text.contains('\n') {
+                    event.prevent_default();
+                    let lines = text
+                        .lines()
+                        .map(|x| x.trim().to_owned())
+                        .filter(|x| !x.is_empty())
+                        .collect();
+
+                    ctx.props().on_paste.emit(lines);
+                }
+
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let oninput = ctx.link().callback(|event: InputEvent| {
+            FilterValuesChecklistMsg::Search(
+                event
+                    .target()
+                    .unwrap()
+                    .unchecked_into::<HtmlInputElement>()
+                    .value(),
+            )
+        });
+
+        let onpaste = ctx
+            .link()
+            .callback(|event: Event| FilterValuesChecklistMsg::Paste(event.unchecked_into()));
+        let values = self.values.iter().map(|x| {
+            let onchange = ctx.link().callback({
+                let value = x.value.clone();
+                move |_: Event| FilterValuesChecklistMsg::Toggle(value.clone())
+            });
+
+            html! {
+                <label class="filter-value">
+                    <input
+                        type="checkbox"
+                        checked={ ctx.props().selected.contains(&x.value) }
+                        onchange={ onchange } />
+                    <span class="filter-value-label">{ &x.label }</span>
+                    <span class="filter-value-count">{ x.count }</span>
+                </label>
+            }
+        });
+
+        let load_more = ctx.link().callback(|_| FilterValuesChecklistMsg::LoadMore);
+        html! {
+            <div class="filter-values-checklist">
+                <input
+                    type="search"
+                    class="filter-values-search"
+                    placeholder="Search, or paste one value per line"
+                    value={ self.search.clone() }
+                    oninput={ oninput }
+                    onpaste={ onpaste } />
+                if let Some(error) = &self.error {
+                    <span class="filter-input-error">{ error }</span>
+                }
+                <div class="filter-values-list">
+                    { for values }
+                    if self.values.len() < self.total {
+                        <button class="filter-values-more" onclick={ load_more }>
+                            { format!("Show more ({} of {})", self.values.len(), self.total) }
+                        </button>
+                    }
+                </div>
+            </div>
+        }
+    }
+}
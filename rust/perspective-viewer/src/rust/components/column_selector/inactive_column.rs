@@ -172,6 +172,12 @@ impl Component for InactiveColumn {
             .metadata()
             .is_column_expression(&ctx.props().name);
 
+        let renderer = &ctx.props().renderer;
+        let display_name = renderer.get_column_display_name(&ctx.props().name);
+        let title = renderer
+            .get_column_attributes(&ctx.props().name)
+            .and_then(|x| x.description);
+
         let is_active_class = ctx.props().renderer.metadata().mode.css();
         let mut class = classes!("column-selector-column");
         if !ctx.props().visible {
@@ -201,8 +207,8 @@ impl Component for InactiveColumn {
                     { ondragend }>
 
                     <div class="column-selector-column-border">
-                        <span class={ format!("column_name {}", col_type) }>
-                            { &ctx.props().name }
+                        <span class={ format!("column_name {}", col_type) } { title }>
+                            { display_name }
                         </span>
                         <span class="column-selector--spacer"></span>
 
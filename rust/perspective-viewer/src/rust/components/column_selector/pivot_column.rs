@@ -10,24 +10,139 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::fmt::Display;
+
+use itertools::Itertools;
 use web_sys::*;
 use yew::prelude::*;
 
 use crate::components::containers::dragdrop_list::*;
+use crate::components::containers::select::*;
+use crate::config::{ColumnAttributes, DateBucketUnit, Type, DATE_BUCKET_HIERARCHY};
 use crate::dragdrop::*;
+use crate::session::Session;
 
 pub struct PivotColumn {}
 
+/// The numbers of equal width bins offered for numeric columns.
+const NUMERIC_BIN_COUNTS: [usize; 3] = [5, 10, 20];
+
+/// A way to bucket a `group_by` or `split_by` column, for the `<select>`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum BucketOption {
+    /// The column's values as-is.
+    #[default]
+    None,
+
+    /// A `DateBucket` per unit, or many for a hierarchy.
+    Date(Vec<DateBucketUnit>),
+
+    /// `NumericBins` of equal width, by count.
+    Bins(usize),
+}
+
+impl Display for BucketOption {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(fmt, "all values"),
+            Self::Date(units) => write!(fmt, "{}", units.iter().join(" › ")),
+            Self::Bins(count) => write!(fmt, "{} bins", count),
+        }
+    }
+}
+
+impl BucketOption {
+    /// The options for, and current bucket of, a `group_by` or `split_by`
+    /// entry `column`, or `None` if it can't be bucketed.
+    pub fn for_column(session: &Session, column: &str) -> Option<(Vec<Self>, Self)> {
+        match session.get_pivot_source_type(column)? {
+            ty @ (Type::Date | Type::Datetime) => {
+                let units = DateBucketUnit::options(ty == Type::Datetime);
+                let options = std::iter::once(Self::None)
+                    .chain(units.map(|unit| Self::Date(vec![unit])))
+                    .chain(std::iter::once(Self::Date(DATE_BUCKET_HIERARCHY.to_vec())))
+                    .collect();
+
+                let selected = session
+                    .get_date_bucket(column)
+                    .map(|x| Self::Date(vec![x.unit]))
+                    .unwrap_or_default();
+
+                Some((options, selected))
+            }
+            Type::Integer | Type::Float => {
+                let selected = session
+                    .get_numeric_bins(column)
+                    .map(|x| Self::Bins(x.count()))
+                    .unwrap_or_default();
+
+                let mut options = std::iter::once(Self::None)
+                    .chain(NUMERIC_BIN_COUNTS.into_iter().map(Self::Bins))
+                    .collect::<Vec<_>>();
+
+                if !options.contains(&selected) {
+                    options.push(selected.clone());
+                }
+
+                Some((options, selected))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Properties)]
 pub struct PivotColumnProps {
     pub column: String,
     pub dragdrop: DragDrop,
     pub action: DragTarget,
+
+    #[prop_or_default]
+    pub attributes: ColumnAttributes,
+
+    /// For (buckets of) `date`, `datetime` and numeric columns, the ways this
+    /// column can be bucketed.
+    #[prop_or_default]
+    pub buckets: Option<Vec<BucketOption>>,
+
+    /// This column's current bucket.
+    #[prop_or_default]
+    pub bucket: BucketOption,
+
+    #[prop_or_default]
+    pub on_bucket: Callback<BucketOption>,
 }
 
 impl PartialEq for PivotColumnProps {
     fn eq(&self, other: &Self) -> bool {
-        self.column == other.column && self.action == other.action
+        self.column == other.column
+            && self.action == other.action
+            && self.attributes == other.attributes
+            && self.buckets == other.buckets
+            && self.bucket == other.bucket
+    }
+}
+
+impl PivotColumnProps {
+    fn bucket_select(&self) -> Html {
+        let Some(buckets) = &self.buckets else {
+            return html! {};
+        };
+
+        let values = buckets
+            .iter()
+            .cloned()
+            .map(SelectItem::Option)
+            .collect::<Vec<_>>();
+
+        html! {
+            <Select<BucketOption>
+                class="bucket-selector"
+                { values }
+                selected={ self.bucket.clone() }
+                on_select={ self.on_bucket.clone() }>
+            </Select<BucketOption>>
+        }
     }
 }
 
@@ -66,9 +181,12 @@ impl Component for PivotColumn {
                 ondragstart={ dragstart }
                 ondragend={ dragend }>
                 <div class="pivot-column-border">
-                    <span class="column_name string">
-                        { ctx.props().column.clone() }
+                    <span
+                        class="column_name string"
+                        title={ ctx.props().attributes.description.clone() }>
+                        { ctx.props().attributes.display_name(&ctx.props().column) }
                     </span>
+                    { ctx.props().bucket_select() }
                 </div>
             </div>
         }
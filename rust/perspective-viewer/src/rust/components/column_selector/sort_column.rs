@@ -14,6 +14,7 @@ use web_sys::*;
 use yew::prelude::*;
 
 use crate::components::containers::dragdrop_list::*;
+use crate::components::viewer::ColumnLocator;
 use crate::config::*;
 use crate::dragdrop::*;
 use crate::model::*;
@@ -26,13 +27,14 @@ use crate::*;
 /// button which cycles through the available `SortDir` states.
 pub struct SortColumn {}
 
-#[derive(Properties)]
+#[derive(Clone, Properties)]
 pub struct SortColumnProps {
     pub sort: Sort,
     pub idx: usize,
     pub session: Session,
     pub renderer: Renderer,
     pub dragdrop: DragDrop,
+    pub on_open_expr_panel: Callback<ColumnLocator>,
 }
 
 impl PartialEq for SortColumnProps {
@@ -53,6 +55,7 @@ impl DragDropListItemProps for SortColumnProps {
 
 pub enum SortColumnMsg {
     SortDirClick(bool),
+    CustomOrderClick,
 }
 
 impl Component for SortColumn {
@@ -77,6 +80,29 @@ impl Component for SortColumn {
                 ApiFuture::spawn(ctx.props().update_and_render(update));
                 false
             }
+            SortColumnMsg::CustomOrderClick => {
+                let props = ctx.props().clone();
+                if let Some(order) = props.session.get_custom_order(&props.sort.0) {
+                    props
+                        .on_open_expr_panel
+                        .emit(ColumnLocator::Expr(Some(order.alias)));
+                } else {
+                    ApiFuture::spawn(async move {
+                        let order = props
+                            .session
+                            .get_default_custom_order(&props.sort.0)
+                            .await?;
+                        let update = props.session.create_custom_order_update(props.idx, &order);
+                        props.update_and_render(update).await?;
+                        props
+                            .on_open_expr_panel
+                            .emit(ColumnLocator::Expr(Some(order.alias)));
+                        Ok(())
+                    });
+                }
+
+                false
+            }
         }
     }
 
@@ -85,6 +111,11 @@ impl Component for SortColumn {
             .link()
             .callback(|event: MouseEvent| SortColumnMsg::SortDirClick(event.shift_key()));
 
+        let session = &ctx.props().session;
+        let column = &ctx.props().sort.0;
+        let is_custom_order = session.get_custom_order(column).is_some();
+        let on_custom_order = ctx.link().callback(|_| SortColumnMsg::CustomOrderClick);
+
         let dragstart = Callback::from({
             let event_name = ctx.props().sort.0.to_owned();
             let dragdrop = ctx.props().dragdrop.clone();
@@ -111,6 +142,13 @@ impl Component for SortColumn {
                         class="column_name string">
                         { ctx.props().sort.0.to_owned() }
                     </span>
+                    if is_custom_order || session.is_custom_orderable(column) {
+                        <span
+                            class={ classes!("custom-order-icon", is_custom_order.then_some("active")) }
+                            title="Custom order"
+                            onmousedown={ on_custom_order }>
+                        </span>
+                    }
                     <span
                         class={ format!("sort-icon {}", ctx.props().sort.1) }
                         onmousedown={ onclick }>
@@ -10,8 +10,12 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod attributes_editor;
 mod attributes_tab;
+mod bins_tab;
+mod order_tab;
 mod style_tab;
+mod style_toolbar;
 
 use std::fmt::Display;
 
@@ -20,6 +24,8 @@ use yew::{function_component, html, Callback, Html, Properties};
 use super::containers::tablist::Tab;
 use super::viewer::ColumnLocator;
 use crate::components::column_settings_sidebar::attributes_tab::AttributesTab;
+use crate::components::column_settings_sidebar::bins_tab::BinsTab;
+use crate::components::column_settings_sidebar::order_tab::OrderTab;
 use crate::components::column_settings_sidebar::style_tab::StyleTab;
 use crate::components::containers::sidebar::Sidebar;
 use crate::components::containers::tablist::TabList;
@@ -35,6 +41,8 @@ pub enum ColumnSettingsTab {
     #[default]
     Attributes,
     Style,
+    Bins,
+    Order,
 }
 impl Display for ColumnSettingsTab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -67,17 +75,28 @@ pub fn ColumnSettingsSidebar(p: &ColumnSettingsProps) -> Html {
     let column_type = p.session.metadata().get_column_view_type(&column_name);
     let is_active = column_type.is_some();
 
-    let mut tabs = vec![];
-
-    // Eventually the Attributes tab will have more properties than
-    // just the expression editor. Once that happens, stop hiding it.
-    if matches!(p.selected_column, ColumnLocator::Expr(_)) {
-        tabs.push(ColumnSettingsTab::Attributes);
-    }
+    let mut tabs = vec![ColumnSettingsTab::Attributes];
     if !matches!(p.selected_column, ColumnLocator::Expr(None)) && is_active {
         tabs.push(ColumnSettingsTab::Style);
     }
 
+    let (bins, order) = match &p.selected_column {
+        ColumnLocator::Expr(Some(alias)) => (
+            p.session.get_numeric_bins(alias),
+            p.session.get_custom_order(alias),
+        ),
+        _ => (None, None),
+    };
+
+    if bins.is_some() {
+        tabs.push(ColumnSettingsTab::Bins);
+    }
+
+    // A custom order is edited from its sort pill, so open on its values.
+    if order.is_some() {
+        tabs.insert(0, ColumnSettingsTab::Order);
+    }
+
     let title = format!("Editing ‘{column_name}’...");
 
     clone!(
@@ -86,7 +105,9 @@ pub fn ColumnSettingsSidebar(p: &ColumnSettingsProps) -> Html {
         p.session,
         p.renderer,
         p.presentation,
-        column_name
+        column_name,
+        bins,
+        order
     );
     let match_fn = Callback::from(move |tab| {
         clone!(
@@ -95,23 +116,19 @@ pub fn ColumnSettingsSidebar(p: &ColumnSettingsProps) -> Html {
             session,
             renderer,
             presentation,
-            column_name
+            column_name,
+            bins,
+            order
         );
         match tab {
-            ColumnSettingsTab::Attributes => {
-                let selected_column = match selected_column {
-                    ColumnLocator::Expr(s) => s,
-                    _ => panic!("Tried to open Attributes tab for non-expression column!"),
-                };
-                html! {
-                    <AttributesTab
-                        { selected_column }
-                        { on_close }
-                        { session }
-                        { renderer }
-                    />
-                }
-            }
+            ColumnSettingsTab::Attributes => html! {
+                <AttributesTab
+                    { selected_column }
+                    { on_close }
+                    { session }
+                    { renderer }
+                />
+            },
             ColumnSettingsTab::Style => html! {
                 <StyleTab
                     { column_name }
@@ -120,6 +137,16 @@ pub fn ColumnSettingsSidebar(p: &ColumnSettingsProps) -> Html {
                     { presentation }
                 />
             },
+            ColumnSettingsTab::Bins => html! {
+                if let Some(bins) = bins {
+                    <BinsTab { bins } { session } { renderer } />
+                }
+            },
+            ColumnSettingsTab::Order => html! {
+                if let Some(order) = order {
+                    <OrderTab { order } { session } { renderer } />
+                }
+            },
         }
     });
 
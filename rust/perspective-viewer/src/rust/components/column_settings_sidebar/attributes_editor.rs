@@ -0,0 +1,171 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Display;
+
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::components::containers::select::*;
+use crate::components::style::LocalStyle;
+use crate::config::*;
+use crate::model::*;
+use crate::renderer::Renderer;
+use crate::session::Session;
+use crate::utils::ApiFuture;
+use crate::{css, derive_model, html_template};
+
+#[derive(PartialEq, Clone, Properties)]
+pub struct ColumnAttributesEditorProps {
+    pub column_name: String,
+    pub session: Session,
+    pub renderer: Renderer,
+}
+
+derive_model!(Renderer, Session for ColumnAttributesEditorProps);
+
+#[derive(Clone, Copy, PartialEq)]
+enum AttributeField {
+    Label,
+    Description,
+    Units,
+}
+
+impl AttributeField {
+    fn get_mut(self, attributes: &mut ColumnAttributes) -> &mut Option<String> {
+        match self {
+            Self::Label => &mut attributes.label,
+            Self::Description => &mut attributes.description,
+            Self::Units => &mut attributes.units,
+        }
+    }
+}
+
+/// A `ShowValuesAs` option for the `<select>`, which may be unset.
+#[derive(Clone, Copy, PartialEq)]
+struct ShowValuesAsOption(Option<ShowValuesAs>);
+
+impl Display for ShowValuesAsOption {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(x) => write!(fmt, "{}", x),
+            None => write!(fmt, "values"),
+        }
+    }
+}
+
+/// Edits a column's `ColumnAttributes`, its display label, description and
+/// units, and (for numeric columns) how its values are shown.  Each field is
+/// applied when its input is committed (on blur or "Enter"), and an empty field
+/// clears that attribute.
+#[function_component]
+pub fn ColumnAttributesEditor(p: &ColumnAttributesEditorProps) -> Html {
+    let attributes = p
+        .renderer
+        .get_column_attributes(&p.column_name)
+        .unwrap_or_default();
+
+    let on_change = |field: AttributeField| {
+        let p = p.clone();
+        Callback::from(move |event: Event| {
+            let value = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>()
+                .value();
+
+            let mut attributes = p
+                .renderer
+                .get_column_attributes(&p.column_name)
+                .unwrap_or_default();
+
+            let value = value.trim();
+            *field.get_mut(&mut attributes) = (!value.is_empty()).then(|| value.to_owned());
+            ApiFuture::spawn(p.set_column_attributes(&p.column_name, Some(attributes)));
+        })
+    };
+
+    let on_show_values_as = {
+        let p = p.clone();
+        Callback::from(move |ShowValuesAsOption(show_values_as)| {
+            let mut attributes = p
+                .renderer
+                .get_column_attributes(&p.column_name)
+                .unwrap_or_default();
+
+            attributes.show_values_as = show_values_as;
+            ApiFuture::spawn(p.set_column_attributes(&p.column_name, Some(attributes)));
+        })
+    };
+
+    let is_numeric = matches!(
+        p.session.metadata().get_column_view_type(&p.column_name),
+        Some(Type::Integer | Type::Float)
+    );
+
+    let show_values_as_options = std::iter::once(ShowValuesAsOption(None))
+        .chain(SHOW_VALUES_AS.iter().map(|x| ShowValuesAsOption(Some(*x))))
+        .map(SelectItem::Option)
+        .collect::<Vec<_>>();
+
+    html_template! {
+        <LocalStyle href={ css!("column-style") } />
+        <div id="column-style-container" class="column-attributes-container">
+            <div class="column-style-label">
+                <label>{ "Label" }</label>
+            </div>
+            <div class="row section">
+                <input
+                    id="column-label"
+                    class="parameter"
+                    type="text"
+                    placeholder={ p.column_name.clone() }
+                    value={ attributes.label.clone().unwrap_or_default() }
+                    onchange={ on_change(AttributeField::Label) } />
+            </div>
+            <div class="column-style-label">
+                <label>{ "Description" }</label>
+            </div>
+            <div class="row section">
+                <input
+                    id="column-description"
+                    class="parameter"
+                    type="text"
+                    value={ attributes.description.clone().unwrap_or_default() }
+                    onchange={ on_change(AttributeField::Description) } />
+            </div>
+            <div class="column-style-label">
+                <label>{ "Units" }</label>
+            </div>
+            <div class="row section">
+                <input
+                    id="column-units"
+                    class="parameter"
+                    type="text"
+                    value={ attributes.units.clone().unwrap_or_default() }
+                    onchange={ on_change(AttributeField::Units) } />
+            </div>
+            if is_numeric {
+                <div class="column-style-label">
+                    <label>{ "Show Values As" }</label>
+                </div>
+                <div class="row section">
+                    <Select<ShowValuesAsOption>
+                        id="column-show-values-as"
+                        values={ show_values_as_options }
+                        selected={ ShowValuesAsOption(attributes.show_values_as) }
+                        on_select={ on_show_values_as } />
+                </div>
+            }
+        </div>
+    }
+}
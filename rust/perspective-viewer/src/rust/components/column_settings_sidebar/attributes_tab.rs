@@ -13,7 +13,9 @@
 use wasm_bindgen::JsValue;
 use yew::{function_component, html, Callback, Html, Properties};
 
+use super::attributes_editor::ColumnAttributesEditor;
 use crate::components::expression_editor::ExpressionEditor;
+use crate::components::viewer::ColumnLocator;
 use crate::config::ViewConfigUpdate;
 use crate::model::UpdateAndRender;
 use crate::renderer::Renderer;
@@ -23,7 +25,7 @@ use crate::{derive_model, html_template};
 
 #[derive(PartialEq, Clone, Properties)]
 pub struct AttributesTabProps {
-    pub selected_column: Option<String>,
+    pub selected_column: ColumnLocator,
     pub on_close: Callback<()>,
     pub session: Session,
     pub renderer: Renderer,
@@ -36,8 +38,8 @@ pub fn AttributesTab(p: &AttributesTabProps) -> Html {
     let on_save = yew::use_callback(
         |v, p| {
             match &p.selected_column {
-                None => save_expr(v, p),
-                Some(alias) => update_expr(alias, &v, p),
+                ColumnLocator::Expr(Some(alias)) => update_expr(alias, &v, p),
+                _ => save_expr(v, p),
             }
 
             p.on_close.emit(());
@@ -54,7 +56,7 @@ pub fn AttributesTab(p: &AttributesTabProps) -> Html {
 
     let on_delete = yew::use_callback(
         |(), p| {
-            if let Some(ref s) = p.selected_column {
+            if let ColumnLocator::Expr(Some(ref s)) = p.selected_column {
                 delete_expr(s, p);
             }
 
@@ -63,16 +65,33 @@ pub fn AttributesTab(p: &AttributesTabProps) -> Html {
         p.clone(),
     );
 
+    let column_name = match &p.selected_column {
+        ColumnLocator::Plain(name) | ColumnLocator::Expr(Some(name)) => Some(name.clone()),
+        ColumnLocator::Expr(None) => None,
+    };
+
     html_template! {
         <div id="attributes-tab">
-            <div class="item_title">{"Expression Editor"}</div>
-            <ExpressionEditor
-                { on_save }
-                { on_validate }
-                { on_delete }
-                session = { &p.session }
-                alias = { p.selected_column.clone() }
-            />
+            if let ColumnLocator::Expr(alias) = &p.selected_column {
+                <div class="item_title">{"Expression Editor"}</div>
+                <ExpressionEditor
+                    { on_save }
+                    { on_validate }
+                    { on_delete }
+                    session = { &p.session }
+                    alias = { alias.clone() }
+                />
+            }
+            if let Some(column_name) = column_name {
+                <div class="item_title">{"Display"}</div>
+                <div class="style_contents">
+                    <ColumnAttributesEditor
+                        { column_name }
+                        session = { &p.session }
+                        renderer = { &p.renderer }
+                    />
+                </div>
+            }
         </div>
     }
 }
@@ -0,0 +1,182 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::components::containers::select::*;
+use crate::config::*;
+use crate::model::*;
+use crate::renderer::Renderer;
+use crate::session::Session;
+use crate::utils::*;
+use crate::{clone, derive_model, html_template};
+
+/// How the bins of a `NumericBins` column are specified in the editor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum BinsMode {
+    #[default]
+    Count,
+    Width,
+    Breakpoints,
+}
+
+impl BinsMode {
+    const ALL: [Self; 3] = [Self::Count, Self::Width, Self::Breakpoints];
+
+    /// The current `bins` as input text for this mode.
+    fn format(&self, bins: &NumericBins) -> String {
+        match self {
+            Self::Count => bins.count().to_string(),
+            Self::Width => match bins.breakpoints.as_slice() {
+                [a, b, ..] => (b - a).to_string(),
+                _ => String::new(),
+            },
+            Self::Breakpoints => bins
+                .breakpoints
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
+impl Display for BinsMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Count => "Number of bins",
+            Self::Width => "Fixed width",
+            Self::Breakpoints => "Breakpoints",
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct BinsTabProps {
+    pub bins: NumericBins,
+    pub session: Session,
+    pub renderer: Renderer,
+}
+
+derive_model!(Renderer, Session for BinsTabProps);
+
+impl BinsTabProps {
+    /// Recompute the bins of this column from `input` in `mode`, reading the
+    /// source column's current range for the `Count` and `Width` modes.
+    /// Invalid `input` is returned as the inner `Err` message.
+    async fn apply(&self, mode: BinsMode, input: &str) -> ApiResult<Result<(), String>> {
+        let source = &self.bins.source;
+        let input = input.trim();
+        let range = match mode {
+            BinsMode::Breakpoints => (0.0, 0.0),
+            BinsMode::Count | BinsMode::Width => self
+                .session
+                .get_column_range(source)
+                .await?
+                .unwrap_or_default(),
+        };
+
+        let bins = match mode {
+            BinsMode::Breakpoints => {
+                parse_breakpoints(input).map(|breakpoints| NumericBins::new(source, breakpoints))
+            }
+            BinsMode::Count => usize::from_str(input)
+                .map_err(|_| format!("Not a number of bins: \"{input}\""))
+                .and_then(|count| NumericBins::with_count(source, range, count)),
+            BinsMode::Width => f64::from_str(input)
+                .map_err(|_| format!("Not a bin width: \"{input}\""))
+                .and_then(|width| NumericBins::with_width(source, range, width)),
+        };
+
+        match bins {
+            Ok(bins) => {
+                let update = self.session.create_replace_numeric_bins_update(&bins);
+                self.update_and_render(update).await?;
+                Ok(Ok(()))
+            }
+            Err(err) => Ok(Err(err)),
+        }
+    }
+}
+
+/// Edits the breakpoints of a numeric bins column created from a `group_by`
+/// or `split_by` pill.
+#[function_component]
+pub fn BinsTab(p: &BinsTabProps) -> Html {
+    let mode = use_state_eq(BinsMode::default);
+    let input = use_state_eq(|| mode.format(&p.bins));
+    let error = use_state_eq(|| None::<String>);
+
+    let on_mode = use_callback(
+        |value: BinsMode, (bins, mode, input, error)| {
+            mode.set(value);
+            input.set(value.format(bins));
+            error.set(None);
+        },
+        (p.bins.clone(), mode.clone(), input.clone(), error.clone()),
+    );
+
+    let on_input = use_callback(
+        |event: InputEvent, input| {
+            let target = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+
+            input.set(target.value());
+        },
+        input.clone(),
+    );
+
+    let on_apply = use_callback(
+        |_: MouseEvent, (p, mode, input, error)| {
+            clone!(p, mode, input, error);
+            ApiFuture::spawn(async move {
+                error.set(p.apply(*mode, &input).await?.err());
+                Ok(())
+            });
+        },
+        (p.clone(), mode.clone(), input.clone(), error.clone()),
+    );
+
+    let labels = p.bins.labels();
+    html_template! {
+        <div class="item_title">{ format!("Bins of ‘{}’", p.bins.source) }</div>
+        <div id="bins-editor" class="style_contents">
+            <div class="bins-editor-row">
+                <Select<BinsMode>
+                    id="bins-mode-selector"
+                    values={ BinsMode::ALL.iter().cloned().map(SelectItem::Option).collect::<Vec<_>>() }
+                    selected={ *mode }
+                    on_select={ on_mode } />
+            </div>
+            <div class="bins-editor-row">
+                <input
+                    id="bins-input"
+                    type="text"
+                    value={ (*input).clone() }
+                    oninput={ on_input } />
+                <button id="apply-bins" onclick={ on_apply }>{ "Apply" }</button>
+            </div>
+            if let Some(err) = &*error {
+                <div class="bins-editor-error">{ err }</div>
+            }
+            <ul class="bins-editor-labels">
+                { for labels.iter().map(|label| html! { <li>{ label }</li> }) }
+            </ul>
+        </div>
+    }
+}
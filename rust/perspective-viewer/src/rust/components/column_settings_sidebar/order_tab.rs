@@ -0,0 +1,244 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use yew::prelude::*;
+
+use crate::components::column_selector::InPlaceColumn;
+use crate::components::containers::dragdrop_list::*;
+use crate::components::style::LocalStyle;
+use crate::config::*;
+use crate::dragdrop::*;
+use crate::model::*;
+use crate::renderer::Renderer;
+use crate::session::Session;
+use crate::utils::*;
+use crate::{clone, css, derive_model, html_template};
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct OrderTabProps {
+    pub order: CustomOrder,
+    pub session: Session,
+    pub renderer: Renderer,
+}
+
+derive_model!(Renderer, Session for OrderTabProps);
+
+pub enum OrderTabMsg {
+    SetValues(Vec<String>),
+    DragOver(usize),
+    DragLeave,
+    Drop(String, usize),
+    MoveToEnd(usize),
+}
+
+/// Reorders the values of a `CustomOrder` column by drag/drop, updating its
+/// expression after each move.  Values are dragged with a `DragDrop` private
+/// to this editor, so they can't be dropped elsewhere in the viewer.
+pub struct OrderTab {
+    dragdrop: DragDrop,
+    values: Option<Vec<String>>,
+    _subscription: Subscription,
+}
+
+struct CustomOrderDragContext {}
+
+impl DragContext<OrderTabMsg> for CustomOrderDragContext {
+    fn dragenter(index: usize) -> OrderTabMsg {
+        OrderTabMsg::DragOver(index)
+    }
+
+    fn close(index: usize) -> OrderTabMsg {
+        OrderTabMsg::MoveToEnd(index)
+    }
+
+    fn dragleave() -> OrderTabMsg {
+        OrderTabMsg::DragLeave
+    }
+
+    fn create(_col: InPlaceColumn) -> OrderTabMsg {
+        unreachable!("Custom order lists have no `column_dropdown`")
+    }
+
+    fn is_self_move(target: DragTarget) -> bool {
+        target == DragTarget::CustomOrder
+    }
+}
+
+type CustomOrderSelector = DragDropList<OrderTab, OrderValue, CustomOrderDragContext>;
+
+impl OrderTab {
+    fn fetch_values(ctx: &Context<Self>) {
+        clone!(ctx.props().session, ctx.props().order);
+        let link = ctx.link().clone();
+        ApiFuture::spawn(async move {
+            let values = session.get_custom_order_values(&order).await?;
+            link.send_message(OrderTabMsg::SetValues(values));
+            Ok(())
+        });
+    }
+
+    fn apply(&self, ctx: &Context<Self>) {
+        if let Some(values) = &self.values {
+            let order = CustomOrder::new(&ctx.props().order.source, values.iter().cloned());
+            let update = ctx
+                .props()
+                .session
+                .create_replace_custom_order_update(&order);
+            ApiFuture::spawn(ctx.props().update_and_render(update));
+        }
+    }
+}
+
+impl Component for OrderTab {
+    type Message = OrderTabMsg;
+    type Properties = OrderTabProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let dragdrop = DragDrop::default();
+        let cb = ctx
+            .link()
+            .callback(|x: (String, DragTarget, DragEffect, usize)| OrderTabMsg::Drop(x.0, x.3));
+
+        let _subscription = dragdrop.drop_received.add_listener(cb);
+        Self::fetch_values(ctx);
+        Self {
+            dragdrop,
+            values: None,
+            _subscription,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old: &Self::Properties) -> bool {
+        if ctx.props().order.source != old.order.source {
+            self.values = None;
+            Self::fetch_values(ctx);
+        }
+
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            OrderTabMsg::SetValues(values) => {
+                self.values = Some(values);
+                true
+            }
+            OrderTabMsg::DragOver(index) => self
+                .dragdrop
+                .notify_drag_enter(DragTarget::CustomOrder, index),
+            OrderTabMsg::DragLeave => {
+                self.dragdrop.notify_drag_leave(DragTarget::CustomOrder);
+                true
+            }
+            OrderTabMsg::Drop(value, index) => {
+                if let Some(values) = &mut self.values {
+                    values.retain(|x| *x != value);
+                    let index = std::cmp::min(index, values.len());
+                    values.insert(index, value);
+                    self.apply(ctx);
+                }
+
+                true
+            }
+            OrderTabMsg::MoveToEnd(index) => {
+                if let Some(values) = &mut self.values && index < values.len() {
+                    let value = values.remove(index);
+                    values.push(value);
+                    self.apply(ctx);
+                }
+
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let is_dragover = self.dragdrop.is_dragover(DragTarget::CustomOrder);
+        html_template! {
+            <LocalStyle href={ css!("config-selector") } />
+            <div class="item_title">
+                { format!("Order of ‘{}’", ctx.props().order.source) }
+            </div>
+            <div id="custom-order-editor" class="style_contents">
+                if let Some(values) = &self.values {
+                    <CustomOrderSelector
+                        name="custom_order"
+                        parent={ ctx.link().clone() }
+                        dragdrop={ &self.dragdrop }
+                        { is_dragover }>
+                        {
+                            for values.iter().map(|value| html_nested! {
+                                <OrderValue
+                                    value={ value.clone() }
+                                    dragdrop={ &self.dragdrop }>
+                                </OrderValue>
+                            })
+                        }
+                    </CustomOrderSelector>
+                }
+            </div>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct OrderValueProps {
+    pub value: String,
+    pub dragdrop: DragDrop,
+}
+
+impl DragDropListItemProps for OrderValueProps {
+    type Item = String;
+
+    fn get_item(&self) -> String {
+        self.value.clone()
+    }
+}
+
+/// A draggable value in a `CustomOrder` editor.
+pub struct OrderValue {}
+
+impl Component for OrderValue {
+    type Message = ();
+    type Properties = OrderValueProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let dragstart = Callback::from({
+            clone!(ctx.props().value, ctx.props().dragdrop);
+            move |event: DragEvent| {
+                dragdrop.set_drag_image(&event).unwrap();
+                dragdrop.notify_drag_start(value.clone(), DragEffect::Move(DragTarget::CustomOrder))
+            }
+        });
+
+        let dragend = Callback::from({
+            clone!(ctx.props().dragdrop);
+            move |_event| dragdrop.notify_drag_end()
+        });
+
+        html! {
+            <div
+                class="pivot-column-draggable"
+                draggable="true"
+                ondragstart={ dragstart }
+                ondragend={ dragend }>
+                <div class="pivot-column-border">
+                    <span class="column_name string">{ &ctx.props().value }</span>
+                </div>
+            </div>
+        }
+    }
+}
@@ -10,27 +10,30 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use std::collections::HashMap;
 use std::fmt::Debug;
 
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen::JsValue;
 use yew::{function_component, html, Callback, Html, Properties};
 
+use super::style_toolbar::StyleToolbar;
+use crate::components::bool_column_style::BoolColumnStyle;
 use crate::components::datetime_column_style::DatetimeColumnStyle;
 use crate::components::number_column_style::NumberColumnStyle;
 use crate::components::string_column_style::StringColumnStyle;
 use crate::components::style::LocalStyle;
 use crate::config::{
+    BoolColumnStyleConfig, BoolColumnStyleDefaultConfig, ColumnStyleConfig,
     DatetimeColumnStyleConfig, DatetimeColumnStyleDefaultConfig, NumberColumnStyleConfig,
     NumberColumnStyleDefaultConfig, StringColumnStyleConfig, StringColumnStyleDefaultConfig, Type,
 };
+use crate::model::*;
 use crate::presentation::Presentation;
 use crate::renderer::Renderer;
 use crate::session::Session;
-use crate::utils::{ApiFuture, JsValueSerdeExt};
-use crate::{clone, css, html_template};
+use crate::utils::ApiFuture;
+use crate::{css, derive_model, html_template};
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct StyleTabProps {
@@ -40,6 +43,8 @@ pub struct StyleTabProps {
     pub column_name: String,
 }
 
+derive_model!(Renderer, Session, Presentation for StyleTabProps);
+
 #[derive(Serialize, Deserialize, Debug)]
 struct DefaultConfig {
     string: serde_json::Value,
@@ -50,37 +55,11 @@ struct DefaultConfig {
     bool: serde_json::Value,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct Config {
-    columns: HashMap<String, serde_json::Value>,
-}
-
-/// This function sends the config to the plugin using its `restore` method
-fn send_config<T: Serialize + Debug>(
-    renderer: &Renderer,
-    presentation: &Presentation,
-    view: JsValue,
-    column_name: String,
-    column_config: T,
-) {
-    let current_config = get_config(renderer);
-    let elem = renderer.get_active_plugin().unwrap();
-    if let Some((mut current_config, _)) = current_config {
-        current_config
-            .columns
-            .insert(column_name, serde_json::to_value(column_config).unwrap());
-        let js_config = JsValue::from_serde_ext(&current_config).unwrap();
-        elem.restore(&js_config);
-        ApiFuture::spawn(async move {
-            let view = view.unchecked_into();
-            elem.update(&view, None, None, false).await
-        });
-        // send a config update event in case we need to listen for it outside of the
-        // viewer
-        presentation.column_settings_updated.emit_all(js_config);
-    } else {
-        tracing::warn!("Could not restore and restyle plugin!");
-    }
+/// This function sets the column's style in the viewer-owned `column_config`,
+/// which pushes it to the plugin using its `restore` method.
+fn send_config<T: Into<ColumnStyleConfig>>(props: &StyleTabProps, column_config: T) {
+    let column_name = props.column_name.clone();
+    ApiFuture::spawn(props.set_column_styles([(column_name, Some(column_config.into()))]));
 }
 
 fn jsval_to_type<T: DeserializeOwned>(val: &JsValue) -> Result<T, serde_json::Error> {
@@ -91,81 +70,72 @@ fn jsval_to_type<T: DeserializeOwned>(val: &JsValue) -> Result<T, serde_json::Er
     serde_json::from_str(&stringval)
 }
 
-/// This function retrieves the plugin's config using its `save` method.
-/// It also introduces the `default_config` field for the plugin.
-/// If this field does not exist, the plugin is considered to be unstylable.
-fn get_config(renderer: &Renderer) -> Option<(Config, DefaultConfig)> {
+/// This function retrieves the plugin's `default_config` field.  If this field
+/// does not exist, the plugin is considered to be unstylable.
+fn get_default_config(renderer: &Renderer) -> Option<DefaultConfig> {
     let plugin = renderer.get_active_plugin().unwrap();
-    let config = plugin.save();
+    if !plugin.is_stylable() {
+        return None;
+    }
+
     let default_config = JsValue::from(plugin.default_config());
-    let config = jsval_to_type(&config).ok();
-    let default_config = jsval_to_type(&default_config).ok();
-    config.zip(default_config)
+    jsval_to_type(&default_config).ok()
 }
 
 fn get_column_config<
-    ConfigType: DeserializeOwned + Debug,
+    ConfigType: TryFrom<ColumnStyleConfig>,
     DefaultConfigType: DeserializeOwned + Debug,
 >(
     renderer: &Renderer,
     column_name: &str,
     ty: Type,
 ) -> Result<(Option<ConfigType>, DefaultConfigType), String> {
-    get_config(renderer)
-        .ok_or_else(|| "Could not get_config!".into())
-        .and_then(|(mut config, default_config)| {
-            let current_config = if let Some(config) = config.columns.remove(column_name) {
-                serde_json::from_value(config)
-                    .map_err(|e| format!("Could not deserialize config with error {e:?}"))?
-            } else {
-                None
-            };
-
-            let val = match ty {
-                Type::String => default_config.string,
-                Type::Datetime => default_config.datetime,
-                Type::Date => default_config.date,
-                Type::Integer => default_config.integer,
-                Type::Float => default_config.float,
-                Type::Bool => default_config.bool,
-            };
-            serde_json::from_value(val)
-                .map_err(|e| format!("Could not deserialize default_config with error {e:?}"))
-                .map(|default_config| (current_config, default_config))
-        })
+    let default_config = get_default_config(renderer).ok_or("Could not get default_config!")?;
+
+    // A style of the wrong type (e.g. if this column's aggregate has changed)
+    // is treated as no style, and will be replaced when edited.
+    let current_config = renderer
+        .get_column_style(column_name)
+        .and_then(|config| ConfigType::try_from(config).ok());
+
+    let val = match ty {
+        Type::String => default_config.string,
+        Type::Datetime => default_config.datetime,
+        Type::Date => default_config.date,
+        Type::Integer => default_config.integer,
+        Type::Float => default_config.float,
+        Type::Bool => default_config.bool,
+    };
+
+    serde_json::from_value(val)
+        .map_err(|e| format!("Could not deserialize default_config with error {e:?}"))
+        .map(|default_config| (current_config, default_config))
 }
 
 #[function_component]
 pub fn StyleTab(p: &StyleTabProps) -> Html {
+    let force_update = yew::use_force_update();
     let opts = p
         .session
         .metadata()
         .get_column_view_type(&p.column_name)
-        .zip(p.session.get_view());
+        .filter(|_| p.session.get_view().is_some());
     if opts.is_none() {
         return html! {};
     }
-    let (ty, view) = opts.unwrap();
-    let view = (*view).clone();
+    let ty = opts.unwrap();
     let title = format!("{} Styling", ty.to_capitalized());
 
-    clone!(p.renderer, p.presentation, p.column_name);
+    let props = p.clone();
+    let (renderer, column_name) = (&p.renderer, &p.column_name);
     let opt_html =
         match ty {
             Type::String => get_column_config::<
                 StringColumnStyleConfig,
                 StringColumnStyleDefaultConfig,
-            >(&renderer, &column_name, ty)
+            >(renderer, column_name, ty)
             .map(|(config, default_config)| {
-                let on_change = Callback::from(move |config| {
-                    send_config(
-                        &renderer,
-                        &presentation,
-                        view.clone(),
-                        column_name.clone(),
-                        config,
-                    );
-                });
+                let on_change = Callback::from(move |config| send_config(&props, config));
                 html_template! {
                     <div class="item_title">{title.clone()}</div>
                     <div class="style_contents">
@@ -176,17 +146,9 @@ pub fn StyleTab(p: &StyleTabProps) -> Html {
             Type::Datetime | Type::Date => get_column_config::<
                 DatetimeColumnStyleConfig,
                 DatetimeColumnStyleDefaultConfig,
-            >(&renderer, &column_name, ty)
+            >(renderer, column_name, ty)
             .map(|(config, default_config)| {
-                let on_change = Callback::from(move |config| {
-                    send_config(
-                        &renderer,
-                        &presentation,
-                        view.clone(),
-                        column_name.clone(),
-                        config,
-                    );
-                });
+                let on_change = Callback::from(move |config| send_config(&props, config));
                 html_template! {
                     <div class="item_title">{title.clone()}</div>
                     <div class="style_contents">
@@ -194,6 +156,7 @@ pub fn StyleTab(p: &StyleTabProps) -> Html {
                             enable_time_config={matches!(ty, Type::Datetime)}
                             { config }
                             {default_config}
+                            time_zone={ p.session.get_time_zone() }
                             {on_change}
                             />
                     </div>
@@ -202,17 +165,9 @@ pub fn StyleTab(p: &StyleTabProps) -> Html {
             Type::Integer | Type::Float => get_column_config::<
                 NumberColumnStyleConfig,
                 NumberColumnStyleDefaultConfig,
-            >(&renderer, &column_name, ty)
+            >(renderer, column_name, ty)
             .map(|(config, default_config)| {
-                let on_change = Callback::from(move |config| {
-                    send_config(
-                        &renderer,
-                        &presentation,
-                        view.clone(),
-                        column_name.clone(),
-                        config,
-                    );
-                });
+                let on_change = Callback::from(move |config| send_config(&props, config));
                 html_template! {
                     <div class="item_title">{title.clone()}</div>
                     <div class="style_contents">
@@ -220,10 +175,38 @@ pub fn StyleTab(p: &StyleTabProps) -> Html {
                     </div>
                 }
             }),
-            _ => Err("Booleans aren't styled yet.".into()),
+            Type::Bool => get_column_config::<BoolColumnStyleConfig, BoolColumnStyleDefaultConfig>(
+                renderer,
+                column_name,
+                ty,
+            )
+            .map(|(config, default_config)| {
+                let on_change = Callback::from(move |config| send_config(&props, config));
+                html_template! {
+                    <div class="item_title">{title.clone()}</div>
+                    <div class="style_contents">
+                        <BoolColumnStyle  { config } {default_config} {on_change} />
+                    </div>
+                }
+            }),
         };
     let inner = if let Ok(html) = opt_html {
-        html
+        // Re-render the style editor when the style is changed by the toolbar.
+        let on_change = Callback::from(move |_| force_update.force_update());
+        html_template! {
+            {html}
+            <div class="item_title">{"Copy & Presets"}</div>
+            <div class="style_contents">
+                <StyleToolbar
+                    session={p.session.clone()}
+                    renderer={p.renderer.clone()}
+                    presentation={p.presentation.clone()}
+                    column_name={p.column_name.clone()}
+                    {ty}
+                    {on_change}
+                />
+            </div>
+        }
     } else {
         // do the tracing logs
         tracing::warn!("{}", opt_html.unwrap_err());
@@ -0,0 +1,294 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use yew::prelude::*;
+
+use crate::config::*;
+use crate::model::*;
+use crate::presentation::Presentation;
+use crate::renderer::Renderer;
+use crate::session::Session;
+use crate::utils::*;
+use crate::{clone, derive_model, html_template};
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct StyleToolbarProps {
+    pub session: Session,
+    pub renderer: Renderer,
+    pub presentation: Presentation,
+    pub column_name: String,
+    pub ty: Type,
+
+    /// Fired when this column's style is changed from the toolbar, so the
+    /// style editor can be re-rendered.
+    pub on_change: Callback<()>,
+}
+
+derive_model!(Renderer, Session, Presentation for StyleToolbarProps);
+
+impl StyleToolbarProps {
+    /// The current style of this column, or the empty style for its type if
+    /// it is unstyled.
+    fn current_style(&self) -> Option<ColumnStyleConfig> {
+        self.renderer
+            .get_column_style(&self.column_name)
+            .filter(|style| style.is_compatible(self.ty))
+            .or_else(|| ColumnStyleConfig::from_value(self.ty, serde_json::json!({})).ok())
+    }
+
+    fn set_style(&self, style: ColumnStyleConfig) {
+        let column_name = self.column_name.clone();
+        ApiFuture::spawn(self.set_column_styles([(column_name, Some(style))]));
+        self.on_change.emit(());
+    }
+}
+
+/// Copy/paste and named presets for the style of a column, shown above the
+/// column's style editor.
+#[function_component]
+pub fn StyleToolbar(p: &StyleToolbarProps) -> Html {
+    let preset_name = use_state_eq(String::new);
+    let import_error = use_state_eq(|| None::<String>);
+    // Presets and the style clipboard are not props, so changing them must
+    // re-render explicitly.
+    let revision = use_state(|| 0_u32);
+    let force_update = use_force_update();
+    use_effect_with_deps(
+        |presentation| {
+            let sub = presentation
+                .style_presets_changed
+                .add_listener(move |_| force_update.force_update());
+
+            move || drop(sub)
+        },
+        p.presentation.clone(),
+    );
+
+    let on_copy = use_callback(
+        |_: MouseEvent, (p, revision)| {
+            if let Some(style) = p.current_style() {
+                p.presentation.set_style_clipboard(style);
+                revision.set(**revision + 1);
+            }
+        },
+        (p.clone(), revision.clone()),
+    );
+
+    let clipboard = p
+        .presentation
+        .get_style_clipboard()
+        .filter(|style| style.is_compatible(p.ty));
+
+    let on_paste = use_callback(
+        |_: MouseEvent, (p, clipboard)| {
+            if let Some(style) = clipboard {
+                p.set_style(style.clone());
+            }
+        },
+        (p.clone(), clipboard.clone()),
+    );
+
+    let on_apply_to_type = use_callback(
+        |_: MouseEvent, p| {
+            if let Some(style) = p.current_style() {
+                ApiFuture::spawn(p.apply_style_to_type(p.ty, style));
+                p.on_change.emit(());
+            }
+        },
+        p.clone(),
+    );
+
+    let presets = p
+        .presentation
+        .get_style_presets()
+        .into_iter()
+        .filter(|preset| preset.is_compatible(p.ty))
+        .collect::<Vec<_>>();
+
+    let on_select_preset = use_callback(
+        |event: Event, p| {
+            let select = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlSelectElement>();
+
+            let name = select.value();
+            select.set_selected_index(0);
+            let preset = p
+                .presentation
+                .get_style_presets()
+                .into_iter()
+                .find(|preset| preset.name == name);
+
+            if let Some(preset) = preset {
+                p.set_style(preset.style);
+            }
+        },
+        p.clone(),
+    );
+
+    let on_preset_name = use_callback(
+        |event: InputEvent, preset_name| {
+            let input = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+
+            preset_name.set(input.value());
+        },
+        preset_name.setter(),
+    );
+
+    let on_save_preset = use_callback(
+        |_: MouseEvent, (p, preset_name)| {
+            if let Some(style) = p.current_style() {
+                p.presentation.save_style_presets([ColumnStylePreset {
+                    name: preset_name.trim().to_owned(),
+                    ty: p.ty,
+                    style,
+                }]);
+
+                preset_name.set(String::new());
+            }
+        },
+        (p.clone(), preset_name.clone()),
+    );
+
+    let on_delete_preset = use_callback(
+        |_: MouseEvent, (presentation, preset_name)| {
+            presentation.delete_style_preset(preset_name.trim());
+            preset_name.set(String::new());
+        },
+        (p.presentation.clone(), preset_name.clone()),
+    );
+
+    let on_export = use_callback(
+        |_: MouseEvent, presentation| {
+            let result: ApiResult<()> = (|| {
+                let json = serde_json::to_string_pretty(&presentation.get_style_presets())?;
+                let blob = js_sys::JsString::from(json).as_blob()?;
+                download("column-style-presets.json", &blob)
+            })();
+
+            if let Err(err) = result {
+                tracing::warn!("Failed to export style presets: {:?}", err);
+            }
+        },
+        p.presentation.clone(),
+    );
+
+    let on_import = use_callback(
+        |event: Event, (presentation, import_error, revision)| {
+            let input = event
+                .target()
+                .unwrap()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+
+            let file = input.files().and_then(|files| files.get(0));
+            input.set_value("");
+            if let Some(file) = file {
+                clone!(presentation, import_error, revision);
+                ApiFuture::spawn(async move {
+                    let text = JsFuture::from(file.text()).await?.as_string();
+                    let presets = serde_json::from_str::<Vec<ColumnStylePreset>>(
+                        text.as_deref().unwrap_or_default(),
+                    );
+
+                    match presets {
+                        Ok(presets) => {
+                            presentation.save_style_presets(presets);
+                            import_error.set(None);
+                            revision.set(*revision + 1);
+                        }
+                        Err(err) => import_error.set(Some(err.to_string())),
+                    }
+
+                    Ok(())
+                });
+            }
+        },
+        (p.presentation.clone(), import_error.clone(), revision),
+    );
+
+    let all_presets = p.presentation.get_style_presets();
+    let has_presets = !all_presets.is_empty();
+    let is_existing_preset = all_presets
+        .iter()
+        .any(|preset| preset.name == preset_name.trim());
+
+    html_template! {
+        <div id="style-toolbar">
+            <div class="style-toolbar-row">
+                <button id="copy-style" onclick={ on_copy }>{ "Copy" }</button>
+                <button
+                    id="paste-style"
+                    disabled={ clipboard.is_none() }
+                    onclick={ on_paste }>
+                    { "Paste" }
+                </button>
+                <button id="apply-style-to-type" onclick={ on_apply_to_type }>
+                    { format!("Apply to all {} columns", p.ty) }
+                </button>
+            </div>
+            <div class="style-toolbar-row">
+                <select
+                    id="style-preset-select"
+                    disabled={ presets.is_empty() }
+                    onchange={ on_select_preset }>
+                    <option value="" selected=true disabled=true>{ "Apply preset..." }</option>
+                    {
+                        for presets.iter().map(|preset| html! {
+                            <option value={ preset.name.clone() }>{ &preset.name }</option>
+                        })
+                    }
+                </select>
+            </div>
+            <div class="style-toolbar-row">
+                <input
+                    id="style-preset-name"
+                    type="text"
+                    placeholder="Preset name"
+                    value={ (*preset_name).clone() }
+                    oninput={ on_preset_name } />
+                <button
+                    id="save-style-preset"
+                    disabled={ preset_name.trim().is_empty() }
+                    onclick={ on_save_preset }>
+                    { "Save preset" }
+                </button>
+                <button
+                    id="delete-style-preset"
+                    disabled={ !is_existing_preset }
+                    onclick={ on_delete_preset }>
+                    { "Delete preset" }
+                </button>
+            </div>
+            <div class="style-toolbar-row">
+                <button
+                    id="export-style-presets"
+                    disabled={ !has_presets }
+                    onclick={ on_export }>
+                    { "Export presets" }
+                </button>
+                <label id="import-style-presets" class="button">
+                    { "Import presets" }
+                    <input type="file" accept=".json,application/json" onchange={ on_import } />
+                </label>
+            </div>
+            if let Some(err) = &*import_error {
+                <div class="style-toolbar-error">{ err }</div>
+            }
+        </div>
+    }
+}
@@ -48,10 +48,16 @@ where
     pub parent: Scope<T>,
     pub dragdrop: DragDrop,
     pub name: &'static str,
-    pub column_dropdown: ColumnDropDownElement,
-    pub exclude: HashSet<String>,
     pub children: ChildrenWithProps<U>,
 
+    /// The dropdown for adding a new column to the end of this list, if the
+    /// list can be added to.
+    #[prop_or_default]
+    pub column_dropdown: Option<ColumnDropDownElement>,
+
+    #[prop_or_default]
+    pub exclude: HashSet<String>,
+
     #[prop_or_default]
     pub is_dragover: Option<(
         usize,
@@ -304,11 +310,13 @@ where
                     <div class="psp-text-field">
                         <ul class="psp-text-field__input" for={ ctx.props().name }>
                             { columns_html }
-                            if ctx.props().is_dragover.is_none() || invalid_drag {
-                                <EmptyColumn
-                                    { column_dropdown }
-                                    { exclude }
-                                    { on_select } />
+                            if let Some(column_dropdown) = column_dropdown {
+                                if ctx.props().is_dragover.is_none() || invalid_drag {
+                                    <EmptyColumn
+                                        { column_dropdown }
+                                        { exclude }
+                                        { on_select } />
+                                }
                             }
                         </ul>
                         <label class="pivot-selector-label" for={ ctx.props().name }></label>
@@ -30,7 +30,7 @@ use super::style::LocalStyle;
 use crate::components::datetime_column_style::custom::DatetimeStyleCustom;
 use crate::components::datetime_column_style::simple::DatetimeStyleSimple;
 use crate::config::*;
-use crate::utils::WeakScope;
+use crate::utils::{browser_time_zone, WeakScope};
 use crate::*;
 
 #[wasm_bindgen]
@@ -46,15 +46,7 @@ static ALL_TIMEZONES: LazyLock<Vec<SelectItem<String>>> = LazyLock::new(|| {
         .collect()
 });
 
-static USER_TIMEZONE: LazyLock<String> = LazyLock::new(|| {
-    js_sys::Reflect::get(
-        &js_sys::Intl::DateTimeFormat::new(&json!([]), &json!({})).resolved_options(),
-        &JsValue::from("timeZone"),
-    )
-    .unwrap()
-    .as_string()
-    .unwrap()
-});
+static USER_TIMEZONE: LazyLock<String> = LazyLock::new(browser_time_zone);
 
 pub enum DatetimeColumnStyleMsg {
     Reset(DatetimeColumnStyleConfig),
@@ -76,6 +68,11 @@ pub struct DatetimeColumnStyleProps {
 
     pub default_config: DatetimeColumnStyleDefaultConfig,
 
+    /// The viewer's time zone, which datetimes are displayed in unless this
+    /// column's style overrides it, or `None` for the browser's time zone.
+    #[prop_or_default]
+    pub time_zone: Option<String>,
+
     #[prop_or_default]
     pub on_change: Callback<DatetimeColumnStyleConfig>,
 
@@ -111,6 +108,14 @@ impl DatetimeColumnStyle {
         ctx.props().on_change.emit(self.config.clone());
     }
 
+    /// The time zone this column is displayed in when its style has none.
+    fn default_time_zone(&self, ctx: &Context<Self>) -> String {
+        ctx.props()
+            .time_zone
+            .clone()
+            .unwrap_or_else(|| USER_TIMEZONE.clone())
+    }
+
     /// Generate a color selector component for a specific `StringColorMode`
     /// variant.
     fn color_select_row(&self, ctx: &Context<Self>, mode: &DatetimeColorMode, title: &str) -> Html {
@@ -176,7 +181,7 @@ impl Component for DatetimeColumnStyle {
                 true
             }
             DatetimeColumnStyleMsg::TimezoneChanged(val) => {
-                if *USER_TIMEZONE != val {
+                if self.default_time_zone(ctx) != val {
                     self.config.time_zone = Some(val);
                 } else {
                     self.config.time_zone = None;
@@ -287,7 +292,7 @@ impl Component for DatetimeColumnStyle {
                         <Select<String>
                             wrapper_class="indent"
                             values={ ALL_TIMEZONES.iter().cloned().collect::<Vec<_>>() }
-                            selected={ self.config.time_zone.as_ref().unwrap_or(&*USER_TIMEZONE).clone() }
+                            selected={ self.config.time_zone.clone().unwrap_or_else(|| self.default_time_zone(ctx)) }
                             on_select={ ctx.link().callback(DatetimeColumnStyleMsg::TimezoneChanged) }>
                         </Select<String>>
                     </div>
@@ -0,0 +1,103 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use yew::prelude::*;
+
+use super::style::LocalStyle;
+use crate::model::*;
+use crate::renderer::*;
+use crate::session::*;
+use crate::utils::*;
+use crate::*;
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct DrillDownBarProps {
+    pub session: Session,
+    pub renderer: Renderer,
+}
+
+derive_model!(Renderer, Session for DrillDownBarProps);
+
+pub enum DrillDownBarMsg {
+    ViewCreated,
+    DrillUp(usize),
+}
+
+/// Breadcrumbs for the levels the `Session` has drilled down through, floating
+/// over the plugin.  Clicking a breadcrumb returns to that level;  the bar is
+/// hidden when the `Session` is not drilled down.
+pub struct DrillDownBar {
+    labels: Vec<String>,
+    _sub: Subscription,
+}
+
+impl Component for DrillDownBar {
+    type Message = DrillDownBarMsg;
+    type Properties = DrillDownBarProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let _sub = ctx
+            .props()
+            .session
+            .view_created
+            .add_listener(ctx.link().callback(|_| DrillDownBarMsg::ViewCreated));
+
+        Self {
+            labels: ctx.props().session.get_drill_down_labels(),
+            _sub,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            DrillDownBarMsg::ViewCreated => {
+                let labels = ctx.props().session.get_drill_down_labels();
+                let should_render = labels != self.labels;
+                self.labels = labels;
+                should_render
+            }
+            DrillDownBarMsg::DrillUp(depth) => {
+                ApiFuture::spawn(ctx.props().drill_up(depth));
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.labels.is_empty() {
+            return html! {};
+        }
+
+        let crumbs = std::iter::once("All".to_owned())
+            .chain(self.labels.iter().cloned())
+            .enumerate()
+            .map(|(depth, label)| {
+                if depth == self.labels.len() {
+                    html! {
+                        <span class="drill-down-crumb current">{ label }</span>
+                    }
+                } else {
+                    let onmousedown = ctx
+                        .link()
+                        .callback(move |_| DrillDownBarMsg::DrillUp(depth));
+                    html! {
+                        <span class="drill-down-crumb" { onmousedown }>{ label }</span>
+                    }
+                }
+            });
+
+        html_template! {
+            <LocalStyle href={ css!("drill-down-bar") } />
+            <div id="drill-down-bar">{ for crumbs }</div>
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use yew::prelude::*;
+
+use super::modal::*;
+use super::style::{LocalStyle, StyleProvider};
+use crate::js::*;
+use crate::session::*;
+use crate::utils::*;
+use crate::*;
+
+#[derive(Properties)]
+pub struct DrillThroughModalProps {
+    pub drill_through: DrillThrough,
+    pub on_close: Callback<()>,
+
+    #[prop_or_default]
+    weak_link: WeakScope<DrillThroughModal>,
+}
+
+impl PartialEq for DrillThroughModalProps {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl ModalLink<DrillThroughModal> for DrillThroughModalProps {
+    fn weak_link(&self) -> &'_ WeakScope<DrillThroughModal> {
+        &self.weak_link
+    }
+}
+
+pub enum DrillThroughModalMsg {
+    Export,
+    Copy,
+    Close,
+}
+
+/// The underlying rows of an aggregated cell, with actions to export or copy
+/// all of them as CSV.
+pub struct DrillThroughModal {}
+
+impl Component for DrillThroughModal {
+    type Message = DrillThroughModalMsg;
+    type Properties = DrillThroughModalProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        ctx.set_modal_link();
+        Self {}
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let view = ctx.props().drill_through.view.clone();
+        match msg {
+            DrillThroughModalMsg::Export => {
+                ApiFuture::spawn(async move {
+                    let csv = view.to_csv(json!({"formatted": true})).await?;
+                    download("drill-through.csv", &csv.as_blob()?)
+                });
+            }
+            DrillThroughModalMsg::Copy => {
+                let csv_task =
+                    async move { view.to_csv(json!({"formatted": true})).await?.as_blob() };
+
+                ApiFuture::spawn(copy_to_clipboard(csv_task, MimeType::TextPlain));
+            }
+            DrillThroughModalMsg::Close => ctx.props().on_close.emit(()),
+        };
+
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let drill_through = &ctx.props().drill_through;
+        let export = ctx.link().callback(|_| DrillThroughModalMsg::Export);
+        let copy = ctx.link().callback(|_| DrillThroughModalMsg::Copy);
+        let close = ctx.link().callback(|_| DrillThroughModalMsg::Close);
+        let count = if drill_through.num_rows > drill_through.rows.len() {
+            format!(
+                "Showing {} of {} rows",
+                drill_through.rows.len(),
+                drill_through.num_rows
+            )
+        } else {
+            format!("{} rows", drill_through.num_rows)
+        };
+
+        let header = drill_through.columns.iter().map(|column| {
+            html! {
+                <th>{ column }</th>
+            }
+        });
+
+        let rows = drill_through.rows.iter().map(|row| {
+            html! {
+                <tr>{ for row.iter().map(|value| html! { <td>{ value }</td> }) }</tr>
+            }
+        });
+
+        html! {
+            <StyleProvider>
+                <LocalStyle href={ css!("drill-through") } />
+                <div id="drill-through-header">
+                    <span id="drill-through-title">{ &drill_through.title }</span>
+                    <span id="drill-through-count">{ count }</span>
+                    <span class="drill-through-action" onmousedown={ export }>{ "Export" }</span>
+                    <span class="drill-through-action" onmousedown={ copy }>{ "Copy" }</span>
+                    <span class="drill-through-action" onmousedown={ close }>{ "Close" }</span>
+                </div>
+                <div id="drill-through-rows">
+                    <table>
+                        <thead>
+                            <tr>{ for header }</tr>
+                        </thead>
+                        <tbody>{ for rows }</tbody>
+                    </table>
+                </div>
+            </StyleProvider>
+        }
+    }
+}
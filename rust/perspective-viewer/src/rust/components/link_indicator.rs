@@ -0,0 +1,81 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use yew::prelude::*;
+
+use super::style::LocalStyle;
+use crate::session::*;
+use crate::utils::*;
+use crate::*;
+
+#[derive(PartialEq, Properties)]
+pub struct LinkIndicatorProps {
+    pub session: Session,
+}
+
+pub enum LinkIndicatorMsg {
+    Update,
+}
+
+/// An indicator of the filters applied by the other members of this viewer's
+/// link group, floating over the plugin.  Hidden when there are none.
+pub struct LinkIndicator {
+    labels: Vec<String>,
+    _subs: [Subscription; 2],
+}
+
+impl Component for LinkIndicator {
+    type Message = LinkIndicatorMsg;
+    type Properties = LinkIndicatorProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let session = &ctx.props().session;
+        let _subs = [
+            session
+                .linked_filters_changed
+                .add_listener(ctx.link().callback(|_| LinkIndicatorMsg::Update)),
+            session
+                .view_created
+                .add_listener(ctx.link().callback(|_| LinkIndicatorMsg::Update)),
+        ];
+
+        Self {
+            labels: session.get_linked_filter_labels(),
+            _subs,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            LinkIndicatorMsg::Update => {
+                let labels = ctx.props().session.get_linked_filter_labels();
+                let should_render = labels != self.labels;
+                self.labels = labels;
+                should_render
+            }
+        }
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        if self.labels.is_empty() {
+            return html! {};
+        }
+
+        html_template! {
+            <LocalStyle href={ css!("link-indicator") } />
+            <div id="link-indicator" title={ self.labels.join("\n") }>
+                <span id="link-indicator-icon"></span>
+                <span>{ self.labels.join(", ") }</span>
+            </div>
+        }
+    }
+}
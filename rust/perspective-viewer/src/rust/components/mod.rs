@@ -14,11 +14,14 @@
 //! necessary for public Custom Elements.  The rest are internal components of
 //! these 4.
 
+pub mod bool_column_style;
 pub mod column_dropdown;
 pub mod column_selector;
 pub mod containers;
 pub mod copy_dropdown;
 pub mod datetime_column_style;
+pub mod drill_down_bar;
+pub mod drill_through;
 pub mod export_dropdown;
 pub mod expression_editor;
 pub mod expression_panel_sidebar;
@@ -26,12 +29,15 @@ pub mod filter_dropdown;
 pub mod font_loader;
 pub mod form;
 pub mod function_dropdown;
+pub mod link_indicator;
 pub mod modal;
 pub mod number_column_style;
 pub mod plugin_selector;
+pub mod render_stats_overlay;
 pub mod render_warning;
 pub mod status_bar;
 pub mod status_bar_counter;
+pub mod status_bar_ticker;
 pub mod string_column_style;
 pub mod style;
 pub mod viewer;
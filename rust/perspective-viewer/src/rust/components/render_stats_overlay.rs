@@ -0,0 +1,119 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use yew::prelude::*;
+
+use super::style::LocalStyle;
+use crate::renderer::*;
+use crate::utils::*;
+use crate::*;
+
+#[derive(PartialEq, Properties)]
+pub struct RenderStatsOverlayProps {
+    pub renderer: Renderer,
+}
+
+pub enum RenderStatsOverlayMsg {
+    Update,
+}
+
+/// A debug overlay of the `RenderStats`, floating over the plugin.  Hidden
+/// unless enabled with `toggleRenderStatsOverlay()`.
+pub struct RenderStatsOverlay {
+    _sub: Subscription,
+}
+
+fn format_ms(ms: Option<f64>) -> String {
+    ms.map(|x| format!("{:.1}", x))
+        .unwrap_or_else(|| "-".to_owned())
+}
+
+fn histogram_row(name: &str, hist: &Histogram) -> Html {
+    html! {
+        <tr>
+            <td>{ name.to_owned() }</td>
+            <td>{ hist.count }</td>
+            <td>{ format_ms(hist.mean_ms()) }</td>
+            <td>{ format_ms(hist.percentile_ms(0.95)) }</td>
+            <td>{ format_ms((hist.count > 0).then_some(hist.max_ms)) }</td>
+        </tr>
+    }
+}
+
+impl Component for RenderStatsOverlay {
+    type Message = RenderStatsOverlayMsg;
+    type Properties = RenderStatsOverlayProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let _sub = ctx
+            .props()
+            .renderer
+            .render_stats_changed
+            .add_listener(ctx.link().callback(|_| RenderStatsOverlayMsg::Update));
+
+        Self { _sub }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            RenderStatsOverlayMsg::Update => ctx.props().renderer.is_render_stats_overlay(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let renderer = &ctx.props().renderer;
+        if !renderer.is_render_stats_overlay() {
+            return html! {};
+        }
+
+        let stats = renderer.get_render_stats();
+        let stages = stats
+            .stages()
+            .into_iter()
+            .map(|(stage, hist)| histogram_row(stage.name(), hist))
+            .collect::<Html>();
+
+        let plugins = stats
+            .plugins
+            .iter()
+            .flat_map(|(name, plugin)| {
+                [
+                    (format!("{} draw", name), &plugin.draw),
+                    (format!("{} update", name), &plugin.update),
+                    (format!("{} resize", name), &plugin.resize),
+                ]
+            })
+            .filter(|(_, hist)| hist.count > 0)
+            .map(|(name, hist)| histogram_row(&name, hist))
+            .collect::<Html>();
+
+        html_template! {
+            <LocalStyle href={ css!("render-stats-overlay") } />
+            <div id="render-stats-overlay">
+                <table>
+                    <tr>
+                        <th></th>
+                        <th>{ "n" }</th>
+                        <th>{ "mean" }</th>
+                        <th>{ "p95" }</th>
+                        <th>{ "max" }</th>
+                    </tr>
+                    { stages }
+                    { plugins }
+                </table>
+                <span>
+                    { format!("{} debounced, {} dropped", stats.debounced_updates, stats.dropped_updates) }
+                </span>
+            </div>
+        }
+    }
+}
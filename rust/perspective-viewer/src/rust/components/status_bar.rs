@@ -17,9 +17,10 @@ use yew::prelude::*;
 use super::style::LocalStyle;
 use crate::components::containers::select::*;
 use crate::components::status_bar_counter::StatusBarRowsCounter;
+use crate::components::status_bar_ticker::{is_stale, StatusBarUpdateTicker};
 use crate::custom_elements::copy_dropdown::*;
 use crate::custom_elements::export_dropdown::*;
-use crate::presentation::Presentation;
+use crate::presentation::{ClickAction, Presentation};
 use crate::renderer::*;
 use crate::session::*;
 #[cfg(test)]
@@ -27,6 +28,9 @@ use crate::utils::WeakScope;
 use crate::utils::*;
 use crate::*;
 
+/// How often the update ticker is re-rendered, in milliseconds.
+const TICKER_INTERVAL_MS: i32 = 1000;
+
 #[derive(Properties)]
 pub struct StatusBarProps {
     pub id: String,
@@ -57,6 +61,9 @@ pub enum StatusBarMsg {
     TableStatsChanged,
     SetIsUpdating(bool),
     SetTitle(Option<String>),
+    SetClickAction(ClickAction),
+    TogglePause,
+    Tick,
 }
 
 /// A toolbar with buttons, and `Table` & `View` status information.
@@ -68,7 +75,8 @@ pub struct StatusBar {
     copy_ref: NodeRef,
     export_dropdown: Option<ExportDropDownMenuElement>,
     copy_dropdown: Option<CopyDropDownMenuElement>,
-    _sub: [Subscription; 5],
+    _sub: [Subscription; 7],
+    _ticker: Interval,
 }
 
 impl Component for StatusBar {
@@ -98,8 +106,24 @@ impl Component for StatusBar {
                 .presentation
                 .title_changed
                 .add_listener(ctx.link().callback(|_| StatusBarMsg::TableStatsChanged)),
+            ctx.props()
+                .session
+                .pending_updates_changed
+                .add_listener(ctx.link().callback(|_| StatusBarMsg::TableStatsChanged)),
+            ctx.props()
+                .presentation
+                .stale_threshold_changed
+                .add_listener(ctx.link().callback(|_| StatusBarMsg::Tick)),
         ];
 
+        // Age the update ticker, and re-check the stale threshold, even when no
+        // updates arrive.
+        let _ticker = Interval::new(TICKER_INTERVAL_MS, {
+            let on_tick = ctx.link().callback(|_| StatusBarMsg::Tick);
+            move || on_tick.emit(())
+        })
+        .unwrap();
+
         // Fetch initial theme
         let presentation = ctx.props().presentation.clone();
         let on_theme = ctx.link().callback(StatusBarMsg::SetThemeConfig);
@@ -110,6 +134,7 @@ impl Component for StatusBar {
 
         Self {
             _sub,
+            _ticker,
             theme: None,
             themes: vec![],
             copy_dropdown: None,
@@ -126,7 +151,11 @@ impl Component for StatusBar {
                 self.is_updating = max!(0, self.is_updating + if is_updating { 1 } else { -1 });
                 true
             }
-            StatusBarMsg::TableStatsChanged => true,
+            StatusBarMsg::TableStatsChanged => {
+                self.update_stale_attribute(ctx);
+                true
+            }
+            StatusBarMsg::Tick => self.update_stale_attribute(ctx),
             StatusBarMsg::Reset(all) => {
                 ctx.props().on_reset.emit(all);
                 false
@@ -170,6 +199,20 @@ impl Component for StatusBar {
                 ctx.props().presentation.set_title(title);
                 false
             }
+            StatusBarMsg::TogglePause => {
+                let session = &ctx.props().session;
+                if session.is_paused() {
+                    session.resume_updates();
+                } else {
+                    session.pause_updates();
+                }
+
+                false
+            }
+            StatusBarMsg::SetClickAction(action) => {
+                ctx.props().presentation.set_click_action(action);
+                true
+            }
         }
     }
 
@@ -191,6 +234,26 @@ impl Component for StatusBar {
 
         let export = ctx.link().callback(|_: MouseEvent| StatusBarMsg::Export);
         let copy = ctx.link().callback(|_: MouseEvent| StatusBarMsg::Copy);
+        let click_action = ctx.props().presentation.get_click_action();
+        let mut click_action_class = classes!("button");
+        if click_action != ClickAction::None {
+            click_action_class.push("active");
+        }
+
+        let pending_updates = ctx.props().session.get_pending_updates();
+        let toggle_pause = ctx
+            .link()
+            .callback(|_: MouseEvent| StatusBarMsg::TogglePause);
+        let mut pause_class = classes!("button");
+        if pending_updates.is_some() {
+            pause_class.push("active");
+        }
+
+        let on_click_action = ctx.link().callback(StatusBarMsg::SetClickAction);
+        let click_action_values = ClickAction::ALL
+            .into_iter()
+            .map(SelectItem::Option)
+            .collect::<Vec<_>>();
 
         let theme_button = match &self.theme {
             None => html! {},
@@ -253,13 +316,33 @@ impl Component for StatusBar {
                         placeholder="untitled" />
                 </label>
                 <div id="rows" class="section">
-                    <StatusBarRowsCounter stats={ stats } />
+                    <StatusBarRowsCounter stats={ stats.clone() } />
+                    <StatusBarUpdateTicker
+                        stats={ stats }
+                        stale_threshold={ ctx.props().presentation.get_stale_threshold() }
+                        now={ js_sys::Date::now() } />
+                    if let Some(count) = pending_updates {
+                        <span id="pending-updates">
+                            { format!("Paused, {} updates pending", count) }
+                        </span>
+                    }
                 </div>
                 <div id="menu-bar" class="section">
                     { theme_button }
                     <div id="plugin-settings">
                         <slot name="plugin-settings"></slot>
                     </div>
+                    <span id="click-action" class={ click_action_class }>
+                        <Select<ClickAction>
+                            id="click_action_selector"
+                            values={ click_action_values }
+                            selected={ click_action }
+                            on_select={ on_click_action }>
+                        </Select<ClickAction>>
+                    </span>
+                    <span id="pause" class={ pause_class } onmousedown={ toggle_pause }>
+                        <span>{ if pending_updates.is_some() { "Resume" } else { "Pause" } }</span>
+                    </span>
                     <span id="reset" class="button" onmousedown={ reset }>
                         <span>{ "Reset" }</span>
                     </span>
@@ -287,6 +370,17 @@ impl Component for StatusBar {
 }
 
 impl StatusBar {
+    /// Highlight the viewer if the `Table` has not updated within the stale
+    /// threshold, returning whether the update ticker is visible (and so needs
+    /// re-rendering as it ages).
+    fn update_stale_attribute(&self, ctx: &Context<Self>) -> bool {
+        let stats = ctx.props().session.get_table_stats();
+        let presentation = &ctx.props().presentation;
+        let threshold = presentation.get_stale_threshold();
+        presentation.set_stale_attribute(is_stale(&stats, threshold, js_sys::Date::now()));
+        stats.and_then(|x| x.last_update).is_some()
+    }
+
     const fn status_class_name(&self, stats: &Option<ViewStats>) -> &'static str {
         match stats {
             Some(ViewStats {
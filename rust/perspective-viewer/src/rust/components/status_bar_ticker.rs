@@ -0,0 +1,83 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use yew::prelude::*;
+
+use crate::session::{ViewStats, UPDATE_RATE_WINDOW_MS};
+use crate::*;
+
+/// Whether no update has arrived in the `stale_threshold` seconds before
+/// `now`.  A `Table` which has never updated is measured from when its `View`
+/// was created.
+pub fn is_stale(stats: &Option<ViewStats>, stale_threshold: Option<f64>, now: f64) -> bool {
+    let last_update = stats
+        .as_ref()
+        .and_then(|x| x.last_update.or(x.view_created));
+
+    match (last_update, stale_threshold) {
+        (Some(last_update), Some(threshold)) => now - last_update > threshold * 1000.0,
+        _ => false,
+    }
+}
+
+fn format_age(ms: f64) -> String {
+    let secs = (ms / 1000.0).max(0.0) as u64;
+    match secs {
+        0 => "now".to_owned(),
+        1..=59 => format!("{}s ago", secs),
+        60..=3599 => format!("{}m ago", secs / 60),
+        _ => format!("{}h ago", secs / 3600),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct StatusBarUpdateTickerProps {
+    pub stats: Option<ViewStats>,
+    pub stale_threshold: Option<f64>,
+
+    /// The time to measure the last update's age against, in milliseconds
+    /// since the epoch.
+    pub now: f64,
+}
+
+/// A label widget which displays the rate of `Table` updates, the rows changed
+/// by the last update and how long ago it arrived, once the `Table` has
+/// updated.
+#[function_component(StatusBarUpdateTicker)]
+pub fn status_bar_update_ticker(props: &StatusBarUpdateTickerProps) -> Html {
+    let Some(ViewStats {
+        updates_per_sec: Some(updates_per_sec),
+        last_update: Some(last_update),
+        rows_changed,
+        ..
+    }) = props.stats else {
+        return html! {};
+    };
+
+    let age = props.now - last_update;
+    let updates_per_sec = if age < UPDATE_RATE_WINDOW_MS {
+        updates_per_sec
+    } else {
+        0.0
+    };
+
+    let class = is_stale(&props.stats, props.stale_threshold, props.now).then_some("stale");
+    html_template! {
+        <span id="update-rate" class={ class }>{ format!("{:.1}/s", updates_per_sec) }</span>
+        if let Some(rows_changed) = rows_changed {
+            <span id="rows-changed" title="Row count change">
+                { format!("{:+} rows", rows_changed) }
+            </span>
+        }
+        <span id="last-update" class={ class }>{ format_age(age) }</span>
+    }
+}
@@ -14,4 +14,5 @@ mod column_style;
 mod plugin_selector;
 mod status_bar;
 mod status_bar_counter;
+mod status_bar_ticker;
 mod viewer;
@@ -128,6 +128,7 @@ pub async fn test_status_initializing() {
         num_view_cells: None,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let (div, session) = gen(&stats).await;
@@ -144,6 +145,7 @@ pub async fn test_status_table_loaded() {
         is_group_by: false,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let (div, session) = gen(&stats).await;
@@ -162,6 +164,7 @@ pub async fn test_status_table_and_view_loaded() {
         is_group_by: false,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let (div, session) = gen(&stats).await;
@@ -38,6 +38,7 @@ pub async fn test_counter_initializing() {
         is_group_by: false,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let div = test_html! {
@@ -56,6 +57,7 @@ pub async fn test_counter_some_connected_no_view() {
         is_group_by: false,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let div = test_html! {
@@ -74,6 +76,7 @@ pub async fn test_counter_some_connected_no_pivot() {
         is_group_by: false,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let div = test_html! {
@@ -92,6 +95,7 @@ pub async fn test_counter_some_connected_pivot() {
         is_group_by: false,
         is_split_by: false,
         is_filtered: false,
+        ..ViewStats::default()
     });
 
     let div = test_html! {
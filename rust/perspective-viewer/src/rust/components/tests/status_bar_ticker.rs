@@ -0,0 +1,78 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+
+use crate::components::status_bar_ticker::*;
+use crate::session::ViewStats;
+use crate::utils::request_animation_frame;
+use crate::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub fn test_is_stale() {
+    let stats = Some(ViewStats {
+        last_update: Some(1000.0),
+        ..ViewStats::default()
+    });
+
+    assert!(!is_stale(&stats, None, 60_000.0));
+    assert!(!is_stale(&stats, Some(5.0), 5000.0));
+    assert!(is_stale(&stats, Some(5.0), 7000.0));
+    assert!(!is_stale(&Some(ViewStats::default()), Some(5.0), 7000.0));
+}
+
+#[wasm_bindgen_test]
+pub fn test_is_stale_never_updated() {
+    let stats = Some(ViewStats {
+        view_created: Some(1000.0),
+        ..ViewStats::default()
+    });
+
+    assert!(!is_stale(&stats, Some(5.0), 5000.0));
+    assert!(is_stale(&stats, Some(5.0), 7000.0));
+    assert!(!is_stale(&None, Some(5.0), 7000.0));
+}
+
+#[wasm_bindgen_test]
+pub async fn test_ticker_never_updated() {
+    let div = test_html! {
+        <StatusBarUpdateTicker stats={ Some(ViewStats::default()) } stale_threshold={ None } now={ 0.0 }>
+        </StatusBarUpdateTicker>
+    };
+
+    assert_eq!(div.inner_html(), "");
+}
+
+#[wasm_bindgen_test]
+pub async fn test_ticker_stale() {
+    let stats = Some(ViewStats {
+        updates_per_sec: Some(2.0),
+        last_update: Some(1000.0),
+        rows_changed: Some(3),
+        ..ViewStats::default()
+    });
+
+    let div = test_html! {
+        <StatusBarUpdateTicker stats={ stats } stale_threshold={ Some(5.0) } now={ 11_000.0 }>
+        </StatusBarUpdateTicker>
+    };
+
+    assert_eq!(
+        div.inner_html(),
+        "<span id=\"update-rate\" class=\"stale\">0.0/s</span><span id=\"rows-changed\" \
+         title=\"Row count change\">+3 rows</span><span id=\"last-update\" class=\"stale\">10s \
+         ago</span>"
+    );
+}
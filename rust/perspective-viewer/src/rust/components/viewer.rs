@@ -18,8 +18,11 @@ use yew::prelude::*;
 
 use super::column_selector::ColumnSelector;
 use super::containers::split_panel::SplitPanel;
+use super::drill_down_bar::DrillDownBar;
 use super::font_loader::{FontLoader, FontLoaderProps, FontLoaderStatus};
+use super::link_indicator::LinkIndicator;
 use super::plugin_selector::PluginSelector;
+use super::render_stats_overlay::RenderStatsOverlay;
 use super::render_warning::RenderWarning;
 use super::status_bar::StatusBar;
 use super::style::{LocalStyle, StyleProvider};
@@ -340,6 +343,12 @@ impl Component for PerspectiveViewer {
                                     session={ &ctx.props().session }
                                     renderer={ &ctx.props().renderer }>
                                 </RenderWarning>
+                                <DrillDownBar
+                                    session={ &ctx.props().session }
+                                    renderer={ &ctx.props().renderer }>
+                                </DrillDownBar>
+                                <LinkIndicator session={ &ctx.props().session } />
+                                <RenderStatsOverlay renderer={ &ctx.props().renderer } />
                                 <slot></slot>
                             </div>
                             if let Some(selected_column) = self.selected_column.clone() {
@@ -362,6 +371,12 @@ impl Component for PerspectiveViewer {
                         session={ &ctx.props().session }
                         renderer={ &ctx.props().renderer }>
                     </RenderWarning>
+                    <DrillDownBar
+                        session={ &ctx.props().session }
+                        renderer={ &ctx.props().renderer }>
+                    </DrillDownBar>
+                    <LinkIndicator session={ &ctx.props().session } />
+                    <RenderStatsOverlay renderer={ &ctx.props().renderer } />
                     if ctx.props().is_title() {
                         <StatusBar
                             id="status_bar"
@@ -0,0 +1,98 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::aggregates::*;
+
+/// An additional aggregated copy of a column in `ViewConfig::columns`, e.g.
+/// `avg` of `"Sales"` alongside `sum` of `"Sales"`.  `View` columns must be
+/// unique, so an instance is an expression column which only references its
+/// source column, aliased with a name generated from the source column and
+/// its aggregate, e.g.
+///
+/// ```text
+/// // Sales (avg)
+/// "Sales"
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AggregateInstance {
+    pub alias: String,
+    pub source: String,
+}
+
+impl AggregateInstance {
+    pub fn new(source: &str, aggregate: &Aggregate) -> Self {
+        Self {
+            alias: format!("{} ({})", source, aggregate),
+            source: source.to_owned(),
+        }
+    }
+
+    /// Parse an expression string, returning `Some` only if it is of the
+    /// generated form, so hand-written expressions are never mistaken for
+    /// instances.
+    pub fn parse(expression: &str) -> Option<Self> {
+        let (alias, expr) = expression.strip_prefix("//")?.split_once('\n')?;
+        let source = expr.trim().strip_prefix('"')?.strip_suffix('"')?;
+        let alias = alias.trim();
+        let is_generated = alias
+            .strip_prefix(source)
+            .and_then(|x| x.strip_prefix(" ("))
+            .map(|x| x.ends_with(')'))
+            .unwrap_or_default();
+
+        (is_generated && !source.contains('"')).then(|| Self {
+            alias: alias.to_owned(),
+            source: source.to_owned(),
+        })
+    }
+
+    /// Whether this instance's alias was generated for `aggregate`, and
+    /// should hence be renamed when its aggregate changes.
+    pub fn is_named_for(&self, aggregate: &Aggregate) -> bool {
+        *self == Self::new(&self.source, aggregate)
+    }
+
+    pub fn to_expression(&self) -> String {
+        format!("// {}\n\"{}\"", self.alias, self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    fn test_aggregate_instance_round_trip() {
+        let avg = Aggregate::SingleAggregate(SingleAggregate::Avg);
+        let instance = AggregateInstance::new("Sales", &avg);
+        assert_eq!(instance.alias, "Sales (avg)");
+        assert_eq!(
+            AggregateInstance::parse(&instance.to_expression()),
+            Some(instance.clone())
+        );
+
+        assert!(instance.is_named_for(&avg));
+        assert!(!instance.is_named_for(&Aggregate::SingleAggregate(SingleAggregate::Sum)));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_aggregate_instance_parse_rejects_hand_written() {
+        assert_eq!(AggregateInstance::parse("// Sales2\n\"Sales\""), None);
+        assert_eq!(
+            AggregateInstance::parse("// Sales (avg)\n\"Sales\" * 2"),
+            None
+        );
+        assert_eq!(AggregateInstance::parse("\"Sales\""), None);
+    }
+}
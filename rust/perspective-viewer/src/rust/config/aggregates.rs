@@ -98,6 +98,15 @@ pub enum SingleAggregate {
 
     #[serde(rename = "var")]
     Var,
+
+    #[serde(rename = "and")]
+    And,
+
+    #[serde(rename = "or")]
+    Or,
+
+    #[serde(rename = "pct true")]
+    PctTrue,
 }
 
 impl Display for SingleAggregate {
@@ -129,6 +138,9 @@ impl Display for SingleAggregate {
             Self::Var => "var",
             Self::Max => "max",
             Self::Min => "min",
+            Self::And => "all true",
+            Self::Or => "any true",
+            Self::PctTrue => "pct true",
         };
 
         write!(fmt, "{}", term)
@@ -166,32 +178,236 @@ impl FromStr for SingleAggregate {
             "high minus low" => Ok(Self::HighMinusLow),
             "stddev" => Ok(Self::StdDev),
             "var" => Ok(Self::Var),
+            "all true" | "and" => Ok(Self::And),
+            "any true" | "or" => Ok(Self::Or),
+            "pct true" => Ok(Self::PctTrue),
             x => Err(format!("Unknown aggregate `{}`", x).into()),
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+/// An aggregate which takes a parameter in addition to the column it
+/// aggregates.  The kind of parameter (a column name, a number or an
+/// expression) is determined by the aggregate, see [`AggregateParamKind`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde()]
 pub enum MultiAggregate {
     #[serde(rename = "weighted mean")]
     WeightedMean,
+
+    #[serde(rename = "weighted sum")]
+    WeightedSum,
+
+    #[serde(rename = "last by")]
+    LastBy,
+
+    #[serde(rename = "max by")]
+    MaxBy,
+
+    #[serde(rename = "percentile")]
+    Percentile,
+
+    #[serde(rename = "count where")]
+    CountWhere,
+}
+
+pub const MULTI_AGGREGATES: &[MultiAggregate] = &[
+    MultiAggregate::WeightedMean,
+    MultiAggregate::WeightedSum,
+    MultiAggregate::LastBy,
+    MultiAggregate::MaxBy,
+    MultiAggregate::Percentile,
+    MultiAggregate::CountWhere,
+];
+
+/// The type of a `MultiAggregate`'s parameter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AggregateParamKind {
+    /// The name of another column, e.g. the weights of `weighted mean`.
+    Column,
+
+    /// A number, e.g. the `p` in `percentile`, in the range `[0, 100]`.
+    Number,
+
+    /// An ExprTK boolean expression evaluated per row.
+    Expression,
 }
 
+impl Display for MultiAggregate {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let term = match self {
+            Self::WeightedMean => "weighted mean",
+            Self::WeightedSum => "weighted sum",
+            Self::LastBy => "last by",
+            Self::MaxBy => "max by",
+            Self::Percentile => "percentile",
+            Self::CountWhere => "count where",
+        };
+
+        write!(fmt, "{}", term)
+    }
+}
+
+impl MultiAggregate {
+    pub const fn param_kind(&self) -> AggregateParamKind {
+        match self {
+            Self::WeightedMean | Self::WeightedSum | Self::LastBy | Self::MaxBy => {
+                AggregateParamKind::Column
+            }
+            Self::Percentile => AggregateParamKind::Number,
+            Self::CountWhere => AggregateParamKind::Expression,
+        }
+    }
+
+    /// The word joining this aggregate's name to its parameter in the
+    /// aggregate's string form, e.g. `"weighted mean by Quantity"`, if the
+    /// name itself does not already end in one.
+    const fn connective(&self) -> Option<&'static str> {
+        match self {
+            Self::WeightedMean | Self::WeightedSum => Some("by"),
+            Self::LastBy | Self::MaxBy | Self::Percentile | Self::CountWhere => None,
+        }
+    }
+
+    /// The string form of this aggregate, without its parameter.
+    pub fn prefix(&self) -> String {
+        match self.connective() {
+            Some(connective) => format!("{} {}", self, connective),
+            None => format!("{}", self),
+        }
+    }
+
+    /// The parameter this aggregate is offered with when there is no better
+    /// choice, for aggregates whose parameter is not a column.
+    pub const fn default_param(&self) -> Option<&'static str> {
+        match self.param_kind() {
+            AggregateParamKind::Column => None,
+            AggregateParamKind::Number => Some("50"),
+            AggregateParamKind::Expression => Some("true"),
+        }
+    }
+
+    /// Validate and normalize a parameter for this aggregate.
+    pub fn parse_param(&self, param: &str) -> Result<String, String> {
+        let param = param.trim();
+        match self.param_kind() {
+            _ if param.is_empty() => Err(format!("`{}` requires a parameter", self)),
+            AggregateParamKind::Number => match param.parse::<f64>() {
+                Ok(x) if (0.0..=100.0).contains(&x) => Ok(format!("{}", x)),
+                _ => Err(format!(
+                    "`{}` expects a number in [0, 100], got `{}`",
+                    self, param
+                )),
+            },
+            AggregateParamKind::Column | AggregateParamKind::Expression => Ok(param.to_owned()),
+        }
+    }
+}
+
+/// An aggregate, either a simple one like `sum` or a `MultiAggregate` with its
+/// parameter.
+///
+/// Parameters are stored as strings (numeric parameters are normalized by
+/// [`MultiAggregate::parse_param`]), and serialize to the engine's array
+/// format, e.g. `["weighted mean", "Quantity"]` or `["percentile", 95]`.
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
-#[serde(untagged)]
+#[serde(try_from = "AggregateRecord", into = "AggregateRecord")]
 pub enum Aggregate {
     SingleAggregate(SingleAggregate),
     MultiAggregate(MultiAggregate, String),
 }
 
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum AggregateRecord {
+    SingleAggregate(SingleAggregate),
+    MultiAggregate(MultiAggregate, AggregateParamRecord),
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum AggregateParamRecord {
+    Number(f64),
+    String(String),
+}
+
+impl TryFrom<AggregateRecord> for Aggregate {
+    type Error = String;
+
+    fn try_from(value: AggregateRecord) -> Result<Self, Self::Error> {
+        Ok(match value {
+            AggregateRecord::SingleAggregate(x) => Self::SingleAggregate(x),
+            AggregateRecord::MultiAggregate(x, AggregateParamRecord::Number(y)) => {
+                Self::MultiAggregate(x, x.parse_param(&format!("{}", y))?)
+            }
+            AggregateRecord::MultiAggregate(x, AggregateParamRecord::String(y)) => {
+                Self::MultiAggregate(x, x.parse_param(&y)?)
+            }
+        })
+    }
+}
+
+impl From<Aggregate> for AggregateRecord {
+    fn from(value: Aggregate) -> Self {
+        match value {
+            Aggregate::SingleAggregate(x) => Self::SingleAggregate(x),
+            Aggregate::MultiAggregate(x, y) => match (x.param_kind(), y.parse::<f64>()) {
+                (AggregateParamKind::Number, Ok(num)) => {
+                    Self::MultiAggregate(x, AggregateParamRecord::Number(num))
+                }
+                _ => Self::MultiAggregate(x, AggregateParamRecord::String(y)),
+            },
+        }
+    }
+}
+
+impl Aggregate {
+    /// The name the engine knows this aggregate by, which for
+    /// `MultiAggregate` excludes the parameter.
+    pub fn engine_name(&self) -> String {
+        let name = match self {
+            Self::SingleAggregate(x) => serde_json::to_value(x),
+            Self::MultiAggregate(x, _) => serde_json::to_value(x),
+        };
+
+        name.ok()
+            .and_then(|x| x.as_str().map(|x| x.to_owned()))
+            .unwrap_or_default()
+    }
+
+    /// The aggregate the engine computes this aggregate with for a column of
+    /// type `ty`.  Some aggregates are aliases the engine does not know by
+    /// name: `max`/`min` of a date are its `high`/`low`, and `pct true` of a
+    /// boolean is its `mean`, the fraction of its non-null values which are
+    /// `true`.
+    pub fn to_engine(&self, ty: Type) -> Self {
+        let engine = match (self, ty) {
+            (Self::SingleAggregate(SingleAggregate::Max), Type::Date | Type::Datetime) => {
+                SingleAggregate::High
+            }
+            (Self::SingleAggregate(SingleAggregate::Min), Type::Date | Type::Datetime) => {
+                SingleAggregate::Low
+            }
+            (Self::SingleAggregate(SingleAggregate::PctTrue), Type::Bool) => SingleAggregate::Mean,
+            _ => return self.clone(),
+        };
+
+        Self::SingleAggregate(engine)
+    }
+
+    pub fn multi_aggregate(&self) -> Option<MultiAggregate> {
+        match self {
+            Self::SingleAggregate(_) => None,
+            Self::MultiAggregate(x, _) => Some(*x),
+        }
+    }
+}
+
 impl Display for Aggregate {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         match self {
             Self::SingleAggregate(x) => write!(fmt, "{}", x)?,
-            Self::MultiAggregate(MultiAggregate::WeightedMean, x) => {
-                write!(fmt, "weighted mean by {}", x)?
-            }
+            Self::MultiAggregate(x, y) => write!(fmt, "{} {}", x.prefix(), y)?,
         };
         Ok(())
     }
@@ -200,14 +416,21 @@ impl Display for Aggregate {
 impl FromStr for Aggregate {
     type Err = ApiError;
 
+    /// Parses the `Display` form of an `Aggregate`.  `SingleAggregate` names
+    /// take precedence, so `"last by index"` is never a `LastBy` aggregate.
     fn from_str(input: &str) -> ApiResult<Self> {
-        Ok(
-            if let Some(stripped) = input.strip_prefix("weighted mean by ") {
-                Self::MultiAggregate(MultiAggregate::WeightedMean, stripped.to_owned())
-            } else {
-                Self::SingleAggregate(SingleAggregate::from_str(input)?)
-            },
-        )
+        if let Ok(x) = SingleAggregate::from_str(input) {
+            return Ok(Self::SingleAggregate(x));
+        }
+
+        MULTI_AGGREGATES
+            .iter()
+            .find_map(|x| {
+                let param = input.strip_prefix(&x.prefix())?.strip_prefix(' ')?;
+                let param = x.parse_param(param).map_err(ApiError::from);
+                Some(param.map(|y| Self::MultiAggregate(*x, y)))
+            })
+            .unwrap_or_else(|| Err(format!("Unknown aggregate `{}`", input).into()))
     }
 }
 
@@ -224,6 +447,36 @@ const STRING_AGGREGATES: &[SingleAggregate] = &[
     SingleAggregate::Unique,
 ];
 
+const DATETIME_AGGREGATES: &[SingleAggregate] = &[
+    SingleAggregate::Any,
+    SingleAggregate::Count,
+    SingleAggregate::DistinctCount,
+    SingleAggregate::Dominant,
+    SingleAggregate::First,
+    SingleAggregate::High,
+    SingleAggregate::Last,
+    SingleAggregate::LastByIndex,
+    SingleAggregate::Low,
+    SingleAggregate::Max,
+    SingleAggregate::Median,
+    SingleAggregate::Min,
+    SingleAggregate::Unique,
+];
+
+const BOOL_AGGREGATES: &[SingleAggregate] = &[
+    SingleAggregate::And,
+    SingleAggregate::Any,
+    SingleAggregate::Count,
+    SingleAggregate::DistinctCount,
+    SingleAggregate::Dominant,
+    SingleAggregate::First,
+    SingleAggregate::Last,
+    SingleAggregate::LastByIndex,
+    SingleAggregate::Or,
+    SingleAggregate::PctTrue,
+    SingleAggregate::Unique,
+];
+
 const NUMBER_AGGREGATES: &[SingleAggregate] = &[
     SingleAggregate::AbsSum,
     SingleAggregate::Any,
@@ -254,21 +507,62 @@ const NUMBER_AGGREGATES: &[SingleAggregate] = &[
 
 impl Type {
     pub fn aggregates_iter(&self) -> Box<dyn Iterator<Item = Aggregate>> {
-        match self {
-            Self::Bool | Self::Date | Self::Datetime | Self::String => Box::new(
-                STRING_AGGREGATES
-                    .iter()
-                    .map(|x| Aggregate::SingleAggregate(*x)),
-            ),
-            Self::Integer | Self::Float => Box::new(
-                NUMBER_AGGREGATES
-                    .iter()
-                    .map(|x| Aggregate::SingleAggregate(*x)),
-            ),
+        let aggregates = match self {
+            Self::String => STRING_AGGREGATES,
+            Self::Date | Self::Datetime => DATETIME_AGGREGATES,
+            Self::Bool => BOOL_AGGREGATES,
+            Self::Integer | Self::Float => NUMBER_AGGREGATES,
+        };
+
+        Box::new(aggregates.iter().map(|x| Aggregate::SingleAggregate(*x)))
+    }
+
+    /// Whether a column of this type can be aggregated by `agg`, regardless of
+    /// its parameter.
+    pub fn is_aggregate_valid(&self, agg: &Aggregate) -> bool {
+        match agg {
+            Aggregate::SingleAggregate(_) => self.aggregates_iter().any(|x| x == *agg),
+            Aggregate::MultiAggregate(x, _) => self.is_multi_aggregate_valid(x),
+        }
+    }
+
+    /// Whether a column of this type can be aggregated by `agg`.
+    pub const fn is_multi_aggregate_valid(&self, agg: &MultiAggregate) -> bool {
+        match agg {
+            MultiAggregate::WeightedMean
+            | MultiAggregate::WeightedSum
+            | MultiAggregate::Percentile => matches!(self, Self::Integer | Self::Float),
+            MultiAggregate::LastBy | MultiAggregate::MaxBy | MultiAggregate::CountWhere => true,
+        }
+    }
+
+    /// Whether a column of this type can be the parameter of `agg`, when
+    /// `agg` is parameterized by a column.
+    pub const fn is_multi_aggregate_param_valid(&self, agg: &MultiAggregate) -> bool {
+        match agg.param_kind() {
+            AggregateParamKind::Column => match agg {
+                MultiAggregate::WeightedMean | MultiAggregate::WeightedSum => {
+                    matches!(self, Self::Integer | Self::Float)
+                }
+                _ => !matches!(self, Self::Bool),
+            },
+            AggregateParamKind::Number | AggregateParamKind::Expression => false,
         }
     }
 
+    /// The aggregate used for columns of this type with no aggregate set in
+    /// `ViewConfig::aggregates`.
     pub const fn default_aggregate(&self) -> Aggregate {
+        match self {
+            Self::Date | Self::Datetime => Aggregate::SingleAggregate(SingleAggregate::High),
+            Self::Bool | Self::String => Aggregate::SingleAggregate(SingleAggregate::Count),
+            Self::Integer | Self::Float => Aggregate::SingleAggregate(SingleAggregate::Sum),
+        }
+    }
+
+    /// The aggregate the engine itself uses for columns of this type with no
+    /// aggregate set, which may differ from `default_aggregate()`.
+    pub const fn engine_default_aggregate(&self) -> Aggregate {
         match self {
             Self::Bool | Self::Date | Self::Datetime | Self::String => {
                 Aggregate::SingleAggregate(SingleAggregate::Count)
@@ -277,3 +571,33 @@ impl Type {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_to_engine() {
+        let max = Aggregate::SingleAggregate(SingleAggregate::Max);
+        let min = Aggregate::SingleAggregate(SingleAggregate::Min);
+        let pct_true = Aggregate::SingleAggregate(SingleAggregate::PctTrue);
+        assert_eq!(max.to_engine(Type::Date).engine_name(), "high");
+        assert_eq!(min.to_engine(Type::Datetime).engine_name(), "low");
+        assert_eq!(max.to_engine(Type::Float), max);
+        assert_eq!(pct_true.to_engine(Type::Bool).engine_name(), "mean");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_typed_catalogs() {
+        let pct_true = Aggregate::SingleAggregate(SingleAggregate::PctTrue);
+        let max = Aggregate::SingleAggregate(SingleAggregate::Max);
+        assert!(Type::Bool.is_aggregate_valid(&pct_true));
+        assert!(!Type::String.is_aggregate_valid(&pct_true));
+        assert!(Type::Date.is_aggregate_valid(&max));
+        assert!(Type::Datetime.is_aggregate_valid(&max));
+        assert!(!Type::Bool.is_aggregate_valid(&max));
+        assert_eq!("pct true".parse::<Aggregate>().unwrap(), pct_true);
+    }
+}
@@ -0,0 +1,236 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BoolFormatMode {
+    #[default]
+    #[serde(rename = "icon")]
+    Icon,
+
+    #[serde(rename = "checkbox")]
+    Checkbox,
+
+    #[serde(rename = "text")]
+    Text,
+}
+
+impl Display for BoolFormatMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Icon => "icon",
+            Self::Checkbox => "checkbox",
+            Self::Text => "text",
+        };
+
+        write!(f, "{}", text)
+    }
+}
+
+impl FromStr for BoolFormatMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "icon" => Ok(Self::Icon),
+            "checkbox" => Ok(Self::Checkbox),
+            "text" => Ok(Self::Text),
+            x => Err(format!("Unknown BoolFormatMode::{}", x)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BoolColorMode {
+    #[default]
+    #[serde(rename = "foreground")]
+    Foreground,
+
+    #[serde(rename = "background")]
+    Background,
+}
+
+impl Display for BoolColorMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Self::Foreground => "foreground",
+            Self::Background => "background",
+        };
+
+        write!(f, "{}", text)
+    }
+}
+
+impl FromStr for BoolColorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "foreground" => Ok(Self::Foreground),
+            "background" => Ok(Self::Background),
+            x => Err(format!("Unknown BoolColorMode::{}", x)),
+        }
+    }
+}
+
+/// A model for the JSON serialized style configuration for a column of type
+/// `boolean`.  The `*_text` fields are only used when `format` is
+/// `BoolFormatMode::Text`, except for `null_text` which applies to every
+/// format.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BoolColumnStyleConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub format: Option<BoolFormatMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub true_text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub false_text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub null_text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bool_color_mode: Option<BoolColorMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub true_color: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub false_color: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub null_color: Option<String>,
+}
+
+derive_wasm_abi!(BoolColumnStyleConfig, FromWasmAbi, IntoWasmAbi);
+
+/// The plugin-provided defaults for `BoolColumnStyleConfig`, used in the GUI
+/// when the corresponding field is `None`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BoolColumnStyleDefaultConfig {
+    pub true_color: String,
+    pub false_color: String,
+    pub null_color: String,
+
+    #[serde(default = "BoolColumnStyleDefaultConfig::default_true_text")]
+    pub true_text: String,
+
+    #[serde(default = "BoolColumnStyleDefaultConfig::default_false_text")]
+    pub false_text: String,
+
+    #[serde(default = "BoolColumnStyleDefaultConfig::default_null_text")]
+    pub null_text: String,
+}
+
+impl BoolColumnStyleDefaultConfig {
+    fn default_true_text() -> String {
+        "true".to_owned()
+    }
+
+    fn default_false_text() -> String {
+        "false".to_owned()
+    }
+
+    fn default_null_text() -> String {
+        "-".to_owned()
+    }
+}
+
+impl Default for BoolColumnStyleDefaultConfig {
+    fn default() -> Self {
+        Self {
+            true_color: Default::default(),
+            false_color: Default::default(),
+            null_color: Default::default(),
+            true_text: Self::default_true_text(),
+            false_text: Self::default_false_text(),
+            null_text: Self::default_null_text(),
+        }
+    }
+}
+
+derive_wasm_abi!(BoolColumnStyleDefaultConfig, FromWasmAbi);
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_bool_style_config_serde() {
+        let config = BoolColumnStyleConfig {
+            format: Some(BoolFormatMode::Text),
+            true_text: Some("yes".to_owned()),
+            bool_color_mode: Some(BoolColorMode::Background),
+            ..BoolColumnStyleConfig::default()
+        };
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "format": "text",
+                "true_text": "yes",
+                "bool_color_mode": "background"
+            })
+        );
+
+        let rec: BoolColumnStyleConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(rec, config);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_bool_style_config_empty() {
+        let rec: BoolColumnStyleConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(rec, BoolColumnStyleConfig::default());
+        assert_eq!(serde_json::to_string(&rec).unwrap(), "{}");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_bool_style_default_config_text() {
+        let rec: BoolColumnStyleDefaultConfig = serde_json::from_value(serde_json::json!({
+            "true_color": "#00ff00",
+            "false_color": "#ff0000",
+            "null_color": "#888888"
+        }))
+        .unwrap();
+
+        assert_eq!(rec.true_text, "true");
+        assert_eq!(rec.false_text, "false");
+        assert_eq!(rec.null_text, "-");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_bool_mode_from_str() {
+        assert_eq!("checkbox".parse(), Ok(BoolFormatMode::Checkbox));
+        assert_eq!("background".parse(), Ok(BoolColorMode::Background));
+        assert!("bold".parse::<BoolFormatMode>().is_err());
+        assert_eq!(BoolFormatMode::Text.to_string(), "text");
+    }
+}
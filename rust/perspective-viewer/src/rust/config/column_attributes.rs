@@ -0,0 +1,178 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// The keys a `ColumnAttributes` writes to a plugin's per-column config.
+pub(crate) const COLUMN_ATTRIBUTE_KEYS: &[&str] = &["label", "description", "units"];
+
+/// Display metadata for a column.  This is presentational only - the column
+/// is still referred to by its name in the `ViewConfig`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ColumnAttributes {
+    /// A label to display in place of the column's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// A longer description of the column, shown as a tooltip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The units of the column's values, e.g. `"USD"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub units: Option<String>,
+
+    /// A transform to apply to this column's values, see `ShowValuesAs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_values_as: Option<ShowValuesAs>,
+}
+
+impl ColumnAttributes {
+    pub fn is_empty(&self) -> bool {
+        self.label.is_none()
+            && self.description.is_none()
+            && self.units.is_none()
+            && self.show_values_as.is_none()
+    }
+
+    /// The text to display for a column `name` with these attributes, its
+    /// label (or name) followed by its units or `show_values_as` transform,
+    /// if any.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    pub fn display_name(&self, name: &str) -> String {
+        let label = self.plugin_label(name);
+        match self.plugin_units() {
+            Some(units) => format!("{} ({})", label, units),
+            None => label,
+        }
+    }
+
+    /// The label for a plugin to display, which includes the `show_values_as`
+    /// transform as plugins only render the transformed values.
+    fn plugin_label(&self, name: &str) -> String {
+        let label = self.label.as_deref().unwrap_or(name);
+        match &self.show_values_as {
+            Some(transform) => format!("{} ({})", label, transform),
+            None => label.to_owned(),
+        }
+    }
+
+    fn plugin_units(&self) -> Option<&String> {
+        self.units
+            .as_ref()
+            .filter(|_| self.show_values_as.map_or(true, |x| x.keeps_units()))
+    }
+
+    /// The record of `COLUMN_ATTRIBUTE_KEYS` to merge into a plugin's
+    /// per-column config for a column `name` with these attributes.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    pub fn to_plugin_record(&self, name: &str) -> serde_json::Value {
+        let mut record = serde_json::Map::new();
+        if self.label.is_some() || self.show_values_as.is_some() {
+            record.insert("label".to_owned(), self.plugin_label(name).into());
+        }
+
+        if let Some(description) = &self.description {
+            record.insert("description".to_owned(), description.clone().into());
+        }
+
+        if let Some(units) = self.plugin_units() {
+            record.insert("units".to_owned(), units.clone().into());
+        }
+
+        serde_json::Value::Object(record)
+    }
+}
+
+/// The viewer-owned column attributes, keyed by column name.
+pub type ColumnAttributesConfig = HashMap<String, ColumnAttributes>;
+
+pub type ColumnAttributesUpdate = OptionalUpdate<ColumnAttributesConfig>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_column_attributes_serde() {
+        let attrs: ColumnAttributes = serde_json::from_value(json!({
+            "label": "Sales",
+            "units": "USD",
+            "show_values_as": "running total"
+        }))
+        .unwrap();
+
+        assert_eq!(attrs.label.as_deref(), Some("Sales"));
+        assert_eq!(attrs.description, None);
+        assert_eq!(attrs.show_values_as, Some(ShowValuesAs::RunningTotal));
+        assert_eq!(
+            serde_json::to_value(&attrs).unwrap(),
+            json!({"label": "Sales", "units": "USD", "show_values_as": "running total"})
+        );
+
+        let empty: ColumnAttributes = serde_json::from_str("{}").unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "{}");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_column_attributes_display_name() {
+        let attrs = ColumnAttributes {
+            units: Some("USD".to_owned()),
+            ..ColumnAttributes::default()
+        };
+
+        assert_eq!(attrs.display_name("sales"), "sales (USD)");
+        let attrs = ColumnAttributes {
+            label: Some("Sales".to_owned()),
+            show_values_as: Some(ShowValuesAs::PctOfRow),
+            ..attrs
+        };
+
+        assert_eq!(attrs.display_name("sales"), "Sales (% of row)");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_column_attributes_plugin_record() {
+        assert_eq!(
+            ColumnAttributes::default().to_plugin_record("sales"),
+            json!({})
+        );
+
+        let attrs = ColumnAttributes {
+            description: Some("Gross sales".to_owned()),
+            units: Some("USD".to_owned()),
+            show_values_as: Some(ShowValuesAs::Difference),
+            ..ColumnAttributes::default()
+        };
+
+        assert_eq!(
+            attrs.to_plugin_record("sales"),
+            json!({
+                "label": "sales (difference)",
+                "description": "Gross sales",
+                "units": "USD"
+            })
+        );
+    }
+}
@@ -0,0 +1,662 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::*;
+
+/// The keys each column style config may write to a plugin's per-column
+/// config, by column type.  Plugins may store their own per-column state (such
+/// as the Datagrid's `column_size_override`) alongside these, which must be
+/// preserved when styles are pushed or stripped.
+const NUMBER_STYLE_KEYS: &[&str] = &[
+    "number_fg_mode",
+    "number_bg_mode",
+    "fixed",
+    "pos_fg_color",
+    "neg_fg_color",
+    "pos_bg_color",
+    "neg_bg_color",
+    "fg_gradient",
+    "bg_gradient",
+];
+
+const STRING_STYLE_KEYS: &[&str] = &["format", "string_color_mode", "color"];
+
+const DATETIME_STYLE_KEYS: &[&str] = &[
+    "format",
+    "timeZone",
+    "datetime_color_mode",
+    "color",
+    "dateStyle",
+    "timeStyle",
+    "fractionalSecondDigits",
+    "second",
+    "minute",
+    "hour",
+    "day",
+    "weekday",
+    "month",
+    "year",
+    "hour12",
+];
+
+const BOOL_STYLE_KEYS: &[&str] = &[
+    "format",
+    "true_text",
+    "false_text",
+    "null_text",
+    "bool_color_mode",
+    "true_color",
+    "false_color",
+    "null_color",
+];
+
+/// The style keys valid for a column of type `ty`.
+fn style_keys(ty: Type) -> &'static [&'static str] {
+    match ty {
+        Type::Integer | Type::Float => NUMBER_STYLE_KEYS,
+        Type::String => STRING_STYLE_KEYS,
+        Type::Date | Type::Datetime => DATETIME_STYLE_KEYS,
+        Type::Bool => BOOL_STYLE_KEYS,
+    }
+}
+
+/// The style keys for a type name in a plugin's `default_config`, which are
+/// keyed by `Type` name (though historically `"bool"` rather than
+/// `"boolean"`).
+fn default_config_style_keys(name: &str) -> &'static [&'static str] {
+    match name {
+        "integer" | "float" => NUMBER_STYLE_KEYS,
+        "string" => STRING_STYLE_KEYS,
+        "date" | "datetime" => DATETIME_STYLE_KEYS,
+        "bool" | "boolean" => BOOL_STYLE_KEYS,
+        _ => &[],
+    }
+}
+
+/// The keys of a plugin's `plugin_config.columns` records which are owned by
+/// the viewer, and must be taken out of the plugin's saved config.  A plugin
+/// which declares neither style nor attribute support owns all of its
+/// per-column keys.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PluginColumnKeys {
+    styles: HashSet<String>,
+    attributes: bool,
+}
+
+impl PluginColumnKeys {
+    /// The keys for a plugin which declares style support via its
+    /// `default_config`.  A `default_config` only lists keys with a default
+    /// value, so the style keys for each column type it declares (such as
+    /// `number_fg_mode`) are included as well.
+    ///
+    /// # Arguments
+    /// - `default_config` The plugin's `default_config`, keyed by type name.
+    pub fn from_default_config(default_config: &Value) -> Self {
+        let mut styles = HashSet::new();
+        if let Value::Object(types) = default_config {
+            for (name, defaults) in types.iter() {
+                styles.extend(
+                    default_config_style_keys(name)
+                        .iter()
+                        .map(|x| (*x).to_owned()),
+                );
+                if let Value::Object(defaults) = defaults {
+                    styles.extend(defaults.keys().cloned());
+                }
+            }
+        }
+
+        Self {
+            styles,
+            attributes: false,
+        }
+    }
+
+    /// The keys for a plugin which declares style support, but whose
+    /// `default_config` can't be computed yet (e.g. before it has drawn), in
+    /// which case it may have styles for any column type.
+    pub fn all_styles() -> Self {
+        let styles = [
+            NUMBER_STYLE_KEYS,
+            STRING_STYLE_KEYS,
+            DATETIME_STYLE_KEYS,
+            BOOL_STYLE_KEYS,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|x| (*x).to_owned())
+        .collect();
+
+        Self {
+            styles,
+            attributes: false,
+        }
+    }
+
+    /// Include the `ColumnAttributes` keys, for a plugin which declares
+    /// attribute support.
+    pub fn with_attributes(self, attributes: bool) -> Self {
+        Self { attributes, ..self }
+    }
+}
+
+/// The style for a single column, typed by the column's `Type`.  Serialized,
+/// these are the flat JSON records plugins receive in their
+/// `plugin_config.columns` field.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ColumnStyleConfig {
+    Number(NumberColumnStyleConfig),
+    String(StringColumnStyleConfig),
+    Datetime(DatetimeColumnStyleConfig),
+    Bool(BoolColumnStyleConfig),
+}
+
+macro_rules! impl_column_style_variant {
+    ($variant:ident, $config:ty) => {
+        impl From<$config> for ColumnStyleConfig {
+            fn from(config: $config) -> Self {
+                Self::$variant(config)
+            }
+        }
+
+        impl TryFrom<ColumnStyleConfig> for $config {
+            type Error = String;
+
+            fn try_from(config: ColumnStyleConfig) -> Result<Self, Self::Error> {
+                match config {
+                    ColumnStyleConfig::$variant(x) => Ok(x),
+                    x => Err(format!(
+                        "Expected {} style, found {:?}",
+                        stringify!($variant),
+                        x
+                    )),
+                }
+            }
+        }
+    };
+}
+
+impl_column_style_variant!(Number, NumberColumnStyleConfig);
+impl_column_style_variant!(String, StringColumnStyleConfig);
+impl_column_style_variant!(Datetime, DatetimeColumnStyleConfig);
+impl_column_style_variant!(Bool, BoolColumnStyleConfig);
+
+impl ColumnStyleConfig {
+    /// Deserialize a JSON style record for a column of type `ty`.  The JSON
+    /// format alone is ambiguous (every field is optional), so the column's
+    /// type decides which config it must validate as.
+    ///
+    /// # Arguments
+    /// - `ty` The `View` type of the column this style applies to.
+    /// - `value` The JSON style record.
+    pub fn from_value(ty: Type, value: Value) -> Result<Self, String> {
+        let result = match ty {
+            Type::Integer | Type::Float => serde_json::from_value(value).map(Self::Number),
+            Type::String => serde_json::from_value(value).map(Self::String),
+            Type::Date | Type::Datetime => serde_json::from_value(value).map(Self::Datetime),
+            Type::Bool => serde_json::from_value(value).map(Self::Bool),
+        };
+
+        result.map_err(|e| format!("Invalid {} column style: {}", ty, e))
+    }
+
+    /// Like `from_value()`, but strict - `value` must be an object, and every
+    /// key must be a style key valid for `ty`.  Used to validate styles
+    /// provided directly by the user, where a misspelled or mistyped key
+    /// should be an error rather than silently ignored.
+    ///
+    /// # Arguments
+    /// - `ty` The `View` type of the column this style applies to.
+    /// - `value` The JSON style record.
+    pub fn from_value_strict(ty: Type, value: Value) -> Result<Self, String> {
+        let Value::Object(record) = &value else {
+            return Err(format!("Invalid {} column style: expected an object", ty));
+        };
+
+        let keys = style_keys(ty);
+        if let Some(key) = record.keys().find(|key| !keys.contains(&key.as_str())) {
+            return Err(format!(
+                "Invalid {} column style: unknown key \"{}\", expected one of {}",
+                ty,
+                key,
+                keys.join(", ")
+            ));
+        }
+
+        Self::from_value(ty, value)
+    }
+
+    /// Whether this style can be applied to a column of type `ty`.
+    pub fn is_compatible(&self, ty: Type) -> bool {
+        matches!(
+            (self, ty),
+            (Self::Number(_), Type::Integer | Type::Float)
+                | (Self::String(_), Type::String)
+                | (Self::Datetime(_), Type::Date | Type::Datetime)
+                | (Self::Bool(_), Type::Bool)
+        )
+    }
+
+    /// The JSON record for this style, as sent to the plugin.
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// Whether this style has no non-default settings, in which case the
+    /// column is effectively unstyled.
+    pub fn is_empty(&self) -> bool {
+        matches!(self.to_value(), Value::Object(x) if x.is_empty())
+    }
+}
+
+/// The viewer-owned column styles, keyed by column name.
+pub type ColumnConfig = HashMap<String, ColumnStyleConfig>;
+
+/// Column styles from a `ViewerConfigUpdate`, which can't be typed until the
+/// `Table` (and thus the column types) are known.
+pub type ColumnConfigUpdate = OptionalUpdate<HashMap<String, Value>>;
+
+/// Remove the style keys in `keys` from a plugin's `plugin_config.columns`
+/// records, returning the removed styles by column name.  `ColumnAttributes`
+/// keys are also removed (and discarded) if in `keys`, as these are likewise
+/// owned by the viewer.  Columns with no plugin-specific state left are removed
+/// entirely. Used both to keep styles out of a plugin's saved config and to
+/// migrate layouts saved before styles were owned by the viewer.
+///
+/// # Arguments
+/// - `plugin_config` A JSON plugin config as returned by a plugin's `save()`.
+/// - `keys` The viewer-owned keys of this plugin's per-column config.
+pub fn take_column_styles(
+    plugin_config: &mut Value,
+    keys: &PluginColumnKeys,
+) -> HashMap<String, Value> {
+    let mut styles = HashMap::new();
+    if let Some(Value::Object(columns)) = plugin_config.get_mut("columns") {
+        for (name, column) in columns.iter_mut() {
+            if let Value::Object(column) = column {
+                let style = keys
+                    .styles
+                    .iter()
+                    .filter_map(|key| column.remove(key).map(|x| (key.to_owned(), x)))
+                    .collect::<serde_json::Map<_, _>>();
+
+                if keys.attributes {
+                    for key in COLUMN_ATTRIBUTE_KEYS {
+                        column.remove(*key);
+                    }
+                }
+
+                if !style.is_empty() {
+                    styles.insert(name.to_owned(), Value::Object(style));
+                }
+            }
+        }
+
+        columns.retain(|_, column| !matches!(column, Value::Object(x) if x.is_empty()));
+    }
+
+    styles
+}
+
+/// Remove the `columns` record from a plugin config if it is empty, as it will
+/// be after `take_column_styles()` for plugins which do not store per-column
+/// state of their own.
+///
+/// # Arguments
+/// - `plugin_config` A JSON plugin config, which will be updated in place.
+pub fn strip_empty_columns(plugin_config: &mut Value) {
+    if let Value::Object(config) = plugin_config
+        && matches!(config.get("columns"), Some(Value::Object(x)) if x.is_empty())
+    {
+        config.remove("columns");
+    }
+}
+
+/// Combine a `ColumnConfigUpdate` with styles found in a legacy
+/// `plugin_config` (as returned by `take_column_styles()`).  Explicit
+/// `column_config` records take precedence.
+///
+/// # Arguments
+/// - `update` The `column_config` field of a `ViewerConfigUpdate`.
+/// - `legacy` Styles removed from the same update's `plugin_config`.
+pub fn migrate_column_config(
+    update: ColumnConfigUpdate,
+    legacy: HashMap<String, Value>,
+) -> ColumnConfigUpdate {
+    match update {
+        ColumnConfigUpdate::Missing if !legacy.is_empty() => ColumnConfigUpdate::Update(legacy),
+        ColumnConfigUpdate::Update(mut records) => {
+            for (name, record) in legacy.into_iter() {
+                records.entry(name).or_insert(record);
+            }
+
+            ColumnConfigUpdate::Update(records)
+        }
+        update => update,
+    }
+}
+
+/// Merge `column_config` and `column_attributes` into a plugin's (already
+/// style-free) config, as returned from `take_column_styles()`.
+///
+/// # Arguments
+/// - `plugin_config` A JSON plugin config, which will be updated in place.
+/// - `column_config` The styles to apply.
+/// - `column_attributes` The column attributes to apply.
+pub fn merge_column_styles(
+    plugin_config: &mut Value,
+    column_config: &ColumnConfig,
+    column_attributes: &ColumnAttributesConfig,
+) {
+    if !plugin_config.is_object() {
+        *plugin_config = Value::Object(Default::default());
+    }
+
+    let columns = plugin_config
+        .as_object_mut()
+        .unwrap()
+        .entry("columns")
+        .or_insert_with(|| Value::Object(Default::default()));
+
+    if let Value::Object(columns) = columns {
+        let records = column_config
+            .iter()
+            .map(|(name, style)| (name, style.to_value()))
+            .chain(
+                column_attributes
+                    .iter()
+                    .map(|(name, attrs)| (name, attrs.to_plugin_record(name))),
+            );
+
+        for (name, record) in records {
+            let column = columns
+                .entry(name.to_owned())
+                .or_insert_with(|| Value::Object(Default::default()));
+
+            if let (Value::Object(column), Value::Object(record)) = (column, record) {
+                column.extend(record);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_from_value_strict() {
+        let style = ColumnStyleConfig::from_value_strict(
+            Type::Integer,
+            json!({"fixed": 2, "number_fg_mode": "bar"}),
+        )
+        .unwrap();
+
+        let style = NumberColumnStyleConfig::try_from(style).unwrap();
+        assert_eq!(style.fixed, Some(2));
+        assert_eq!(style.number_fg_mode, NumberForegroundMode::Bar);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_value_strict_errors() {
+        let err = ColumnStyleConfig::from_value_strict(Type::Float, json!({"fixd": 2}));
+        assert!(err.unwrap_err().contains("unknown key \"fixd\""));
+
+        // A valid key, but for a different column type.
+        let err = ColumnStyleConfig::from_value_strict(Type::Float, json!({"true_text": "y"}));
+        assert!(err.is_err());
+
+        let err = ColumnStyleConfig::from_value_strict(Type::Float, json!({"fixed": "two"}));
+        assert!(err.is_err());
+
+        let err = ColumnStyleConfig::from_value_strict(Type::Bool, json!([1]));
+        assert!(err.unwrap_err().contains("expected an object"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_value_lenient() {
+        let style = ColumnStyleConfig::from_value(Type::String, json!({"fixed": 2})).unwrap();
+        assert!(style.is_empty());
+        assert!(style.is_compatible(Type::String));
+        assert!(!style.is_compatible(Type::Float));
+        assert!(BoolColumnStyleConfig::try_from(style).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_column_style_to_value() {
+        let style = ColumnStyleConfig::from(BoolColumnStyleConfig {
+            format: Some(BoolFormatMode::Checkbox),
+            ..BoolColumnStyleConfig::default()
+        });
+
+        assert!(!style.is_empty());
+        assert!(style.is_compatible(Type::Bool));
+        assert_eq!(style.to_value(), json!({"format": "checkbox"}));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_take_column_styles() {
+        let mut plugin_config = json!({
+            "columns": {
+                "x": {"fixed": 2, "column_size_override": 100},
+                "y": {"format": "bar", "color": "#ff0000"},
+            },
+            "scroll_lock": true
+        });
+
+        let styles = take_column_styles(&mut plugin_config, &PluginColumnKeys::all_styles());
+        assert_eq!(styles.len(), 2);
+        assert_eq!(styles["x"], json!({"fixed": 2}));
+        assert_eq!(styles["y"], json!({"format": "bar", "color": "#ff0000"}));
+        assert_eq!(
+            plugin_config,
+            json!({
+                "columns": {"x": {"column_size_override": 100}},
+                "scroll_lock": true
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_take_column_styles_from_default_config() {
+        let keys = PluginColumnKeys::from_default_config(&json!({
+            "float": {"fixed": 2},
+            "string": {"color": "#000", "bg_color": "#fff"}
+        }));
+
+        let mut plugin_config = json!({
+            "columns": {
+                "x": {"fixed": 2, "number_fg_mode": "bar", "true_text": "y"},
+                "y": {"bg_color": "#ff0000", "format": "bar"},
+            }
+        });
+
+        let styles = take_column_styles(&mut plugin_config, &keys);
+        assert_eq!(styles["x"], json!({"fixed": 2, "number_fg_mode": "bar"}));
+        assert_eq!(styles["y"], json!({"bg_color": "#ff0000", "format": "bar"}));
+        assert_eq!(plugin_config, json!({"columns": {"x": {"true_text": "y"}}}));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_take_column_styles_unstylable() {
+        let mut plugin_config = json!({
+            "columns": {"x": {"color": "#ff0000", "format": "bar"}}
+        });
+
+        let styles = take_column_styles(&mut plugin_config, &PluginColumnKeys::default());
+        assert!(styles.is_empty());
+        assert_eq!(
+            plugin_config,
+            json!({"columns": {"x": {"color": "#ff0000", "format": "bar"}}})
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_take_column_styles_no_columns() {
+        let mut plugin_config = json!({"scroll_lock": true});
+        assert!(take_column_styles(&mut plugin_config, &PluginColumnKeys::all_styles()).is_empty());
+        assert_eq!(plugin_config, json!({"scroll_lock": true}));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_migrate_column_config() {
+        let legacy = HashMap::from([
+            ("x".to_owned(), json!({"fixed": 2})),
+            ("y".to_owned(), json!({"fixed": 3})),
+        ]);
+
+        let update =
+            ColumnConfigUpdate::Update(HashMap::from([("x".to_owned(), json!({"fixed": 4}))]));
+
+        let ColumnConfigUpdate::Update(records) = migrate_column_config(update, legacy.clone())
+        else {
+            panic!("Expected update");
+        };
+
+        assert_eq!(records["x"], json!({"fixed": 4}));
+        assert_eq!(records["y"], json!({"fixed": 3}));
+        assert!(matches!(
+            migrate_column_config(ColumnConfigUpdate::Missing, legacy.clone()),
+            ColumnConfigUpdate::Update(x) if x == legacy
+        ));
+
+        assert!(matches!(
+            migrate_column_config(ColumnConfigUpdate::Missing, HashMap::new()),
+            ColumnConfigUpdate::Missing
+        ));
+
+        assert!(matches!(
+            migrate_column_config(ColumnConfigUpdate::SetDefault, legacy),
+            ColumnConfigUpdate::SetDefault
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_merge_column_styles() {
+        let mut plugin_config = json!({
+            "columns": {"x": {"column_size_override": 100}}
+        });
+
+        let style = ColumnStyleConfig::from_value(Type::Float, json!({"fixed": 2})).unwrap();
+        let column_config = ColumnConfig::from([("x".to_owned(), style)]);
+        merge_column_styles(
+            &mut plugin_config,
+            &column_config,
+            &ColumnAttributesConfig::default(),
+        );
+
+        assert_eq!(
+            plugin_config,
+            json!({
+                "columns": {"x": {"column_size_override": 100, "fixed": 2}}
+            })
+        );
+
+        let styles = take_column_styles(&mut plugin_config, &PluginColumnKeys::all_styles());
+        assert_eq!(styles["x"], json!({"fixed": 2}));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_merge_column_attributes() {
+        let mut plugin_config = json!({
+            "columns": {"x": {"column_size_override": 100}}
+        });
+
+        let style = ColumnStyleConfig::from_value(Type::Float, json!({"fixed": 2})).unwrap();
+        let column_config = ColumnConfig::from([("x".to_owned(), style)]);
+        let attrs = ColumnAttributes {
+            label: Some("X".to_owned()),
+            units: Some("USD".to_owned()),
+            ..ColumnAttributes::default()
+        };
+
+        let column_attributes = ColumnAttributesConfig::from([
+            ("x".to_owned(), attrs.clone()),
+            ("y".to_owned(), attrs),
+        ]);
+
+        merge_column_styles(&mut plugin_config, &column_config, &column_attributes);
+        assert_eq!(
+            plugin_config,
+            json!({
+                "columns": {
+                    "x": {"column_size_override": 100, "fixed": 2, "label": "X", "units": "USD"},
+                    "y": {"label": "X", "units": "USD"}
+                }
+            })
+        );
+
+        let mut unattributed = plugin_config.clone();
+        take_column_styles(&mut unattributed, &PluginColumnKeys::all_styles());
+        assert_eq!(
+            unattributed,
+            json!({
+                "columns": {
+                    "x": {"column_size_override": 100, "label": "X", "units": "USD"},
+                    "y": {"label": "X", "units": "USD"}
+                }
+            })
+        );
+
+        let keys = PluginColumnKeys::all_styles().with_attributes(true);
+        let styles = take_column_styles(&mut plugin_config, &keys);
+        assert_eq!(styles.len(), 1);
+        assert_eq!(
+            plugin_config,
+            json!({"columns": {"x": {"column_size_override": 100}}})
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_strip_empty_columns() {
+        let mut plugin_config = json!({"columns": {"y": {"label": "Y"}}, "zoom": 1});
+        take_column_styles(
+            &mut plugin_config,
+            &PluginColumnKeys::default().with_attributes(true),
+        );
+        strip_empty_columns(&mut plugin_config);
+        assert_eq!(plugin_config, json!({"zoom": 1}));
+
+        let mut plugin_config = json!({"columns": {"y": {"column_size_override": 10}}});
+        strip_empty_columns(&mut plugin_config);
+        assert_eq!(
+            plugin_config,
+            json!({"columns": {"y": {"column_size_override": 10}}})
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_merge_column_styles_null_config() {
+        let mut plugin_config = Value::Null;
+        let style = ColumnStyleConfig::from_value(Type::String, json!({"color": "#ff0000"}));
+        let column_config = ColumnConfig::from([("y".to_owned(), style.unwrap())]);
+        merge_column_styles(
+            &mut plugin_config,
+            &column_config,
+            &ColumnAttributesConfig::default(),
+        );
+
+        assert_eq!(
+            plugin_config,
+            json!({"columns": {"y": {"color": "#ff0000"}}})
+        );
+    }
+}
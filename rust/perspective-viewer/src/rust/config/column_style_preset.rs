@@ -0,0 +1,141 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::*;
+
+/// A named column style, which can be applied to any column of a type
+/// compatible with the column it was saved from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "ColumnStylePresetRecord", into = "ColumnStylePresetRecord")]
+pub struct ColumnStylePreset {
+    pub name: String,
+    pub ty: Type,
+    pub style: ColumnStyleConfig,
+}
+
+/// The JSON format of a `ColumnStylePreset`, which must be validated by its
+/// `type` field.
+#[derive(Serialize, Deserialize)]
+struct ColumnStylePresetRecord {
+    name: String,
+
+    #[serde(rename = "type")]
+    ty: Type,
+
+    style: Value,
+}
+
+impl TryFrom<ColumnStylePresetRecord> for ColumnStylePreset {
+    type Error = String;
+
+    fn try_from(record: ColumnStylePresetRecord) -> Result<Self, Self::Error> {
+        let style = ColumnStyleConfig::from_value_strict(record.ty, record.style)
+            .map_err(|e| format!("Preset \"{}\": {}", record.name, e))?;
+
+        Ok(Self {
+            name: record.name,
+            ty: record.ty,
+            style,
+        })
+    }
+}
+
+impl From<ColumnStylePreset> for ColumnStylePresetRecord {
+    fn from(preset: ColumnStylePreset) -> Self {
+        Self {
+            name: preset.name,
+            ty: preset.ty,
+            style: preset.style.to_value(),
+        }
+    }
+}
+
+/// The `localStorage` key presets are persisted to, shared by every
+/// `<perspective-viewer>` on the same origin.
+pub const STYLE_PRESETS_STORAGE_KEY: &str = "perspective-viewer-column-style-presets";
+
+impl ColumnStylePreset {
+    /// Whether this preset can be applied to a column of type `ty`.
+    pub fn is_compatible(&self, ty: Type) -> bool {
+        self.style.is_compatible(ty)
+    }
+}
+
+/// Add `updates` to `presets`, replacing any existing presets of the same
+/// name in place and appending the rest.
+///
+/// # Arguments
+/// - `presets` The existing presets, which will be updated in place.
+/// - `updates` The presets to add.
+pub fn merge_style_presets(
+    presets: &mut Vec<ColumnStylePreset>,
+    updates: impl IntoIterator<Item = ColumnStylePreset>,
+) {
+    for preset in updates.into_iter() {
+        match presets.iter_mut().find(|x| x.name == preset.name) {
+            Some(existing) => *existing = preset,
+            None => presets.push(preset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn preset(name: &str, fixed: u32) -> ColumnStylePreset {
+        let style = json!({ "fixed": fixed });
+        ColumnStylePreset {
+            name: name.to_owned(),
+            ty: Type::Float,
+            style: ColumnStyleConfig::from_value(Type::Float, style).unwrap(),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_preset_serde() {
+        let json = serde_json::to_value(preset("PnL", 2)).unwrap();
+        assert_eq!(
+            json,
+            json!({"name": "PnL", "type": "float", "style": {"fixed": 2}})
+        );
+
+        let rec: ColumnStylePreset = serde_json::from_value(json).unwrap();
+        assert_eq!(rec, preset("PnL", 2));
+        assert!(rec.is_compatible(Type::Integer));
+        assert!(!rec.is_compatible(Type::String));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_preset_invalid_style() {
+        let json = json!({"name": "PnL", "type": "float", "style": {"color": "#ff0000"}});
+        let err = serde_json::from_value::<ColumnStylePreset>(json).unwrap_err();
+        assert!(err.to_string().starts_with("Preset \"PnL\""));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_merge_style_presets() {
+        let mut presets = vec![preset("a", 1), preset("b", 2)];
+        merge_style_presets(&mut presets, [preset("c", 3), preset("a", 4)]);
+        assert_eq!(presets, vec![
+            preset("a", 4),
+            preset("b", 2),
+            preset("c", 3)
+        ]);
+    }
+}
@@ -0,0 +1,134 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use itertools::Itertools;
+
+/// The most values a `CustomOrder` lists explicitly;  values beyond these
+/// sort after them in their natural order.
+pub const MAX_CUSTOM_ORDER_VALUES: usize = 1000;
+
+/// A custom sort order for a string column, for use in `sort`.  Like a
+/// `DateBucket`, this is an expression column aliased with a name generated
+/// from its source column, which evaluates ExprTK's `order()` function, e.g.
+///
+/// ```text
+/// // State (order)
+/// order("State", 'Texas', 'New York')
+/// ```
+///
+/// `order()` places values which are not listed after those which are, so
+/// values which appear in the table later sort at the end.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomOrder {
+    pub alias: String,
+    pub source: String,
+    pub values: Vec<String>,
+}
+
+impl CustomOrder {
+    pub fn new(source: &str, values: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            alias: format!("{} (order)", source),
+            source: source.to_owned(),
+            values: values.into_iter().unique().collect(),
+        }
+    }
+
+    /// Parse an expression string, returning `Some` only if it is of the
+    /// generated form, so hand-written expressions are left alone.
+    pub fn parse(expression: &str) -> Option<Self> {
+        let (alias, expr) = expression.strip_prefix("//")?.split_once('\n')?;
+        let source = alias.trim().strip_suffix(" (order)")?;
+        let args = expr
+            .trim()
+            .strip_prefix("order(\"")?
+            .strip_prefix(source)?
+            .strip_prefix('"')?
+            .strip_suffix(')')?;
+
+        let order = Self::new(source, parse_string_literals(args)?);
+        (order.to_expression() == expression).then_some(order)
+    }
+
+    pub fn to_expression(&self) -> String {
+        let values = self
+            .values
+            .iter()
+            .map(|value| format!(", '{}'", value.replace('\\', "\\\\").replace('\'', "\\'")))
+            .join("");
+
+        format!("// {}\norder(\"{}\"{})", self.alias, self.source, values)
+    }
+}
+
+/// Parse a sequence of `, '...'` ExprTK string literals, as generated by
+/// `CustomOrder::to_expression()`.
+fn parse_string_literals(mut args: &str) -> Option<Vec<String>> {
+    let mut values = vec![];
+    loop {
+        args = args.trim_start();
+        if args.is_empty() {
+            return Some(values);
+        }
+
+        args = args.strip_prefix(',')?.trim_start().strip_prefix('\'')?;
+        let mut value = String::new();
+        let mut chars = args.char_indices();
+        let end = loop {
+            match chars.next()? {
+                (_, '\\') => value.push(chars.next()?.1),
+                (idx, '\'') => break idx,
+                (_, c) => value.push(c),
+            }
+        };
+
+        values.push(value);
+        args = &args[end + 1..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_custom_order_round_trip() {
+        let order = CustomOrder::new(
+            "State",
+            [
+                "Texas",
+                "New York",
+                "O'Higgins, Chile",
+                "back\\slash",
+                "Texas",
+            ]
+            .map(String::from),
+        );
+
+        assert_eq!(order.alias, "State (order)");
+        assert_eq!(order.values.len(), 4);
+        assert_eq!(
+            order.to_expression(),
+            "// State (order)\norder(\"State\", 'Texas', 'New York', 'O\\'Higgins, Chile', \
+             'back\\\\slash')"
+        );
+
+        assert_eq!(CustomOrder::parse(&order.to_expression()), Some(order));
+        assert_eq!(CustomOrder::parse("order(\"State\", 'Texas')"), None);
+        assert_eq!(
+            CustomOrder::parse("// State (order)\norder(\"State\", 'Texas') + 1"),
+            None
+        );
+    }
+}
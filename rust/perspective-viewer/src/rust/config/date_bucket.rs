@@ -0,0 +1,175 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A granularity by which a `date` or `datetime` column can be bucketed when
+/// it is grouped, via the ExprTK `bucket()` function.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DateBucketUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+}
+
+/// The units of the one-click year → month → day hierarchy.
+pub const DATE_BUCKET_HIERARCHY: [DateBucketUnit; 3] = [
+    DateBucketUnit::Year,
+    DateBucketUnit::Month,
+    DateBucketUnit::Day,
+];
+
+impl DateBucketUnit {
+    pub const ALL: [Self; 7] = [
+        Self::Year,
+        Self::Quarter,
+        Self::Month,
+        Self::Week,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+    ];
+
+    /// The units applicable to a column, `date` columns having no time of
+    /// day to bucket.
+    ///
+    /// # Arguments
+    /// - `is_datetime` Whether the column is a `datetime`.
+    pub fn options(is_datetime: bool) -> impl Iterator<Item = Self> {
+        Self::ALL
+            .into_iter()
+            .filter(move |x| is_datetime || !matches!(x, Self::Hour | Self::Minute))
+    }
+
+    /// The unit argument of the ExprTK `bucket()` function.
+    fn to_exprtk(self) -> &'static str {
+        match self {
+            Self::Year => "Y",
+            Self::Quarter => "3M",
+            Self::Month => "M",
+            Self::Week => "W",
+            Self::Day => "D",
+            Self::Hour => "h",
+            Self::Minute => "m",
+        }
+    }
+
+    fn from_exprtk(x: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|unit| unit.to_exprtk() == x)
+    }
+}
+
+impl Display for DateBucketUnit {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Year => "year",
+            Self::Quarter => "quarter",
+            Self::Month => "month",
+            Self::Week => "week",
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+        };
+
+        write!(fmt, "{}", name)
+    }
+}
+
+impl FromStr for DateBucketUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|x| x.to_string() == s)
+            .ok_or_else(|| format!("Unknown date bucket \"{}\"", s))
+    }
+}
+
+/// A `date` or `datetime` column bucketed to a `DateBucketUnit`, for use in
+/// `group_by` or `split_by`.  Like an `AggregateInstance`, this is an
+/// expression column aliased with a name generated from its source column
+/// and unit, e.g.
+///
+/// ```text
+/// // Order Date (month)
+/// bucket("Order Date", 'M')
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DateBucket {
+    pub alias: String,
+    pub source: String,
+    pub unit: DateBucketUnit,
+}
+
+impl DateBucket {
+    pub fn new(source: &str, unit: DateBucketUnit) -> Self {
+        Self {
+            alias: format!("{} ({})", source, unit),
+            source: source.to_owned(),
+            unit,
+        }
+    }
+
+    /// Parse an expression string, returning `Some` only if it is of the
+    /// generated form, so hand-written expressions are left alone.
+    pub fn parse(expression: &str) -> Option<Self> {
+        let (alias, expr) = expression.strip_prefix("//")?.split_once('\n')?;
+        let args = expr.trim().strip_prefix("bucket(")?.strip_suffix(')')?;
+        let (source, unit) = args.rsplit_once(',')?;
+        let source = source.trim().strip_prefix('"')?.strip_suffix('"')?;
+        let unit = unit.trim().strip_prefix('\'')?.strip_suffix('\'')?;
+        let bucket = Self::new(source, DateBucketUnit::from_exprtk(unit)?);
+        (bucket.alias == alias.trim() && !source.contains('"')).then_some(bucket)
+    }
+
+    pub fn to_expression(&self) -> String {
+        format!(
+            "// {}\nbucket(\"{}\", '{}')",
+            self.alias,
+            self.source,
+            self.unit.to_exprtk()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_date_bucket_round_trip() {
+        for unit in DateBucketUnit::ALL {
+            let bucket = DateBucket::new("Order Date", unit);
+            assert_eq!(DateBucket::parse(&bucket.to_expression()), Some(bucket));
+        }
+
+        assert_eq!(
+            DateBucket::parse("// Order Date (month)\nbucket(\"Order Date\", 'M')").map(|x| x.unit),
+            Some(DateBucketUnit::Month)
+        );
+
+        assert_eq!(
+            DateBucket::parse("// By Month\nbucket(\"Order Date\", 'M')"),
+            None
+        );
+
+        assert_eq!(DateBucket::parse("bucket(\"Order Date\", 'M')"), None);
+    }
+}
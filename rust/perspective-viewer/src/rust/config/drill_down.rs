@@ -0,0 +1,95 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use serde::{Deserialize, Serialize};
+
+use super::filters::*;
+
+/// One level of a drill-down into a grouped row, which records the row's path
+/// and the `group_by` and `filter` of the `ViewConfig` it replaced, so the
+/// drill-down can be navigated back out of.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DrillDownLevel {
+    /// The `group_by` values of the row drilled into, e.g. `["East"]`.
+    pub row_path: Vec<Scalar>,
+
+    /// The `group_by` before drilling into `row_path`.
+    pub group_by: Vec<String>,
+
+    /// The `filter` before drilling into `row_path`.
+    pub filter: Vec<Filter>,
+}
+
+/// The filters which select the rows of a group, where each value of
+/// `row_path` is the group's value of the corresponding column of `group_by`
+/// (or of `split_by`, for a column path).
+pub fn row_path_filters(group_by: &[String], row_path: &[Scalar]) -> Vec<Filter> {
+    group_by
+        .iter()
+        .zip(row_path)
+        .map(|(column, value)| {
+            let op = match value {
+                Scalar::Null => FilterOp::IsNull,
+                _ => FilterOp::EQ,
+            };
+
+            Filter(column.clone(), op, FilterTerm::Scalar(value.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_drill_down_level_round_trip() {
+        let level = DrillDownLevel {
+            row_path: vec![Scalar::String("East".to_owned()), Scalar::Null],
+            group_by: vec!["Region".to_owned(), "State".to_owned(), "City".to_owned()],
+            filter: vec![Filter(
+                "Sales".to_owned(),
+                FilterOp::GT,
+                FilterTerm::Scalar(Scalar::Float(100.0)),
+            )],
+        };
+
+        let json = serde_json::to_value(&level).unwrap();
+        assert_eq!(json["row_path"], serde_json::json!(["East", null]));
+        assert_eq!(
+            serde_json::from_value::<DrillDownLevel>(json).unwrap(),
+            level
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_row_path_filters() {
+        let group_by = vec!["Region".to_owned(), "State".to_owned()];
+        let row_path = vec![Scalar::String("East".to_owned()), Scalar::Null];
+        assert_eq!(row_path_filters(&group_by, &row_path), vec![
+            Filter(
+                "Region".to_owned(),
+                FilterOp::EQ,
+                FilterTerm::Scalar(Scalar::String("East".to_owned()))
+            ),
+            Filter(
+                "State".to_owned(),
+                FilterOp::IsNull,
+                FilterTerm::Scalar(Scalar::Null)
+            ),
+        ]);
+
+        assert_eq!(row_path_filters(&group_by, &[]), vec![]);
+    }
+}
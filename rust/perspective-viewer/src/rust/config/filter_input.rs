@@ -0,0 +1,332 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! A small grammar for the text of a filter's `<input>`, which may be a
+//! scalar (e.g. `10k`, `5%`, `2024-01-05 13:00`), a relative date (e.g.
+//! `yesterday`), a range `lo..hi` (which becomes a `between` filter), or a
+//! comma-separated list (which becomes an `in` filter).
+
+use std::str::FromStr;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+use super::column_type::*;
+use super::filters::*;
+use super::relative_date::*;
+use crate::utils::*;
+
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%m/%d/%Y",
+    "%d %b %Y",
+    "%b %d %Y",
+    "%b %d, %Y",
+    "%d %B %Y",
+    "%B %d %Y",
+    "%B %d, %Y",
+];
+
+const TIME_FORMATS: &[&str] = &["%H:%M", "%H:%M:%S", "%H:%M:%S%.f"];
+
+/// Parse the text of a filter's `<input>` into the filter's new op and term,
+/// or `None` if the input is empty.  Ranges and lists change the op (to
+/// `between` and `in` respectively), as do relative dates when `op` is not
+/// a comparison.  A list is of values of type `ty`, which
+/// `ViewConfig::as_jsvalue()` lowers to an expression filter unless they are
+/// strings, as the engine's `in` only compares strings.
+///
+/// # Arguments
+/// - `input` The text of the `<input>`.
+/// - `ty` The type of the filter's column.
+/// - `op` The filter's current op.
+/// - `time_zone` The time zone of datetime input, or `None` for the browser's
+///   time zone.
+pub fn parse_filter_input(
+    input: &str,
+    ty: Type,
+    op: FilterOp,
+    time_zone: Option<&str>,
+) -> Result<Option<(FilterOp, FilterTerm)>, String> {
+    // Strings are taken verbatim, as they may legitimately contain `,` or `..`.
+    if ty == Type::String {
+        return Ok(Some(match op {
+            FilterOp::In | FilterOp::NotIn => (
+                op,
+                FilterTerm::Array(
+                    input
+                        .split(',')
+                        .map(|x| Scalar::String(x.trim().to_owned()))
+                        .collect(),
+                ),
+            ),
+            _ => (op, FilterTerm::Scalar(Scalar::String(input.to_owned()))),
+        }));
+    }
+
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    if ty == Type::Bool {
+        let term = FilterTerm::Scalar(parse_filter_scalar(input, ty, time_zone)?);
+        return Ok(Some((op, term)));
+    }
+
+    if matches!(op, FilterOp::In | FilterOp::NotIn) || input.contains(',') {
+        let values = input
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(|x| parse_filter_scalar(x, ty, time_zone))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let op = if op == FilterOp::NotIn {
+            FilterOp::NotIn
+        } else {
+            FilterOp::In
+        };
+
+        return Ok(Some((op, FilterTerm::Array(values))));
+    }
+
+    if let Some((lo, hi)) = input.split_once("..") {
+        let bound = |x: &str| match x.trim() {
+            "" => Ok(Scalar::Null),
+            x => parse_filter_scalar(x, ty, time_zone),
+        };
+
+        let term = FilterTerm::Array(vec![bound(lo)?, bound(hi)?]);
+        return Ok(Some((FilterOp::Between, term)));
+    }
+
+    let is_comparison = matches!(
+        op,
+        FilterOp::GT | FilterOp::GTE | FilterOp::LT | FilterOp::LTE
+    );
+
+    if matches!(ty, Type::Date | Type::Datetime) {
+        if let Ok(relative) = RelativeDate::from_str(input) {
+            let op = match op {
+                _ if is_comparison => op,
+                _ if relative.is_instant() => FilterOp::LT,
+                _ => FilterOp::EQ,
+            };
+
+            return Ok(Some((op, FilterTerm::Relative(RelativeDateTerm(relative)))));
+        }
+    }
+
+    let op = match op {
+        FilterOp::Between | FilterOp::In | FilterOp::NotIn => FilterOp::EQ,
+        op => op,
+    };
+
+    Ok(Some((
+        op,
+        FilterTerm::Scalar(parse_filter_scalar(input, ty, time_zone)?),
+    )))
+}
+
+/// Parse a single value of a filter on a column of type `ty`.
+///
+/// # Arguments
+/// - `input` The text of the value, e.g. `10k`.
+/// - `ty` The type of the filter's column.
+/// - `time_zone` The time zone of datetime input, or `None` for the browser's
+///   time zone.
+pub fn parse_filter_scalar(
+    input: &str,
+    ty: Type,
+    time_zone: Option<&str>,
+) -> Result<Scalar, String> {
+    match ty {
+        Type::String => Ok(Scalar::String(input.to_owned())),
+        Type::Integer => {
+            let x = parse_number(input)?;
+            if (x - x.round()).abs() > 1e-9 {
+                Err(format!("\"{}\" is not an integer", input.trim()))
+            } else {
+                Ok(Scalar::Float(x.round()))
+            }
+        }
+        Type::Float => Ok(Scalar::Float(parse_number(input)?)),
+        Type::Bool => match input.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Scalar::Bool(true)),
+            "false" | "no" | "0" => Ok(Scalar::Bool(false)),
+            x => Err(format!("\"{}\" is not true or false", x)),
+        },
+        Type::Date => {
+            let date = parse_date(input.trim())
+                .ok_or_else(|| format!("\"{}\" is not a date", input.trim()))?;
+
+            let posix = date.and_hms_opt(0, 0, 0).unwrap().timestamp_millis();
+            Ok(Scalar::DateTime(posix as f64))
+        }
+        Type::Datetime => {
+            let datetime = parse_datetime(input.trim())
+                .ok_or_else(|| format!("\"{}\" is not a date or time", input.trim()))?;
+
+            Ok(Scalar::DateTime(local_to_posix(datetime, time_zone)))
+        }
+    }
+}
+
+/// Parse a number, with an optional magnitude suffix (`k`, `m`, `b` or `t`)
+/// or `%`, e.g. `1.5k` or `5%`.
+fn parse_number(input: &str) -> Result<f64, String> {
+    let error = || format!("\"{}\" is not a number", input.trim());
+    let number = input.trim().replace('_', "");
+    let (mantissa, exponent) = match number.chars().last().map(|x| x.to_ascii_lowercase()) {
+        Some('%') => (&number[..number.len() - 1], -2),
+        Some('k') => (&number[..number.len() - 1], 3),
+        Some('m') => (&number[..number.len() - 1], 6),
+        Some('b') => (&number[..number.len() - 1], 9),
+        Some('t') => (&number[..number.len() - 1], 12),
+        _ => (number.as_str(), 0),
+    };
+
+    // Scaling via the exponent, rather than by multiplication, avoids
+    // rounding errors such as `1.1k == 1100.0000000000002`.
+    let mantissa = mantissa.trim();
+    let value = if exponent == 0 {
+        mantissa.parse::<f64>()
+    } else if mantissa.contains(['e', 'E']) {
+        mantissa.parse::<f64>().map(|x| x * 10_f64.powi(exponent))
+    } else {
+        format!("{}e{}", mantissa, exponent).parse::<f64>()
+    };
+
+    match value {
+        Ok(x) if x.is_finite() && !mantissa.is_empty() => Ok(x),
+        _ => Err(error()),
+    }
+}
+
+fn parse_date(input: &str) -> Option<NaiveDate> {
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(input, format).ok())
+}
+
+fn parse_datetime(input: &str) -> Option<NaiveDateTime> {
+    DATE_FORMATS
+        .iter()
+        .flat_map(|date| {
+            TIME_FORMATS.iter().flat_map(move |time| {
+                [" ", "T"]
+                    .into_iter()
+                    .map(move |sep| format!("{}{}{}", date, sep, time))
+            })
+        })
+        .find_map(|format| NaiveDateTime::parse_from_str(input, &format).ok())
+        .or_else(|| parse_date(input)?.and_hms_opt(0, 0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_parse_numbers() {
+        let parse = |x| parse_filter_scalar(x, Type::Float, None);
+        assert_eq!(parse("1e6"), Ok(Scalar::Float(1_000_000.0)));
+        assert_eq!(parse("1.1k"), Ok(Scalar::Float(1100.0)));
+        assert_eq!(parse("2.5M"), Ok(Scalar::Float(2_500_000.0)));
+        assert_eq!(parse("5%"), Ok(Scalar::Float(0.05)));
+        assert!(parse("k").is_err());
+        assert!(parse("ten").is_err());
+        assert!(parse_filter_scalar("1.5", Type::Integer, None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_parse_ranges_and_lists() {
+        assert_eq!(
+            parse_filter_input("1..5k", Type::Integer, FilterOp::EQ, None),
+            Ok(Some((
+                FilterOp::Between,
+                FilterTerm::Array(vec![Scalar::Float(1.0), Scalar::Float(5000.0)])
+            )))
+        );
+
+        assert_eq!(
+            parse_filter_input("..5", Type::Float, FilterOp::EQ, None),
+            Ok(Some((
+                FilterOp::Between,
+                FilterTerm::Array(vec![Scalar::Null, Scalar::Float(5.0)])
+            )))
+        );
+
+        assert_eq!(
+            parse_filter_input("1, 2", Type::Integer, FilterOp::NotIn, None),
+            Ok(Some((
+                FilterOp::NotIn,
+                FilterTerm::Array(vec![Scalar::Float(1.0), Scalar::Float(2.0)])
+            )))
+        );
+
+        let Ok(Some((op, term))) = parse_filter_input("1, 2", Type::Integer, FilterOp::EQ, None)
+        else {
+            panic!("\"1, 2\" is not a list");
+        };
+
+        let filter = Filter("x".to_owned(), op, term);
+        assert!(filter.is_lowered());
+        assert_eq!(
+            filter.lower("alias", "\"x\"").1,
+            Some("// alias\nfloat(\"x\") == 1 or float(\"x\") == 2".to_owned())
+        );
+
+        assert_eq!(
+            parse_filter_input("a,b", Type::String, FilterOp::EQ, None),
+            Ok(Some((
+                FilterOp::EQ,
+                FilterTerm::Scalar(Scalar::String("a,b".to_owned()))
+            )))
+        );
+
+        assert!(parse_filter_input("1, x", Type::Integer, FilterOp::EQ, None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_parse_dates() {
+        let midnight = |y, m, d| {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            Scalar::DateTime(date.and_hms_opt(0, 0, 0).unwrap().timestamp_millis() as f64)
+        };
+
+        assert_eq!(
+            parse_filter_scalar("2024-01-05", Type::Date, None),
+            Ok(midnight(2024, 1, 5))
+        );
+        assert_eq!(
+            parse_filter_scalar("01/05/2024", Type::Date, None),
+            Ok(midnight(2024, 1, 5))
+        );
+        assert_eq!(
+            parse_filter_scalar("Jan 5, 2024", Type::Date, None),
+            Ok(midnight(2024, 1, 5))
+        );
+        assert_eq!(
+            parse_filter_input("yesterday", Type::Date, FilterOp::GT, None),
+            Ok(Some((
+                FilterOp::GT,
+                FilterTerm::Relative(RelativeDateTerm(RelativeDate::Yesterday))
+            )))
+        );
+
+        assert!(parse_filter_scalar("2024-13-01", Type::Date, None).is_err());
+    }
+}
@@ -10,11 +10,18 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use chrono::{Datelike, TimeZone, Utc};
 use itertools::Itertools;
+use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::aggregates::*;
+use super::relative_date::*;
 
 #[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -29,6 +36,33 @@ pub enum Scalar {
     // Int(u32)
 }
 
+impl Scalar {
+    /// Convert a value returned by the engine (e.g. from `to_columns()`) to a
+    /// `Scalar`, or `Scalar::Null` if it is null or of an unsupported type.
+    pub fn from_jsvalue(value: &JsValue) -> Self {
+        if let Some(x) = value.as_string() {
+            Self::String(x)
+        } else if let Some(x) = value.as_f64() {
+            Self::Float(x)
+        } else if let Some(x) = value.as_bool() {
+            Self::Bool(x)
+        } else {
+            Self::Null
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Float(x) | Self::DateTime(x), Self::Float(y) | Self::DateTime(y)) => {
+                x.partial_cmp(y)
+            }
+            (Self::String(x), Self::String(y)) => Some(x.cmp(y)),
+            (Self::Bool(x), Self::Bool(y)) => Some(x.cmp(y)),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Scalar {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         match self {
@@ -83,6 +117,30 @@ pub enum FilterOp {
 
     #[serde(rename = "!=")]
     NE,
+
+    #[serde(rename = "top n")]
+    TopN,
+
+    #[serde(rename = "bottom n")]
+    BottomN,
+
+    #[serde(rename = "between")]
+    Between,
+
+    #[serde(rename = "not contains")]
+    NotContains,
+
+    #[serde(rename = "not begins with")]
+    NotBeginsWith,
+
+    #[serde(rename = "matches")]
+    Matches,
+
+    #[serde(rename = "== (ignore case)")]
+    EqIgnoreCase,
+
+    #[serde(rename = "!= (ignore case)")]
+    NeIgnoreCase,
 }
 
 impl Display for FilterOp {
@@ -101,6 +159,14 @@ impl Display for FilterOp {
             Self::GTE => ">=",
             Self::LTE => "<=",
             Self::NE => "!=",
+            Self::TopN => "top n",
+            Self::BottomN => "bottom n",
+            Self::Between => "between",
+            Self::NotContains => "not contains",
+            Self::NotBeginsWith => "not begins with",
+            Self::Matches => "matches",
+            Self::EqIgnoreCase => "== (ignore case)",
+            Self::NeIgnoreCase => "!= (ignore case)",
         };
 
         write!(fmt, "{}", op)
@@ -125,16 +191,85 @@ impl FromStr for FilterOp {
             ">=" => Ok(Self::GTE),
             "<=" => Ok(Self::LTE),
             "!=" => Ok(Self::NE),
+            "top n" => Ok(Self::TopN),
+            "bottom n" => Ok(Self::BottomN),
+            "between" => Ok(Self::Between),
+            "not contains" => Ok(Self::NotContains),
+            "not begins with" => Ok(Self::NotBeginsWith),
+            "matches" => Ok(Self::Matches),
+            "== (ignore case)" => Ok(Self::EqIgnoreCase),
+            "!= (ignore case)" => Ok(Self::NeIgnoreCase),
             x => Err(format!("Unknown filter operator {}", x)),
         }
     }
 }
 
+impl FilterOp {
+    /// Is this a "top n" filter, which must be resolved by the viewer (via
+    /// `TopNFilter`) before it can be applied by the engine?
+    pub fn is_top_n(&self) -> bool {
+        matches!(self, Self::TopN | Self::BottomN)
+    }
+
+    /// Is this an op the engine does not support, which must be lowered (via
+    /// `Filter::lower()`) to native filters before it can be applied?
+    pub fn is_lowered(&self) -> bool {
+        matches!(
+            self,
+            Self::Between
+                | Self::NotContains
+                | Self::NotBeginsWith
+                | Self::Matches
+                | Self::EqIgnoreCase
+                | Self::NeIgnoreCase
+        )
+    }
+}
+
+/// The term of a `FilterOp::TopN` or `FilterOp::BottomN` filter, which keeps
+/// the `n` values of the filter column with the largest (or smallest) `by`
+/// column, as aggregated by `aggregate` (or the `by` column's default).
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+pub struct TopNFilter {
+    pub n: u32,
+    pub by: String,
+
+    #[serde(default)]
+    pub aggregate: Option<Aggregate>,
+}
+
+// `FilterTerm` is untagged, so `TopNFilter` is always serialized as a map -
+// as a sequence (e.g. in messagepack), it would be indistinguishable from
+// `FilterTerm::Array`.
+impl Serialize for TopNFilter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = if self.aggregate.is_some() { 3 } else { 2 };
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("n", &self.n)?;
+        map.serialize_entry("by", &self.by)?;
+        if let Some(aggregate) = &self.aggregate {
+            map.serialize_entry("aggregate", aggregate)?;
+        }
+
+        map.end()
+    }
+}
+
 #[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum FilterTerm {
     Scalar(Scalar),
     Array(Vec<Scalar>),
+    TopN(TopNFilter),
+    Relative(RelativeDateTerm),
+}
+
+impl FilterTerm {
+    /// Is this a `RelativeDate`, which must be resolved by the viewer before
+    /// the engine can apply it?
+    pub fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative(_))
+    }
 }
 
 impl Display for FilterTerm {
@@ -149,6 +284,8 @@ impl Display for FilterTerm {
                 Itertools::intersperse(xs.iter().map(|x| format!("{}", x)), ",".to_owned())
                     .collect::<String>()
             )?,
+            Self::TopN(x) => write!(fmt, "{}", x.n)?,
+            Self::Relative(x) => write!(fmt, "{}", x.0)?,
         }
 
         Ok(())
@@ -158,3 +295,311 @@ impl Display for FilterTerm {
 #[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 #[serde()]
 pub struct Filter(pub String, pub FilterOp, pub FilterTerm);
+
+impl Filter {
+    /// Does `value` satisfy this filter?  Used to evaluate filters the engine
+    /// does not apply itself, e.g. `ViewConfig::having`.  Like the engine's,
+    /// string comparisons other than `==`, `!=` and `matches` ignore case.
+    ///
+    /// # Arguments
+    /// - `value` The value to test, e.g. an aggregate of this filter's column.
+    pub fn matches(&self, value: &Scalar) -> bool {
+        match (self.1, &self.2, value) {
+            (FilterOp::IsNull, _, value) => *value == Scalar::Null,
+            (FilterOp::IsNotNull, _, value) => *value != Scalar::Null,
+            (_, _, Scalar::Null) => false,
+            (FilterOp::In, FilterTerm::Array(xs), value) => {
+                xs.iter().any(|x| x.compare(value) == Some(Ordering::Equal))
+            }
+            (FilterOp::NotIn, FilterTerm::Array(xs), value) => {
+                xs.iter().all(|x| x.compare(value) != Some(Ordering::Equal))
+            }
+            (FilterOp::Contains, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                y.to_lowercase().contains(&x.to_lowercase())
+            }
+            (FilterOp::BeginsWith, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                y.to_lowercase().starts_with(&x.to_lowercase())
+            }
+            (FilterOp::EndsWith, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                y.to_lowercase().ends_with(&x.to_lowercase())
+            }
+            (FilterOp::Between, FilterTerm::Array(xs), value) => match xs.as_slice() {
+                [lo, hi] => {
+                    (*lo == Scalar::Null || value.compare(lo).map_or(false, |x| x.is_ge()))
+                        && (*hi == Scalar::Null || value.compare(hi).map_or(false, |x| x.is_le()))
+                }
+                _ => false,
+            },
+            (FilterOp::NotContains, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                !y.to_lowercase().contains(&x.to_lowercase())
+            }
+            (FilterOp::NotBeginsWith, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                !y.to_lowercase().starts_with(&x.to_lowercase())
+            }
+            (FilterOp::Matches, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                regex::Regex::new(x).map_or(false, |re| re.is_match(y))
+            }
+            (FilterOp::EqIgnoreCase, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                x.to_lowercase() == y.to_lowercase()
+            }
+            (FilterOp::NeIgnoreCase, FilterTerm::Scalar(Scalar::String(x)), Scalar::String(y)) => {
+                x.to_lowercase() != y.to_lowercase()
+            }
+            (op, FilterTerm::Scalar(term), value) => match value.compare(term) {
+                Some(ordering) => match op {
+                    FilterOp::EQ => ordering == Ordering::Equal,
+                    FilterOp::NE => ordering != Ordering::Equal,
+                    FilterOp::GT => ordering == Ordering::Greater,
+                    FilterOp::GTE => ordering != Ordering::Less,
+                    FilterOp::LT => ordering == Ordering::Less,
+                    FilterOp::LTE => ordering != Ordering::Greater,
+                    _ => false,
+                },
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Must this filter be lowered for the engine to apply it, see `lower()`?
+    pub fn is_lowered(&self) -> bool {
+        self.1.is_lowered() || self.is_typed_in()
+    }
+
+    /// Is this an `in` or `not in` filter with a non-string value, which the
+    /// engine's `in` (which only compares strings) does not support?
+    fn is_typed_in(&self) -> bool {
+        matches!(self.1, FilterOp::In | FilterOp::NotIn)
+            && matches!(&self.2, FilterTerm::Array(xs) if xs.iter().any(|x| !matches!(x, Scalar::String(_))))
+    }
+
+    /// Lower this filter to filters the engine supports natively.  String ops
+    /// the engine lacks are evaluated by a generated boolean expression
+    /// column, which is returned as a `(filters, expression)` pair, as are
+    /// `in` and `not in` filters of non-string values, by an `==` comparison
+    /// per value; `between` is simply split into two comparisons.  An op with
+    /// a missing or empty term is lowered to no filters at all.
+    ///
+    /// # Arguments
+    /// - `alias` The alias for the generated expression column, if any.
+    /// - `source` The expression which references this filter's column, e.g.
+    ///   `"Sales"`.
+    pub fn lower(&self, alias: &str, source: &str) -> (Vec<Filter>, Option<String>) {
+        let Filter(column, op, term) = self;
+        let (expression, expected) = match (op, term) {
+            (FilterOp::Between, FilterTerm::Array(xs)) => {
+                let bound = |op, x: Option<&Scalar>| {
+                    x.filter(|x| **x != Scalar::Null)
+                        .map(|x| Filter(column.clone(), op, FilterTerm::Scalar(x.clone())))
+                };
+
+                let filters = [
+                    bound(FilterOp::GTE, xs.get(0)),
+                    bound(FilterOp::LTE, xs.get(1)),
+                ];
+
+                return (filters.into_iter().flatten().collect(), None);
+            }
+            (FilterOp::In | FilterOp::NotIn, FilterTerm::Array(xs)) if self.is_typed_in() => {
+                let terms = xs.iter().map(|x| eq_expression(source, x)).join(" or ");
+                (terms, *op == FilterOp::In)
+            }
+            (_, FilterTerm::Scalar(Scalar::String(x))) if op.is_lowered() && !x.is_empty() => {
+                let (pattern, expected) = match op {
+                    FilterOp::NotContains => (format!("(?i){}", escape_regex(x)), false),
+                    FilterOp::NotBeginsWith => (format!("(?i)^{}", escape_regex(x)), false),
+                    FilterOp::Matches => (x.clone(), true),
+                    FilterOp::EqIgnoreCase => (format!("(?i)^{}$", escape_regex(x)), true),
+                    FilterOp::NeIgnoreCase => (format!("(?i)^{}$", escape_regex(x)), false),
+                    _ => return (vec![], None),
+                };
+
+                let pattern = string_literal(&pattern);
+                (format!("match({}, {})", source, pattern), expected)
+            }
+            _ if op.is_lowered() => return (vec![], None),
+            _ => return (vec![self.clone()], None),
+        };
+
+        let expression = format!("// {}\n{}", alias, expression);
+        let filter = Filter(
+            alias.to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Scalar(Scalar::Bool(expected)),
+        );
+
+        (vec![filter], Some(expression))
+    }
+}
+
+/// An ExprTK string literal of `x`.
+fn string_literal(x: &str) -> String {
+    format!("'{}'", x.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// An expression which is `true` where `source` equals `value`.  The engine's
+/// `==` requires both sides to have the same type, so numbers are compared
+/// as floats, and a date time at midnight UTC (the representation of a date
+/// column's values) is also compared as a date.
+///
+/// # Arguments
+/// - `source` The expression which references the filter's column.
+/// - `value` The value to compare to.
+fn eq_expression(source: &str, value: &Scalar) -> String {
+    match value {
+        Scalar::Null => format!("is_null({})", source),
+        Scalar::String(x) => format!("{} == {}", source, string_literal(x)),
+        Scalar::Bool(x) => format!("{} == {}", source, if *x { "True" } else { "False" }),
+        Scalar::Float(x) => format!("float({}) == {}", source, x),
+        Scalar::DateTime(x) => {
+            let is_midnight = (*x as i64).rem_euclid(86_400_000) == 0;
+            let datetime = Utc.timestamp_millis_opt(*x as i64).single();
+            match datetime.filter(|_| is_midnight) {
+                Some(date) => format!(
+                    "(float({}) == {} or {} == date({}, {}, {}))",
+                    source,
+                    x,
+                    source,
+                    date.year(),
+                    date.month(),
+                    date.day()
+                ),
+                None => format!("float({}) == {}", source, x),
+            }
+        }
+    }
+}
+
+/// Escape the special characters of `x`, so it matches literally as a regular
+/// expression.
+fn escape_regex(x: &str) -> String {
+    let mut escaped = String::with_capacity(x.len());
+    for c in x.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn string_filter(op: FilterOp, term: &str) -> Filter {
+        Filter(
+            "City".to_owned(),
+            op,
+            FilterTerm::Scalar(Scalar::String(term.to_owned())),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_matches_string_ops_ignore_case() {
+        let value = Scalar::String("St. Paul".to_owned());
+        assert!(string_filter(FilterOp::Contains, "Paul").matches(&value));
+        assert!(string_filter(FilterOp::Contains, "paul").matches(&value));
+        assert!(!string_filter(FilterOp::Contains, "pauls").matches(&value));
+        assert!(string_filter(FilterOp::BeginsWith, "st.").matches(&value));
+        assert!(string_filter(FilterOp::EndsWith, "PAUL").matches(&value));
+        assert!(!string_filter(FilterOp::NotContains, "Paul").matches(&value));
+        assert!(!string_filter(FilterOp::NotContains, "paul").matches(&value));
+        assert!(string_filter(FilterOp::NotContains, "pauls").matches(&value));
+        assert!(!string_filter(FilterOp::NotBeginsWith, "St.").matches(&value));
+        assert!(!string_filter(FilterOp::NotBeginsWith, "st.").matches(&value));
+        assert!(string_filter(FilterOp::NotBeginsWith, "Paul").matches(&value));
+        assert!(string_filter(FilterOp::EqIgnoreCase, "st. paul").matches(&value));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_matches_invalid_pattern() {
+        let value = Scalar::String("St. Paul".to_owned());
+        assert!(string_filter(FilterOp::Matches, "^St\\. P").matches(&value));
+        assert!(!string_filter(FilterOp::Matches, "St. (Paul").matches(&value));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_lower_not_begins_with() {
+        let (filters, expression) =
+            string_filter(FilterOp::NotBeginsWith, "St.").lower("alias", "\"City\"");
+
+        assert_eq!(filters, vec![Filter(
+            "alias".to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Scalar(Scalar::Bool(false))
+        )]);
+
+        assert_eq!(
+            expression,
+            Some("// alias\nmatch(\"City\", '(?i)^St\\\\.')".to_owned())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_lower_in_numbers() {
+        let filter = Filter(
+            "x".to_owned(),
+            FilterOp::In,
+            FilterTerm::Array(vec![Scalar::Float(1.5), Scalar::Null]),
+        );
+
+        let (filters, expression) = filter.lower("alias", "\"x\"");
+        assert_eq!(filters, vec![Filter(
+            "alias".to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Scalar(Scalar::Bool(true))
+        )]);
+
+        assert_eq!(
+            expression,
+            Some("// alias\nfloat(\"x\") == 1.5 or is_null(\"x\")".to_owned())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_lower_not_in_dates() {
+        // 2024-01-15 and 2024-01-15 12:00 UTC
+        let filter = Filter(
+            "d".to_owned(),
+            FilterOp::NotIn,
+            FilterTerm::Array(vec![
+                Scalar::DateTime(1705276800000.0),
+                Scalar::DateTime(1705320000000.0),
+            ]),
+        );
+
+        let (filters, expression) = filter.lower("alias", "\"d\"");
+        assert_eq!(filters, vec![Filter(
+            "alias".to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Scalar(Scalar::Bool(false))
+        )]);
+
+        assert_eq!(
+            expression,
+            Some(
+                "// alias\n(float(\"d\") == 1705276800000 or \"d\" == date(2024, 1, 15)) or \
+                 float(\"d\") == 1705320000000"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_lower_in_strings_is_native() {
+        let filter = Filter(
+            "City".to_owned(),
+            FilterOp::In,
+            FilterTerm::Array(vec![Scalar::String("St. Paul".to_owned())]),
+        );
+
+        assert!(!filter.is_lowered());
+        let lowered = filter.lower("alias", "\"City\"");
+        assert_eq!(lowered, (vec![filter], None));
+    }
+}
@@ -13,21 +13,45 @@
 //! A collection of (de-)serializable structs which capture the application
 //! state, suitable for persistence, history, etc. features.
 
+mod aggregate_instance;
 mod aggregates;
+mod bool_column_style;
+mod column_attributes;
+mod column_config;
+mod column_style_preset;
 mod column_type;
+mod custom_order;
+mod date_bucket;
 mod datetime_column_style;
+mod drill_down;
+mod filter_input;
 mod filters;
 mod number_column_style;
+mod numeric_bins;
+mod relative_date;
+mod show_values_as;
 mod sort;
 mod string_column_style;
 mod view_config;
 mod viewer_config;
 
+pub use aggregate_instance::*;
 pub use aggregates::*;
+pub use bool_column_style::*;
+pub use column_attributes::*;
+pub use column_config::*;
+pub use column_style_preset::*;
 pub use column_type::*;
+pub use custom_order::*;
+pub use date_bucket::*;
 pub use datetime_column_style::*;
+pub use drill_down::*;
+pub use filter_input::*;
 pub use filters::*;
 pub use number_column_style::*;
+pub use numeric_bins::*;
+pub use relative_date::*;
+pub use show_values_as::*;
 pub use sort::*;
 pub use string_column_style::*;
 pub use view_config::*;
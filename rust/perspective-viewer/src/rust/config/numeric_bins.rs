@@ -0,0 +1,266 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use itertools::Itertools;
+
+/// The most bins `NumericBins` may generate, as each is a branch of the
+/// generated expression.
+pub const MAX_NUMERIC_BINS: usize = 100;
+
+/// A numeric column binned into ranges between sorted `breakpoints`, for use
+/// in `group_by` or `split_by`.  Like a `DateBucket`, this is an expression
+/// column aliased with a name generated from its source column, which
+/// evaluates to a readable label for each range, e.g.
+///
+/// ```text
+/// // Sales (bins)
+/// // breakpoints: 0, 100
+/// if ("Sales" < 0) '< 0';
+/// else if ("Sales" < 100) '0 - 100';
+/// else '>= 100'
+/// ```
+///
+/// `group_by` sorts these labels as strings, so a companion expression
+/// evaluates the index of each range instead, which is added to `sort` to
+/// preserve the numeric order of the ranges, e.g.
+///
+/// ```text
+/// // Sales (bins order)
+/// // breakpoints: 0, 100
+/// if ("Sales" < 0) 0;
+/// else if ("Sales" < 100) 1;
+/// else 2
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericBins {
+    pub alias: String,
+    pub order_alias: String,
+    pub source: String,
+    pub breakpoints: Vec<f64>,
+}
+
+impl NumericBins {
+    pub fn new(source: &str, breakpoints: impl IntoIterator<Item = f64>) -> Self {
+        let breakpoints = breakpoints
+            .into_iter()
+            .filter(|x| x.is_finite())
+            .sorted_by(|x, y| x.total_cmp(y))
+            .dedup()
+            .collect();
+
+        Self {
+            alias: format!("{} (bins)", source),
+            order_alias: format!("{} (bins order)", source),
+            source: source.to_owned(),
+            breakpoints,
+        }
+    }
+
+    /// Bins of a fixed `width`, aligned to multiples of `width`, which cover
+    /// the range `min` to `max`.
+    pub fn with_width(source: &str, (min, max): (f64, f64), width: f64) -> Result<Self, String> {
+        if !(width > 0_f64 && width.is_finite()) {
+            return Err("Bin width must be a positive number".to_owned());
+        }
+
+        let start = (min / width).floor();
+        let count = ((max / width).floor() - start) as usize + 1;
+        if count > MAX_NUMERIC_BINS {
+            return Err(format!(
+                "A width of {} makes {} bins, more than the maximum of {}",
+                width, count, MAX_NUMERIC_BINS
+            ));
+        }
+
+        let precision = decimal_precision(width);
+        Ok(Self::new(
+            source,
+            (0..=count).map(|i| round_to((start + i as f64) * width, precision)),
+        ))
+    }
+
+    /// `count` bins of equal width which span the range `min` to `max`.
+    pub fn with_count(source: &str, (min, max): (f64, f64), count: usize) -> Result<Self, String> {
+        if count == 0 || count > MAX_NUMERIC_BINS {
+            return Err(format!(
+                "The number of bins must be between 1 and {}",
+                MAX_NUMERIC_BINS
+            ));
+        }
+
+        let width = (max - min) / count as f64;
+        if width <= 0_f64 {
+            return Ok(Self::new(source, [min]));
+        }
+
+        let precision = decimal_precision(width);
+        Ok(Self::new(
+            source,
+            (0..=count).map(|i| round_to(min + i as f64 * width, precision)),
+        ))
+    }
+
+    /// The number of bounded ranges, excluding the open ranges below the
+    /// first and above the last breakpoint.
+    pub fn count(&self) -> usize {
+        self.breakpoints.len().saturating_sub(1)
+    }
+
+    /// The label of each range, in order.
+    pub fn labels(&self) -> Vec<String> {
+        let first = self.breakpoints.first().map(|x| format!("< {}", x));
+        let last = self.breakpoints.last().map(|x| format!(">= {}", x));
+        let middle = self
+            .breakpoints
+            .iter()
+            .tuple_windows()
+            .map(|(lo, hi)| format!("{} - {}", lo, hi));
+
+        first.into_iter().chain(middle).chain(last).collect()
+    }
+
+    /// Parse an expression string, returning `Some` only if it is of the
+    /// generated form, so hand-written expressions are left alone.
+    pub fn parse(expression: &str) -> Option<Self> {
+        let bins = Self::parse_header(expression, " (bins)")?;
+        (bins.to_expression() == expression).then_some(bins)
+    }
+
+    /// Parse an expression string, returning `Some` only if it is of the
+    /// generated form of `to_order_expression()`.
+    pub fn parse_order(expression: &str) -> Option<Self> {
+        let bins = Self::parse_header(expression, " (bins order)")?;
+        (bins.to_order_expression() == expression).then_some(bins)
+    }
+
+    pub fn to_expression(&self) -> String {
+        let labels = self.labels().into_iter().map(|x| format!("'{}'", x));
+        self.to_branches(&self.alias, labels)
+    }
+
+    /// The companion expression which evaluates the index of each range, for
+    /// use in `sort`.
+    pub fn to_order_expression(&self) -> String {
+        let indices = (0..=self.breakpoints.len()).map(|x| x.to_string());
+        self.to_branches(&self.order_alias, indices)
+    }
+
+    fn parse_header(expression: &str, suffix: &str) -> Option<Self> {
+        let mut lines = expression.lines();
+        let alias = lines.next()?.strip_prefix("//")?.trim();
+        let source = alias.strip_suffix(suffix)?;
+        let breakpoints = lines
+            .next()?
+            .strip_prefix("// breakpoints:")?
+            .split(',')
+            .map(|x| x.trim().parse::<f64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self::new(source, breakpoints))
+    }
+
+    /// An `if` chain which evaluates to the `values` of each range in order.
+    fn to_branches(&self, alias: &str, values: impl Iterator<Item = String>) -> String {
+        let column = format!("\"{}\"", self.source);
+        let values = values.collect::<Vec<_>>();
+        let branches = self
+            .breakpoints
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .map(|(i, (x, value))| {
+                let keyword = if i == 0 { "if" } else { "else if" };
+                format!("{} ({} < {}) {};", keyword, column, x, value)
+            })
+            .chain(values.last().map(|value| format!("else {}", value)))
+            .join("\n");
+
+        format!(
+            "// {}\n// breakpoints: {}\n{}",
+            alias,
+            self.breakpoints.iter().join(", "),
+            branches
+        )
+    }
+}
+
+/// Parse a comma separated list of breakpoints, e.g. `0, 10, 100`.
+pub fn parse_breakpoints(input: &str) -> Result<Vec<f64>, String> {
+    let breakpoints = input
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            x.parse::<f64>()
+                .ok()
+                .filter(|x| x.is_finite())
+                .ok_or_else(|| format!("\"{}\" is not a number", x))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if breakpoints.is_empty() {
+        Err("Enter at least one breakpoint".to_owned())
+    } else if breakpoints.len() > MAX_NUMERIC_BINS {
+        Err(format!("At most {} breakpoints", MAX_NUMERIC_BINS))
+    } else {
+        Ok(breakpoints)
+    }
+}
+
+/// The number of decimal places needed to show multiples of `step` readably.
+fn decimal_precision(step: f64) -> i32 {
+    std::cmp::max(0, 1 - step.log10().floor() as i32)
+}
+
+fn round_to(x: f64, decimals: i32) -> f64 {
+    let scale = 10_f64.powi(decimals);
+    (x * scale).round() / scale
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_numeric_bins() {
+        let bins = NumericBins::with_width("Sales", (3.0, 27.0), 10.0).unwrap();
+        assert_eq!(bins.breakpoints, vec![0.0, 10.0, 20.0, 30.0]);
+        assert_eq!(bins.count(), 3);
+        assert_eq!(
+            NumericBins::parse(&bins.to_expression()),
+            Some(bins.clone())
+        );
+
+        assert_eq!(bins.labels(), vec![
+            "< 0", "0 - 10", "10 - 20", "20 - 30", ">= 30"
+        ]);
+        assert_eq!(
+            NumericBins::parse_order(&bins.to_order_expression()),
+            Some(bins.clone())
+        );
+        assert_eq!(NumericBins::parse(&bins.to_order_expression()), None);
+        assert_eq!(
+            NumericBins::new("Sales", [0.0, 100.0]).to_order_expression(),
+            "// Sales (bins order)\n// breakpoints: 0, 100\nif (\"Sales\" < 0) 0;\nelse if \
+             (\"Sales\" < 100) 1;\nelse 2"
+        );
+
+        let bins = NumericBins::with_count("Sales", (0.0, 1.0), 3).unwrap();
+        assert_eq!(bins.breakpoints, vec![0.0, 0.33, 0.67, 1.0]);
+        assert!(NumericBins::with_width("Sales", (0.0, 1e6), 1.0).is_err());
+        assert_eq!(parse_breakpoints("10, 0,1e2"), Ok(vec![10.0, 0.0, 100.0]));
+        assert!(parse_breakpoints("1, x").is_err());
+        assert_eq!(NumericBins::parse("// Sales (bins)\n\"Sales\""), None);
+    }
+}
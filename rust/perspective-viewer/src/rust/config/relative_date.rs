@@ -0,0 +1,352 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize};
+
+use super::filters::FilterOp;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RelativeDateUnit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl RelativeDateUnit {
+    fn duration(&self, n: u32) -> Duration {
+        let n = n as i64;
+        match self {
+            Self::Minutes => Duration::minutes(n),
+            Self::Hours => Duration::hours(n),
+            Self::Days => Duration::days(n),
+            Self::Weeks => Duration::weeks(n),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+            Self::Weeks => "weeks",
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Minutes => "m",
+            Self::Hours => "h",
+            Self::Days => "d",
+            Self::Weeks => "w",
+        }
+    }
+
+    fn parse(x: &str) -> Option<Self> {
+        match x {
+            "m" | "min" | "mins" | "minute" | "minutes" => Some(Self::Minutes),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Some(Self::Hours),
+            "d" | "day" | "days" => Some(Self::Days),
+            "w" | "wk" | "wks" | "week" | "weeks" => Some(Self::Weeks),
+            _ => None,
+        }
+    }
+}
+
+/// A date or time relative to the moment a filter is applied, e.g. `"today"`,
+/// which is stored symbolically in a `ViewConfig` and resolved to absolute
+/// values when a `View` is created.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RelativeDate {
+    /// The current instant, `"now"`.
+    Now,
+
+    /// An instant in the past, e.g. `"now - 15m"`.
+    Ago(u32, RelativeDateUnit),
+
+    /// The period from the start of a unit ago until now, e.g. `"last 7
+    /// days"`.
+    Last(u32, RelativeDateUnit),
+
+    Today,
+    Yesterday,
+    ThisWeek,
+    ThisMonth,
+    ThisQuarter,
+    YearToDate,
+}
+
+/// Preset `RelativeDate`s to offer in the UI.
+pub const RELATIVE_DATE_PRESETS: &[RelativeDate] = &[
+    RelativeDate::Today,
+    RelativeDate::Yesterday,
+    RelativeDate::ThisWeek,
+    RelativeDate::ThisMonth,
+    RelativeDate::ThisQuarter,
+    RelativeDate::YearToDate,
+    RelativeDate::Last(24, RelativeDateUnit::Hours),
+    RelativeDate::Last(7, RelativeDateUnit::Days),
+    RelativeDate::Last(30, RelativeDateUnit::Days),
+    RelativeDate::Ago(15, RelativeDateUnit::Minutes),
+    RelativeDate::Now,
+];
+
+impl Display for RelativeDate {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            Self::Now => write!(fmt, "now"),
+            Self::Ago(n, unit) => write!(fmt, "now - {}{}", n, unit.suffix()),
+            Self::Last(n, unit) => write!(fmt, "last {} {}", n, unit.name()),
+            Self::Today => write!(fmt, "today"),
+            Self::Yesterday => write!(fmt, "yesterday"),
+            Self::ThisWeek => write!(fmt, "this week"),
+            Self::ThisMonth => write!(fmt, "this month"),
+            Self::ThisQuarter => write!(fmt, "this quarter"),
+            Self::YearToDate => write!(fmt, "year to date"),
+        }
+    }
+}
+
+impl FromStr for RelativeDate {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim().to_lowercase();
+        let parse_amount = |x: &str| -> Option<(u32, RelativeDateUnit)> {
+            let x = x.trim();
+            let split = x.find(|c: char| !c.is_ascii_digit())?;
+            let n = x[..split].parse::<u32>().ok()?;
+            Some((n, RelativeDateUnit::parse(x[split..].trim())?))
+        };
+
+        match input.as_str() {
+            "now" => Ok(Self::Now),
+            "today" => Ok(Self::Today),
+            "yesterday" => Ok(Self::Yesterday),
+            "this week" => Ok(Self::ThisWeek),
+            "this month" => Ok(Self::ThisMonth),
+            "this quarter" => Ok(Self::ThisQuarter),
+            "year to date" | "ytd" => Ok(Self::YearToDate),
+            x => x
+                .strip_prefix("now -")
+                .and_then(parse_amount)
+                .map(|(n, unit)| Self::Ago(n, unit))
+                .or_else(|| {
+                    x.strip_prefix("last ")
+                        .and_then(parse_amount)
+                        .map(|(n, unit)| Self::Last(n, unit))
+                })
+                .ok_or_else(|| format!("Unknown relative date \"{}\"", x)),
+        }
+    }
+}
+
+impl RelativeDate {
+    /// Is this a single instant, rather than a period?
+    pub fn is_instant(&self) -> bool {
+        matches!(self, Self::Now | Self::Ago(..))
+    }
+
+    /// Can a filter with this `RelativeDate` term be resolved for `op`?
+    pub fn is_supported_op(&self, op: FilterOp) -> bool {
+        matches!(
+            op,
+            FilterOp::EQ | FilterOp::GT | FilterOp::GTE | FilterOp::LT | FilterOp::LTE
+        )
+    }
+
+    /// The time elapsed between the start of this `RelativeDate` and now, for
+    /// instants and periods measured in minutes or hours.  These must be
+    /// resolved against posix time rather than the wall clock, or a DST
+    /// transition would lengthen or shorten them.
+    pub fn elapsed(&self) -> Option<Duration> {
+        use RelativeDateUnit::*;
+        match self {
+            Self::Now => Some(Duration::zero()),
+            Self::Ago(n, unit @ (Minutes | Hours)) | Self::Last(n, unit @ (Minutes | Hours)) => {
+                Some(unit.duration(*n))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve this `RelativeDate` to a `[start, end)` range of local date
+    /// times, where `end` is `None` for periods which end now (and for
+    /// instants, which have `start == end`).
+    ///
+    /// # Arguments
+    /// - `now` The current local date time.
+    pub fn resolve(&self, now: NaiveDateTime) -> (NaiveDateTime, Option<NaiveDateTime>) {
+        let today = now.date();
+        let midnight = |x: NaiveDate| x.and_hms_opt(0, 0, 0).unwrap();
+        let first_of_month = |year: i32, month: u32| {
+            let (year, month) = (year + (month as i32 - 1) / 12, (month - 1) % 12 + 1);
+            midnight(NaiveDate::from_ymd_opt(year, month, 1).unwrap())
+        };
+
+        match self {
+            Self::Now => (now, Some(now)),
+            Self::Ago(n, unit) => {
+                let instant = now - unit.duration(*n);
+                (instant, Some(instant))
+            }
+            Self::Last(n, unit) => (now - unit.duration(*n), None),
+            Self::Today => (midnight(today), Some(midnight(today) + Duration::days(1))),
+            Self::Yesterday => (midnight(today) - Duration::days(1), Some(midnight(today))),
+            Self::ThisWeek => {
+                let offset = today.weekday().num_days_from_monday() as i64;
+                let start = midnight(today) - Duration::days(offset);
+                (start, Some(start + Duration::weeks(1)))
+            }
+            Self::ThisMonth => (
+                first_of_month(today.year(), today.month()),
+                Some(first_of_month(today.year(), today.month() + 1)),
+            ),
+            Self::ThisQuarter => {
+                let month = (today.month() - 1) / 3 * 3 + 1;
+                (
+                    first_of_month(today.year(), month),
+                    Some(first_of_month(today.year(), month + 3)),
+                )
+            }
+            Self::YearToDate => (first_of_month(today.year(), 1), None),
+        }
+    }
+}
+
+/// The term of a filter on a `RelativeDate`, serialized as a map
+/// `{"relative": "today"}` to distinguish it from a string `Scalar`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelativeDateTerm(pub RelativeDate);
+
+impl Serialize for RelativeDateTerm {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("relative", &self.0.to_string())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RelativeDateTerm {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Record {
+            relative: String,
+        }
+
+        let record = Record::deserialize(deserializer)?;
+        RelativeDate::from_str(&record.relative)
+            .map(RelativeDateTerm)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn datetime(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_resolve_this_week() {
+        // Sunday belongs to the week starting the previous Monday.
+        let (start, end) = RelativeDate::ThisWeek.resolve(datetime(2023, 1, 1, 23, 59));
+        assert_eq!(start, datetime(2022, 12, 26, 0, 0));
+        assert_eq!(end, Some(datetime(2023, 1, 2, 0, 0)));
+
+        let (start, end) = RelativeDate::ThisWeek.resolve(datetime(2023, 1, 2, 0, 0));
+        assert_eq!(start, datetime(2023, 1, 2, 0, 0));
+        assert_eq!(end, Some(datetime(2023, 1, 9, 0, 0)));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_resolve_this_quarter() {
+        let (start, end) = RelativeDate::ThisQuarter.resolve(datetime(2023, 3, 31, 23, 59));
+        assert_eq!(start, datetime(2023, 1, 1, 0, 0));
+        assert_eq!(end, Some(datetime(2023, 4, 1, 0, 0)));
+
+        let (start, end) = RelativeDate::ThisQuarter.resolve(datetime(2023, 12, 31, 12, 0));
+        assert_eq!(start, datetime(2023, 10, 1, 0, 0));
+        assert_eq!(end, Some(datetime(2024, 1, 1, 0, 0)));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_resolve_year_boundaries() {
+        let now = datetime(2024, 1, 1, 0, 30);
+        assert_eq!(
+            RelativeDate::YearToDate.resolve(now),
+            (datetime(2024, 1, 1, 0, 0), None)
+        );
+
+        assert_eq!(
+            RelativeDate::Yesterday.resolve(now),
+            (
+                datetime(2023, 12, 31, 0, 0),
+                Some(datetime(2024, 1, 1, 0, 0))
+            )
+        );
+
+        assert_eq!(
+            RelativeDate::ThisMonth.resolve(datetime(2023, 12, 15, 0, 0)),
+            (
+                datetime(2023, 12, 1, 0, 0),
+                Some(datetime(2024, 1, 1, 0, 0))
+            )
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_resolve_dst() {
+        // 2023-03-12 is 23 hours long in `America/New_York`, but calendar
+        // periods are whole wall clock days regardless.
+        let now = datetime(2023, 3, 12, 12, 0);
+        assert_eq!(
+            RelativeDate::Today.resolve(now),
+            (
+                datetime(2023, 3, 12, 0, 0),
+                Some(datetime(2023, 3, 13, 0, 0))
+            )
+        );
+
+        // ... while periods of elapsed time are resolved in posix time.
+        let last_day = RelativeDate::Last(24, RelativeDateUnit::Hours);
+        assert_eq!(last_day.elapsed(), Some(Duration::hours(24)));
+        let ago = RelativeDate::Ago(15, RelativeDateUnit::Minutes);
+        assert_eq!(ago.elapsed(), Some(Duration::minutes(15)));
+        assert_eq!(RelativeDate::Now.elapsed(), Some(Duration::zero()));
+        assert_eq!(
+            RelativeDate::Last(7, RelativeDateUnit::Days).elapsed(),
+            None
+        );
+        assert_eq!(RelativeDate::Today.elapsed(), None);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_is_supported_op() {
+        assert!(RelativeDate::Today.is_supported_op(FilterOp::EQ));
+        assert!(RelativeDate::Now.is_supported_op(FilterOp::LT));
+        assert!(!RelativeDate::Today.is_supported_op(FilterOp::Contains));
+        assert!(!RelativeDate::Now.is_supported_op(FilterOp::In));
+    }
+}
@@ -0,0 +1,159 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::aggregates::*;
+
+/// A transform applied to a column's aggregated values before they are
+/// rendered, a la Excel's "Show Values As".  Percentages of a summed column
+/// are compiled into the engine's `pct sum` aggregates where the `View` allows
+/// (see `engine_aggregate()`), the rest are computed by the viewer from the
+/// `View`'s output, so are independent of the `ViewConfig`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum ShowValuesAs {
+    #[serde(rename = "pct of row")]
+    PctOfRow,
+
+    #[serde(rename = "pct of column")]
+    PctOfColumn,
+
+    #[serde(rename = "pct of grand total")]
+    PctOfGrandTotal,
+
+    #[serde(rename = "pct of parent")]
+    PctOfParent,
+
+    #[serde(rename = "running total")]
+    RunningTotal,
+
+    #[serde(rename = "rank")]
+    Rank,
+
+    #[serde(rename = "difference")]
+    Difference,
+
+    #[serde(rename = "pct change")]
+    PctChange,
+}
+
+pub const SHOW_VALUES_AS: &[ShowValuesAs] = &[
+    ShowValuesAs::PctOfRow,
+    ShowValuesAs::PctOfColumn,
+    ShowValuesAs::PctOfGrandTotal,
+    ShowValuesAs::PctOfParent,
+    ShowValuesAs::RunningTotal,
+    ShowValuesAs::Rank,
+    ShowValuesAs::Difference,
+    ShowValuesAs::PctChange,
+];
+
+impl Display for ShowValuesAs {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(fmt, "{}", match self {
+            Self::PctOfRow => "% of row",
+            Self::PctOfColumn => "% of column",
+            Self::PctOfGrandTotal => "% of grand total",
+            Self::PctOfParent => "% of parent",
+            Self::RunningTotal => "running total",
+            Self::Rank => "rank",
+            Self::Difference => "difference",
+            Self::PctChange => "% change",
+        })
+    }
+}
+
+impl ShowValuesAs {
+    /// Whether the transformed values are in the same units as the source
+    /// column, e.g. a running total of "USD" is still "USD" but a percentage
+    /// is not.
+    pub fn keeps_units(&self) -> bool {
+        matches!(self, Self::RunningTotal | Self::Difference)
+    }
+
+    /// The type of the transformed column, given the `View` type of the
+    /// source column.
+    ///
+    /// # Arguments
+    /// - `view_type` The type name of the source column in the `View`'s schema,
+    ///   e.g. `"integer"`.
+    pub fn view_type<'a>(&self, view_type: &'a str) -> &'a str {
+        match self {
+            Self::RunningTotal | Self::Difference => view_type,
+            Self::Rank => "integer",
+            _ => "float",
+        }
+    }
+
+    /// The engine aggregate which computes this transform of a column, if
+    /// any, replacing the column's aggregate in a `View` with a `group_by`.
+    /// The engine's `pct sum` aggregates are percentages of each `split_by`
+    /// column's own tree, so "% of grand total" can only be compiled when
+    /// there is no `split_by`.
+    ///
+    /// # Arguments
+    /// - `aggregate` The column's aggregate.
+    /// - `has_split_by` Whether the `View` has a `split_by`.
+    pub fn engine_aggregate(&self, aggregate: &Aggregate, has_split_by: bool) -> Option<Aggregate> {
+        if *aggregate != Aggregate::SingleAggregate(SingleAggregate::Sum) {
+            return None;
+        }
+
+        match self {
+            Self::PctOfColumn => Some(SingleAggregate::PctSumGrandTotal),
+            Self::PctOfGrandTotal if !has_split_by => Some(SingleAggregate::PctSumGrandTotal),
+            Self::PctOfParent => Some(SingleAggregate::PctSumParent),
+            _ => None,
+        }
+        .map(Aggregate::SingleAggregate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_engine_aggregate() {
+        let sum = Aggregate::SingleAggregate(SingleAggregate::Sum);
+        let mean = Aggregate::SingleAggregate(SingleAggregate::Mean);
+        let grand_total = Aggregate::SingleAggregate(SingleAggregate::PctSumGrandTotal);
+        let parent = Aggregate::SingleAggregate(SingleAggregate::PctSumParent);
+        let pct_of_column = ShowValuesAs::PctOfColumn;
+        assert_eq!(
+            pct_of_column.engine_aggregate(&sum, true),
+            Some(grand_total.clone())
+        );
+        assert_eq!(
+            ShowValuesAs::PctOfParent.engine_aggregate(&sum, true),
+            Some(parent)
+        );
+        assert_eq!(
+            ShowValuesAs::PctOfGrandTotal.engine_aggregate(&sum, false),
+            Some(grand_total)
+        );
+
+        assert_eq!(
+            ShowValuesAs::PctOfGrandTotal.engine_aggregate(&sum, true),
+            None
+        );
+        assert_eq!(
+            ShowValuesAs::PctOfColumn.engine_aggregate(&mean, false),
+            None
+        );
+        assert_eq!(ShowValuesAs::Rank.engine_aggregate(&sum, false), None);
+    }
+}
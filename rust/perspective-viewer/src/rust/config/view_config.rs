@@ -49,6 +49,11 @@ pub struct ViewConfig {
 
     #[serde(default)]
     pub aggregates: HashMap<String, Aggregate>,
+
+    /// Filters on the aggregated values of the first `group_by` level, which
+    /// are resolved by the viewer into a `filter` the engine can apply.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub having: Vec<Filter>,
 }
 
 impl ViewConfig {
@@ -65,9 +70,69 @@ impl ViewConfig {
     pub fn as_jsvalue(&self) -> ApiResult<JsPerspectiveViewConfig> {
         let mut new_config = self.clone();
         new_config.columns.retain(|x| x.is_some());
+        new_config.having.clear();
+        new_config.lower_filters();
+        new_config.lower_aggregates();
         Ok(JsValue::from_serde_ext(&new_config).map(|x| x.unchecked_into())?)
     }
 
+    /// Lower the `filter`s the engine does not support (e.g. `"between"`, or
+    /// `"in"` of numbers) to native filters, adding any expression columns they
+    /// require.  These are not in `columns`, so are hidden from the `View`.
+    fn lower_filters(&mut self) {
+        if !self.filter.iter().any(|x| x.is_lowered()) {
+            return;
+        }
+
+        let filter = std::mem::take(&mut self.filter);
+        for (idx, x) in filter.iter().enumerate() {
+            let alias = format!("__perspective_filter_{}__", idx);
+            let source = self
+                .expressions
+                .iter()
+                .find_map(|expr| match expr.strip_prefix("//")?.split_once('\n') {
+                    Some((name, body)) if name.trim() == x.0 => Some(format!("({})", body)),
+                    _ => None,
+                })
+                .or_else(|| {
+                    self.expressions
+                        .contains(&x.0)
+                        .then(|| format!("({})", x.0))
+                })
+                .unwrap_or_else(|| format!("\"{}\"", x.0.replace('"', "\\\"")));
+
+            let (filters, expression) = x.lower(&alias, &source);
+            self.filter.extend(filters);
+            self.expressions.extend(expression);
+        }
+    }
+
+    /// Lower the `count where` aggregates, whose parameter is an expression,
+    /// to aggregates over hidden boolean expression columns, which the engine
+    /// counts the `true` rows of.
+    fn lower_aggregates(&mut self) {
+        let mut names = self
+            .aggregates
+            .iter()
+            .filter(|(_, agg)| {
+                matches!(
+                    agg,
+                    Aggregate::MultiAggregate(MultiAggregate::CountWhere, _)
+                )
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        names.sort();
+        for (idx, name) in names.into_iter().enumerate() {
+            let alias = format!("__perspective_count_where_{}__", idx);
+            if let Some(Aggregate::MultiAggregate(_, expr)) = self.aggregates.get_mut(&name) {
+                let expr = std::mem::replace(expr, alias.clone());
+                self.expressions.push(format!("// {}\n{}", alias, expr));
+            }
+        }
+    }
+
     pub fn reset(&mut self, reset_expressions: bool) {
         let mut config = Self::default();
         if !reset_expressions {
@@ -87,6 +152,7 @@ impl ViewConfig {
         changed = Self::_apply(&mut self.sort, update.sort) || changed;
         changed = Self::_apply(&mut self.aggregates, update.aggregates) || changed;
         changed = Self::_apply(&mut self.expressions, update.expressions) || changed;
+        changed = Self::_apply(&mut self.having, update.having) || changed;
         changed
     }
 
@@ -100,6 +166,7 @@ impl ViewConfig {
             || self.split_by.contains(&name)
             || self.sort.iter().any(|x| x.0 == name)
             || self.filter.iter().any(|x| x.0 == name)
+            || self.having.iter().any(|x| x.0 == name)
             || self.columns.contains(&Some(name))
     }
 }
@@ -127,11 +194,15 @@ pub struct ViewConfigUpdate {
 
     #[serde(default)]
     pub aggregates: Option<HashMap<String, Aggregate>>,
+
+    #[serde(default)]
+    pub having: Option<Vec<Filter>>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::*;
 
     #[wasm_bindgen_test]
     pub fn test_multiaggregate_weighted_mean() {
@@ -148,6 +219,21 @@ mod tests {
         );
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_multiaggregate_percentile() {
+        let x = json!({
+            "aggregates": {
+                "x": ["percentile", 95]
+            }
+        });
+
+        let rec: ViewConfig = x.into_serde_ext().unwrap();
+        assert_eq!(
+            *rec.aggregates.get("x").unwrap(),
+            Aggregate::MultiAggregate(MultiAggregate::Percentile, "95".to_owned())
+        );
+    }
+
     #[wasm_bindgen_test]
     pub fn test_group_by() {
         let x = json!({
@@ -204,6 +290,128 @@ mod tests {
         );
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_column_filters_top_n() {
+        let filter = Filter(
+            "Test".to_owned(),
+            FilterOp::TopN,
+            FilterTerm::TopN(TopNFilter {
+                n: 10,
+                by: "Sales".to_owned(),
+                aggregate: None,
+            }),
+        );
+
+        let config = ViewConfig {
+            filter: vec![filter.clone()],
+            ..ViewConfig::default()
+        };
+
+        let bytes = rmp_serde::to_vec(&config).unwrap();
+        let rec: ViewConfig = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(rec.filter, vec![filter]);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_column_filters_relative() {
+        let filter = Filter(
+            "Test".to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Relative(RelativeDateTerm(RelativeDate::Last(
+                7,
+                RelativeDateUnit::Days,
+            ))),
+        );
+
+        let config = ViewConfig {
+            filter: vec![filter.clone()],
+            ..ViewConfig::default()
+        };
+
+        let bytes = rmp_serde::to_vec(&config).unwrap();
+        let rec: ViewConfig = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(rec.filter, vec![filter]);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_lower_filters() {
+        let mut config = ViewConfig {
+            filter: vec![
+                Filter(
+                    "Sales".to_owned(),
+                    FilterOp::Between,
+                    FilterTerm::Array(vec![Scalar::Float(1.0), Scalar::Null]),
+                ),
+                Filter(
+                    "City".to_owned(),
+                    FilterOp::NotContains,
+                    FilterTerm::Scalar(Scalar::String("St. Paul's".to_owned())),
+                ),
+            ],
+            ..ViewConfig::default()
+        };
+
+        config.lower_filters();
+        assert_eq!(config.filter, vec![
+            Filter(
+                "Sales".to_owned(),
+                FilterOp::GTE,
+                FilterTerm::Scalar(Scalar::Float(1.0))
+            ),
+            Filter(
+                "__perspective_filter_1__".to_owned(),
+                FilterOp::EQ,
+                FilterTerm::Scalar(Scalar::Bool(false))
+            ),
+        ]);
+
+        let expression = "// __perspective_filter_1__\nmatch(\"City\", '(?i)St\\\\. Paul\\'s')";
+        assert_eq!(config.expressions, vec![expression.to_owned()]);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_lower_aggregates() {
+        let mut config = ViewConfig {
+            expressions: vec!["// Profitable\n\"Profit\" > 0".to_owned()],
+            aggregates: HashMap::from([
+                (
+                    "Sales".to_owned(),
+                    Aggregate::MultiAggregate(
+                        MultiAggregate::CountWhere,
+                        "\"Sales\" > 10".to_owned(),
+                    ),
+                ),
+                (
+                    "Profit".to_owned(),
+                    Aggregate::MultiAggregate(MultiAggregate::Percentile, "95".to_owned()),
+                ),
+            ]),
+            ..ViewConfig::default()
+        };
+
+        config.lower_aggregates();
+        assert_eq!(
+            config.aggregates.get("Sales"),
+            Some(&Aggregate::MultiAggregate(
+                MultiAggregate::CountWhere,
+                "__perspective_count_where_0__".to_owned()
+            ))
+        );
+
+        assert_eq!(
+            config.aggregates.get("Profit"),
+            Some(&Aggregate::MultiAggregate(
+                MultiAggregate::Percentile,
+                "95".to_owned()
+            ))
+        );
+
+        assert_eq!(config.expressions, vec![
+            "// Profitable\n\"Profit\" > 0".to_owned(),
+            "// __perspective_count_where_0__\n\"Sales\" > 10".to_owned(),
+        ]);
+    }
+
     #[wasm_bindgen_test]
     pub fn test_column_sorts() {
         let sort = ["Test", "asc"]
@@ -10,6 +10,7 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::collections::HashMap;
 use std::io::{Read, Write};
 use std::str::FromStr;
 
@@ -21,6 +22,9 @@ use serde_json::Value;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 
+use super::column_attributes::*;
+use super::column_config::*;
+use super::drill_down::*;
 use super::view_config::*;
 use crate::utils::*;
 
@@ -55,12 +59,27 @@ pub struct ViewerConfig {
     pub theme: Option<String>,
     pub title: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ColumnConfig::is_empty")]
+    pub column_config: ColumnConfig,
+
+    #[serde(skip_serializing_if = "ColumnAttributesConfig::is_empty")]
+    pub column_attributes: ColumnAttributesConfig,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub drill_down: Vec<DrillDownLevel>,
+
     #[serde(flatten)]
     pub view_config: ViewConfig,
 }
 
 // `#[serde(flatten)]` makes messagepack 2x as big as they can no longer be
 // struct fields, so make a tuple alternative for serialization in binary.
+// Column styles and attributes are encoded as JSON records, as the former's
+// typed representation is ambiguous without the column's type, and the latter
+// skips missing fields.
 type ViewerConfigBinarySerialFormat<'a> = (
     &'a String,
     &'a Value,
@@ -68,15 +87,26 @@ type ViewerConfigBinarySerialFormat<'a> = (
     &'a Option<String>,
     &'a Option<String>,
     &'a ViewConfig,
+    HashMap<String, Value>,
+    HashMap<String, Value>,
+    &'a Option<String>,
+    &'a Vec<DrillDownLevel>,
 );
 
-type ViewerConfigBinaryDeserialFormat = (
+// Fields added after the original 6 are defaulted, so tokens encoded before
+// they existed can still be decoded.
+#[derive(Deserialize)]
+struct ViewerConfigBinaryDeserialFormat(
     PluginUpdate,
     Option<Value>,
     SettingsUpdate,
     ThemeUpdate,
     TitleUpdate,
     ViewConfigUpdate,
+    #[serde(default)] ColumnConfigUpdate,
+    #[serde(default)] ColumnAttributesUpdate,
+    #[serde(default)] TimeZoneUpdate,
+    #[serde(default)] DrillDownUpdate,
 );
 
 impl ViewerConfig {
@@ -88,6 +118,21 @@ impl ViewerConfig {
             &self.theme,
             &self.title,
             &self.view_config,
+            self.column_config
+                .iter()
+                .map(|(name, style)| (name.to_owned(), style.to_value()))
+                .collect(),
+            self.column_attributes
+                .iter()
+                .map(|(name, attrs)| {
+                    (
+                        name.to_owned(),
+                        serde_json::to_value(attrs).unwrap_or_default(),
+                    )
+                })
+                .collect(),
+            &self.time_zone,
+            &self.drill_down,
         )
     }
 
@@ -131,6 +176,9 @@ pub struct ViewerConfigUpdate {
     #[serde(default)]
     pub title: TitleUpdate,
 
+    #[serde(default)]
+    pub time_zone: TimeZoneUpdate,
+
     #[serde(default)]
     pub theme: ThemeUpdate,
 
@@ -140,13 +188,33 @@ pub struct ViewerConfigUpdate {
     #[serde(default)]
     pub plugin_config: Option<Value>,
 
+    #[serde(default)]
+    pub column_config: ColumnConfigUpdate,
+
+    #[serde(default)]
+    pub column_attributes: ColumnAttributesUpdate,
+
+    #[serde(default)]
+    pub drill_down: DrillDownUpdate,
+
     #[serde(flatten)]
     pub view_config: ViewConfigUpdate,
 }
 
 impl ViewerConfigUpdate {
     fn from_token(
-        (plugin, plugin_config, settings, theme, title, view_config): ViewerConfigBinaryDeserialFormat,
+        ViewerConfigBinaryDeserialFormat(
+            plugin,
+            plugin_config,
+            settings,
+            theme,
+            title,
+            view_config,
+            column_config,
+            column_attributes,
+            time_zone,
+            drill_down,
+        ): ViewerConfigBinaryDeserialFormat,
     ) -> ViewerConfigUpdate {
         ViewerConfigUpdate {
             plugin,
@@ -154,6 +222,10 @@ impl ViewerConfigUpdate {
             settings,
             theme,
             title,
+            time_zone,
+            column_config,
+            column_attributes,
+            drill_down,
             view_config,
         }
     }
@@ -196,6 +268,8 @@ pub type PluginUpdate = OptionalUpdate<String>;
 pub type SettingsUpdate = OptionalUpdate<bool>;
 pub type ThemeUpdate = OptionalUpdate<String>;
 pub type TitleUpdate = OptionalUpdate<String>;
+pub type TimeZoneUpdate = OptionalUpdate<String>;
+pub type DrillDownUpdate = OptionalUpdate<Vec<DrillDownLevel>>;
 
 /// Handles `{}` when included as a field with `#[serde(default)]`.
 impl<T: Clone> Default for OptionalUpdate<T> {
@@ -228,3 +302,63 @@ where
         Option::deserialize(deserializer).map(Into::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_decode_token_without_column_config() {
+        let view_config = ViewConfig {
+            group_by: vec!["x".to_owned()],
+            ..ViewConfig::default()
+        };
+
+        let token = (
+            &"Datagrid".to_owned(),
+            &serde_json::json!({}),
+            true,
+            &Some("Pro Light".to_owned()),
+            &None::<String>,
+            &view_config,
+        );
+
+        let bytes = rmp_serde::to_vec(&token).unwrap();
+        let token: ViewerConfigBinaryDeserialFormat = rmp_serde::from_slice(&bytes).unwrap();
+        let update = ViewerConfigUpdate::from_token(token);
+        assert!(matches!(update.plugin, PluginUpdate::Update(x) if x == "Datagrid"));
+        assert!(matches!(update.settings, SettingsUpdate::Update(true)));
+        assert!(matches!(update.title, TitleUpdate::SetDefault));
+        assert!(matches!(update.column_config, ColumnConfigUpdate::Missing));
+        assert!(matches!(
+            update.column_attributes,
+            ColumnAttributesUpdate::Missing
+        ));
+        assert!(matches!(update.time_zone, TimeZoneUpdate::Missing));
+        assert!(matches!(update.drill_down, DrillDownUpdate::Missing));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decode_token_round_trip() {
+        let config = ViewerConfig {
+            plugin: "Datagrid".to_owned(),
+            plugin_config: serde_json::json!({}),
+            settings: false,
+            theme: None,
+            title: None,
+            time_zone: Some("Asia/Tokyo".to_owned()),
+            column_config: ColumnConfig::default(),
+            column_attributes: ColumnAttributesConfig::default(),
+            drill_down: vec![],
+            view_config: ViewConfig::default(),
+        };
+
+        let bytes = rmp_serde::to_vec(&config.token()).unwrap();
+        let token: ViewerConfigBinaryDeserialFormat = rmp_serde::from_slice(&bytes).unwrap();
+        let update = ViewerConfigUpdate::from_token(token);
+        assert!(matches!(update.time_zone, TimeZoneUpdate::Update(x) if x == "Asia/Tokyo"));
+        assert!(matches!(update.column_config, ColumnConfigUpdate::Update(x) if x.is_empty()));
+    }
+}
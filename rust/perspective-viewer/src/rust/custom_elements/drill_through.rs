@@ -0,0 +1,79 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::*;
+use yew::*;
+
+use crate::components::drill_through::{DrillThroughModal, DrillThroughModalProps};
+use crate::custom_elements::modal::*;
+use crate::session::DrillThrough;
+use crate::utils::*;
+use crate::*;
+
+/// A modal for a `DrillThrough`, which is destroyed when it is closed (or
+/// loses focus), deleting the `DrillThrough`'s `View`.
+#[derive(Clone)]
+pub struct DrillThroughElement {
+    modal: Rc<RefCell<Option<ModalElement<DrillThroughModal>>>>,
+}
+
+impl DrillThroughElement {
+    pub fn new(drill_through: DrillThrough) -> Self {
+        let document = window().unwrap().document().unwrap();
+        let elem = document
+            .create_element("perspective-drill-through")
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+
+        let modal_rc: Rc<RefCell<Option<ModalElement<DrillThroughModal>>>> = Default::default();
+        let on_close = Callback::from({
+            clone!(modal_rc);
+            move |_| {
+                let modal = modal_rc.borrow().clone();
+                if let Some(modal) = modal {
+                    crate::js_log_maybe!({
+                        modal.hide()?;
+                    });
+                }
+            }
+        });
+
+        // `hide()` is called from within the modal's own event handlers, so
+        // it must be destroyed asynchronously.
+        let on_blur = Callback::from({
+            clone!(modal_rc);
+            move |_| {
+                let modal = modal_rc.borrow_mut().take();
+                ApiFuture::spawn(async move { modal.into_apierror()?.destroy() });
+            }
+        });
+
+        let props = props!(DrillThroughModalProps {
+            drill_through,
+            on_close
+        });
+
+        let modal = ModalElement::new(elem, props, true, Some(on_blur));
+        *modal_rc.borrow_mut() = Some(modal);
+        Self { modal: modal_rc }
+    }
+
+    /// Open this modal over `target`, typically the `<perspective-viewer>`.
+    pub async fn open(self, target: HtmlElement) -> ApiResult<()> {
+        let modal = self.modal.borrow().clone().into_apierror()?;
+        modal.open(target, None).await
+    }
+}
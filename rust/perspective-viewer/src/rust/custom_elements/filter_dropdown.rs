@@ -21,7 +21,7 @@ use yew::*;
 
 use crate::components::filter_dropdown::*;
 use crate::custom_elements::modal::*;
-use crate::session::Session;
+use crate::session::{ColumnValuesQuery, Session};
 use crate::utils::ApiFuture;
 use crate::*;
 
@@ -94,7 +94,14 @@ impl FilterDropDownElement {
                 ApiFuture::spawn({
                     clone!(self.modal, self.session, self.values);
                     async move {
-                        let all_values = session.get_column_values(column.1).await?;
+                        let all_values = session
+                            .get_column_values(&column.1, &ColumnValuesQuery::default())
+                            .await?
+                            .values
+                            .into_iter()
+                            .map(|x| x.label)
+                            .collect();
+
                         *values.borrow_mut() = Some(all_values);
                         let filter_values = filter_values(&input, &values);
                         if filter_values.len() == 1 && filter_values[0] == input {
@@ -13,6 +13,7 @@
 mod column_dropdown;
 pub mod copy_dropdown;
 pub mod debug_plugin;
+pub mod drill_through;
 pub mod export_dropdown;
 mod filter_dropdown;
 mod function_dropdown;
@@ -11,6 +11,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 use std::str::FromStr;
 
@@ -23,13 +24,15 @@ use yew::prelude::*;
 
 use crate::components::viewer::{PerspectiveViewer, PerspectiveViewerMsg, PerspectiveViewerProps};
 use crate::config::*;
+use crate::custom_elements::drill_through::DrillThroughElement;
 use crate::custom_events::*;
 use crate::dragdrop::*;
 use crate::js::*;
+use crate::link_group::LinkGroupMember;
 use crate::model::*;
 use crate::presentation::*;
 use crate::renderer::*;
-use crate::session::Session;
+use crate::session::{Session, RELATIVE_FILTER_INTERVAL_MS};
 use crate::utils::*;
 use crate::*;
 
@@ -105,6 +108,92 @@ impl ResizeObserverState {
     }
 }
 
+/// Handles the `"perspective-click"` events dispatched by the plugin, per the
+/// `ClickAction` chosen from the status bar.  A "select" click is published
+/// to the viewer's link group, if it has joined one.
+struct ClickActionHandler {
+    elem: HtmlElement,
+    link_group: Rc<RefCell<Option<LinkGroupMember>>>,
+    session: Session,
+    renderer: Renderer,
+    presentation: Presentation,
+}
+
+derive_model!(Renderer, Session for ClickActionHandler);
+
+impl ClickActionHandler {
+    fn on_click(&self, event: &Event) {
+        let Some(event) = event.dyn_ref::<CustomEvent>() else {
+            return;
+        };
+
+        let detail = event.detail();
+        let get_path = |obj: &JsValue, field: &str| {
+            let path = Reflect::get(obj, &field.into())
+                .ok()?
+                .dyn_into::<Array>()
+                .ok()?;
+            Some(
+                path.iter()
+                    .map(|x| Scalar::from_jsvalue(&x))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let row = Reflect::get(&detail, js_intern::js_intern!("row")).unwrap_or_default();
+        let row_path = get_path(&row, "__ROW_PATH__").unwrap_or_default();
+        let column_path = get_path(&detail, "column_names").unwrap_or_default();
+        match self.presentation.get_click_action() {
+            ClickAction::None => {
+                if let Some(member) = self.link_group.borrow().as_ref() {
+                    let value = match column_path.last() {
+                        Some(Scalar::String(column)) => Reflect::get(&row, &column.into())
+                            .map(|x| Scalar::from_jsvalue(&x))
+                            .unwrap_or(Scalar::Null),
+                        _ => Scalar::Null,
+                    };
+
+                    let filters =
+                        self.session
+                            .get_selection_filters(&row_path, &column_path, value);
+
+                    member.publish(filters);
+                }
+            }
+            ClickAction::DrillDown => {
+                let task = self.drill_down(&row_path);
+                ApiFuture::spawn(async move { task.await.map(|_| ()) });
+            }
+            ClickAction::DrillThrough => {
+                ApiFuture::spawn(drill_through(
+                    &self.session,
+                    &self.elem,
+                    row_path,
+                    column_path,
+                ));
+            }
+        }
+    }
+}
+
+/// Open a modal over `elem` of the underlying rows of the aggregated cell at
+/// `row_path` and `column_path`.
+fn drill_through(
+    session: &Session,
+    elem: &HtmlElement,
+    row_path: Vec<Scalar>,
+    column_path: Vec<Scalar>,
+) -> ApiFuture<()> {
+    clone!(session, elem);
+    ApiFuture::new(async move {
+        let drill_through = session
+            .create_drill_through(&row_path, &column_path)
+            .await?;
+
+        DrillThroughElement::new(drill_through).open(elem).await
+    })
+}
+
 /// A `customElements` class which encapsulates both the `<perspective-viewer>`
 /// public API, as well as the Rust component state.
 ///
@@ -136,6 +225,9 @@ pub struct PerspectiveViewerElement {
     presentation: Presentation,
     _events: CustomEvents,
     _subscriptions: Rc<Subscription>,
+    _relative_filter_interval: Interval,
+    link_group: Rc<RefCell<Option<LinkGroupMember>>>,
+    _click_listener: utils::EventListener,
 }
 
 derive_model!(Renderer, Session, Presentation for PerspectiveViewerElement);
@@ -178,10 +270,55 @@ impl PerspectiveViewerElement {
             clone!(renderer, session);
             move |_| {
                 clone!(renderer, session);
-                ApiFuture::spawn(async move { renderer.update(&session).await })
+                ApiFuture::spawn(async move {
+                    if session
+                        .refresh_resolved_filters(renderer.get_render_interval())
+                        .await?
+                    {
+                        renderer.draw(session.validate().await?.create_view()).await
+                    } else {
+                        renderer.update(&session).await
+                    }
+                })
             }
         });
 
+        // Relative date filters (e.g. `"last 15 minutes"`) must be re-resolved
+        // as time passes, even when the `Table` is not updated.
+        let relative_filter_interval = Interval::new(RELATIVE_FILTER_INTERVAL_MS, {
+            clone!(renderer, session);
+            move || {
+                clone!(renderer, session);
+                ApiFuture::spawn(async move {
+                    if session
+                        .refresh_relative_filters(renderer.get_render_interval())
+                        .await?
+                    {
+                        renderer
+                            .draw(session.validate().await?.create_view())
+                            .await?;
+                    }
+
+                    Ok(())
+                })
+            }
+        })
+        .unwrap();
+
+        let link_group: Rc<RefCell<Option<LinkGroupMember>>> = Default::default();
+        let click_handler = ClickActionHandler {
+            elem: elem.clone(),
+            link_group: link_group.clone(),
+            session: session.clone(),
+            renderer: renderer.clone(),
+            presentation: presentation.clone(),
+        };
+
+        let click_listener = utils::EventListener::new(&elem, "perspective-click", move |event| {
+            click_handler.on_click(&event)
+        })
+        .unwrap();
+
         let _events = CustomEvents::new(&elem, &session, &renderer, &presentation);
         let resize_handle = ResizeObserverHandle::new(&elem, &renderer, &root);
         Self {
@@ -193,6 +330,9 @@ impl PerspectiveViewerElement {
             resize_handle: Rc::new(RefCell::new(Some(resize_handle))),
             _events,
             _subscriptions: Rc::new(update_sub),
+            _relative_filter_interval: relative_filter_interval,
+            link_group,
+            _click_listener: click_listener,
         }
     }
 
@@ -249,6 +389,7 @@ impl PerspectiveViewerElement {
     /// callee).  Allowing a `<perspective-viewer>` to be garbage-collected
     /// without calling `delete()` will leak WASM memory.
     pub fn delete(&mut self) -> ApiFuture<bool> {
+        *self.link_group.borrow_mut() = None;
         clone!(self.renderer, self.session, self.root);
         ApiFuture::new(self.renderer.clone().with_lock(async move {
             renderer.delete()?;
@@ -314,13 +455,55 @@ impl PerspectiveViewerElement {
         ApiFuture::new(async move {
             let ViewerConfigUpdate {
                 plugin,
-                plugin_config,
+                mut plugin_config,
+                column_config,
+                column_attributes,
                 settings,
                 theme: theme_name,
                 title,
+                time_zone,
+                drill_down,
                 mut view_config,
             } = ViewerConfigUpdate::decode(&update)?;
 
+            // A `group_by` or `filter` restored without a drill-down stack
+            // replaces the view the stack would return to.
+            match drill_down {
+                OptionalUpdate::Update(x) => session.set_drill_down(x),
+                OptionalUpdate::SetDefault => session.set_drill_down(vec![]),
+                OptionalUpdate::Missing
+                    if view_config.group_by.is_some() || view_config.filter.is_some() =>
+                {
+                    session.set_drill_down(vec![])
+                }
+                OptionalUpdate::Missing => {}
+            }
+
+            let time_zone = match time_zone {
+                OptionalUpdate::Update(x) => Some(Some(x)),
+                OptionalUpdate::SetDefault => Some(None),
+                OptionalUpdate::Missing => None,
+            };
+
+            if let Some(time_zone) = &time_zone {
+                session.set_time_zone(time_zone.clone())?;
+            }
+
+            match &column_attributes {
+                OptionalUpdate::Update(x) => {
+                    let show_values_as = x
+                        .iter()
+                        .filter_map(|(name, attrs)| Some((name.clone(), attrs.show_values_as?)))
+                        .collect();
+
+                    session.set_show_values_as(show_values_as);
+                }
+                OptionalUpdate::SetDefault => {
+                    session.set_show_values_as(HashMap::new());
+                }
+                OptionalUpdate::Missing => {}
+            }
+
             if !session.has_table() {
                 if let OptionalUpdate::Update(x) = settings {
                     presentation.set_settings_attribute(x);
@@ -372,12 +555,30 @@ impl PerspectiveViewerElement {
 
                 let internal_task = async {
                     let plugin = renderer.get_active_plugin()?;
+                    let legacy_styles = plugin_config
+                        .as_mut()
+                        .map(|x| take_column_styles(x, &plugin.column_keys()))
+                        .unwrap_or_default();
+
                     if let Some(plugin_config) = &plugin_config {
                         let js_config = JsValue::from_serde_ext(plugin_config)?;
                         plugin.restore(&js_config);
+
+                        // `plugin_config` no longer carries column styles, so
+                        // the plugin's must be re-applied even when this
+                        // update does not change them.
+                        renderer.push_column_config()?;
                     }
 
-                    session.validate().await?.create_view().await
+                    let session = session.validate().await?.create_view().await?;
+                    let column_config = migrate_column_config(column_config, legacy_styles);
+                    renderer.update_column_config(column_config, session)?;
+                    renderer.update_column_attributes(column_attributes)?;
+                    if let Some(time_zone) = time_zone {
+                        renderer.set_time_zone(time_zone)?;
+                    }
+
+                    Ok(session)
                 }
                 .await;
 
@@ -422,14 +623,14 @@ impl PerspectiveViewerElement {
     /// - `flat` Whether to use the current `ViewConfig` to generate this data,
     ///   or use the default.
     pub fn download(&self, flat: Option<bool>) -> ApiFuture<()> {
-        let session = self.session.clone();
-        ApiFuture::new(async move {
-            let val = session
-                .csv_as_jsvalue(flat.unwrap_or_default())
-                .await?
-                .as_blob()?;
-            download("untitled.csv", &val)
-        })
+        let method = if flat.unwrap_or_default() {
+            ExportMethod::CsvAll
+        } else {
+            ExportMethod::Csv
+        };
+
+        let js_task = self.export_method_to_jsvalue(method);
+        ApiFuture::new(async move { download("untitled.csv", &js_task.await?) })
     }
 
     /// Copy this viewer's `View` or `Table` data as CSV to the system
@@ -450,6 +651,138 @@ impl PerspectiveViewerElement {
         ApiFuture::new(copy_task)
     }
 
+    /// Drill into the grouped row `row_path`, filtering to its group and
+    /// grouping by the next `group_by` column.  Resolves to `false` if there
+    /// is no level to drill into.
+    ///
+    /// # Arguments
+    /// - `row_path` The row's `__ROW_PATH__`.
+    #[wasm_bindgen(js_name = "drillDown")]
+    pub fn js_drill_down(&self, row_path: Array) -> ApiFuture<bool> {
+        let row_path = row_path
+            .iter()
+            .map(|x| Scalar::from_jsvalue(&x))
+            .collect::<Vec<_>>();
+        self.drill_down(&row_path)
+    }
+
+    /// Return to a previous drill-down level.
+    ///
+    /// # Arguments
+    /// - `depth` The level to return to, or `None` for the view before any
+    ///   drill-down.
+    #[wasm_bindgen(js_name = "drillUp")]
+    pub fn js_drill_up(&self, depth: Option<usize>) -> ApiFuture<()> {
+        self.drill_up(depth.unwrap_or_default())
+    }
+
+    /// Open a modal of the underlying rows of an aggregated cell, i.e. the
+    /// rows of the `Table` in the cell's row and column groups, which can be
+    /// exported or copied.
+    ///
+    /// # Arguments
+    /// - `row_path` The cell's `__ROW_PATH__`.
+    /// - `column_path` The cell's `split_by` values, if any.
+    #[wasm_bindgen(js_name = "drillThrough")]
+    pub fn js_drill_through(&self, row_path: Array, column_path: Option<Array>) -> ApiFuture<()> {
+        let to_path = |x: Array| {
+            x.iter()
+                .map(|x| Scalar::from_jsvalue(&x))
+                .collect::<Vec<_>>()
+        };
+        let column_path = column_path.map(to_path).unwrap_or_default();
+        drill_through(&self.session, &self.elem, to_path(row_path), column_path)
+    }
+
+    /// Join a link group, leaving any link group this viewer is already a
+    /// member of.  Cells clicked in this viewer (when its click action is
+    /// "Select") are published to the other members as filters, and their
+    /// selections are applied on top of this viewer's `filter` (though never
+    /// saved to it).
+    ///
+    /// # Arguments
+    /// - `name` The name of the link group.
+    /// - `column_map` A map of the other members' column names to this
+    ///   viewer's, for columns which are not named the same.
+    #[wasm_bindgen(js_name = "joinLinkGroup")]
+    pub fn join_link_group(&self, name: String, column_map: JsValue) -> ApiFuture<()> {
+        let column_map: ApiResult<HashMap<String, String>> = if column_map.is_undefined() {
+            Ok(HashMap::default())
+        } else {
+            column_map.into_serde_ext().map_err(ApiError::from)
+        };
+
+        let leave_task = self.leave_link_group();
+        let model = self.cloned();
+        let link_group = self.link_group.clone();
+        ApiFuture::new(async move {
+            let column_map = column_map?;
+            leave_task.await?;
+            let member = LinkGroupMember::join(&name, column_map, move |selection| {
+                if model
+                    .session()
+                    .set_linked_filters(selection.source, selection.filters)
+                {
+                    ApiFuture::spawn(model.validate_and_render());
+                }
+            });
+
+            *link_group.borrow_mut() = Some(member);
+            Ok(())
+        })
+    }
+
+    /// Leave this viewer's link group, if any, removing the filters applied
+    /// by the other members.
+    #[wasm_bindgen(js_name = "leaveLinkGroup")]
+    pub fn leave_link_group(&self) -> ApiFuture<()> {
+        *self.link_group.borrow_mut() = None;
+        if self.session.clear_linked_filters() {
+            self.validate_and_render()
+        } else {
+            ApiFuture::new(async { Ok(()) })
+        }
+    }
+
+    /// The name of this viewer's link group, if it has joined one.
+    #[wasm_bindgen(js_name = "getLinkGroup")]
+    pub fn get_link_group(&self) -> Option<String> {
+        self.link_group
+            .borrow()
+            .as_ref()
+            .map(|x| x.name().to_owned())
+    }
+
+    /// Pause rendering `Table` updates, e.g. to read a fast-moving grid.  The
+    /// status bar shows the number of updates which have arrived since.
+    pub fn pause(&self) {
+        self.session.pause_updates();
+    }
+
+    /// Resume rendering `Table` updates, rendering the latest state once if
+    /// any updates arrived while paused.
+    pub fn resume(&self) {
+        self.session.resume_updates();
+    }
+
+    /// Whether `Table` updates are paused.
+    #[wasm_bindgen(js_name = "isPaused")]
+    pub fn is_paused(&self) -> bool {
+        self.session.is_paused()
+    }
+
+    /// Highlight the viewer as stale when no `Table` update has arrived for
+    /// `seconds`, or never if `seconds` is `undefined`.
+    #[wasm_bindgen(js_name = "setStaleThreshold")]
+    pub fn set_stale_threshold(&self, seconds: Option<f64>) {
+        self.presentation.set_stale_threshold(seconds);
+    }
+
+    #[wasm_bindgen(js_name = "getStaleThreshold")]
+    pub fn get_stale_threshold(&self) -> Option<f64> {
+        self.presentation.get_stale_threshold()
+    }
+
     /// Reset the viewer's `ViewerConfig` to the default.
     ///
     /// # Arguments
@@ -567,6 +900,29 @@ impl PerspectiveViewerElement {
         self.renderer.set_throttle(val);
     }
 
+    /// Get the render timing statistics of this viewer: histograms of the
+    /// durations of `View` creation, plugin draws, updates and resizes (in
+    /// total and per plugin), and counts of debounced and dropped updates.
+    #[wasm_bindgen(js_name = "getRenderStats")]
+    pub fn get_render_stats(&self) -> ApiResult<JsValue> {
+        Ok(JsValue::from_serde_ext(&self.renderer.get_render_stats())?)
+    }
+
+    /// Clear the render timing statistics returned by `getRenderStats()`.
+    #[wasm_bindgen(js_name = "resetRenderStats")]
+    pub fn reset_render_stats(&self) {
+        self.renderer.reset_render_stats();
+    }
+
+    /// Toggle (or force) the render timing statistics debug overlay.
+    ///
+    /// # Arguments
+    /// - `force` Force the overlay shown or hidden, or `None` to toggle.
+    #[wasm_bindgen(js_name = "toggleRenderStatsOverlay")]
+    pub fn toggle_render_stats_overlay(&self, force: Option<bool>) -> bool {
+        self.renderer.toggle_render_stats_overlay(force)
+    }
+
     /// Toggle (or force) the config panel open/closed.
     ///
     /// # Arguments
@@ -635,4 +991,94 @@ impl PerspectiveViewerElement {
             task.await.map_err(|_| ApiError::from("Cancelled"))
         })
     }
+
+    /// Get the style of a column, as a JSON record, or `null` if the column is
+    /// unstyled.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    #[wasm_bindgen(js_name = "getColumnStyle")]
+    pub fn get_column_style(&self, name: String) -> ApiResult<JsValue> {
+        match self.renderer.get_column_style(&name) {
+            Some(style) => Ok(JsValue::from_serde_ext(&style)?),
+            None => Ok(JsValue::NULL),
+        }
+    }
+
+    /// Set the style of a column, replacing any existing style, then restyle.
+    /// The style must be valid for the column's type, else this method
+    /// rejects and the column's style is unchanged.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    /// - `style` The JSON style record.
+    #[wasm_bindgen(js_name = "setColumnStyle")]
+    pub fn set_column_style(&self, name: String, style: JsValue) -> ApiFuture<()> {
+        let task = style
+            .into_serde_ext()
+            .map_err(ApiError::from)
+            .and_then(|style| self.parse_column_style(&name, style))
+            .map(|style| self.set_column_styles([(name, Some(style))]));
+
+        ApiFuture::new(async move { task?.await })
+    }
+
+    /// Clear the style of a column, then restyle.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    #[wasm_bindgen(js_name = "resetColumnStyle")]
+    pub fn reset_column_style(&self, name: String) -> ApiFuture<()> {
+        self.set_column_styles([(name, None)])
+    }
+
+    /// Set the style of every column in the current `View` of type `type`,
+    /// then restyle.
+    ///
+    /// # Arguments
+    /// - `ty` The column type name, e.g. `"float"`.
+    /// - `style` The JSON style record.
+    #[wasm_bindgen(js_name = "applyStyleToType")]
+    pub fn apply_style_to_type(&self, ty: String, style: JsValue) -> ApiFuture<()> {
+        let parsed = serde_json::from_value::<Type>(serde_json::Value::String(ty.clone()))
+            .map_err(|_| ApiError::from(format!("Unknown column type \"{}\"", ty)))
+            .and_then(|ty| {
+                let style = ColumnStyleConfig::from_value_strict(ty, style.into_serde_ext()?)?;
+                Ok((ty, style))
+            });
+
+        let task = parsed.map(|(ty, style)| ColumnStyleModel::apply_style_to_type(self, ty, style));
+        ApiFuture::new(async move { task?.await })
+    }
+
+    /// Get the named column style presets, as a JSON array of
+    /// `{name, type, style}` records.
+    #[wasm_bindgen(js_name = "getColumnStylePresets")]
+    pub fn get_column_style_presets(&self) -> ApiResult<JsValue> {
+        Ok(JsValue::from_serde_ext(
+            &self.presentation.get_style_presets(),
+        )?)
+    }
+
+    /// Add named column style presets, as returned by
+    /// `getColumnStylePresets()`, replacing any existing presets of the same
+    /// name.  Every preset is validated before any are added.
+    ///
+    /// # Arguments
+    /// - `presets` A JSON array of `{name, type, style}` records.
+    #[wasm_bindgen(js_name = "saveColumnStylePresets")]
+    pub fn save_column_style_presets(&self, presets: JsValue) -> ApiResult<()> {
+        let presets: Vec<ColumnStylePreset> = presets.into_serde_ext()?;
+        self.presentation.save_style_presets(presets);
+        Ok(())
+    }
+
+    /// Delete a named column style preset.
+    ///
+    /// # Arguments
+    /// - `name` The name of the preset to delete.
+    #[wasm_bindgen(js_name = "deleteColumnStylePreset")]
+    pub fn delete_column_style_preset(&self, name: String) -> bool {
+        self.presentation.delete_style_preset(&name)
+    }
 }
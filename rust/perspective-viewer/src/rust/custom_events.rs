@@ -31,7 +31,7 @@ use crate::*;
 /// on `CustomElements`, but when it is `drop()` the Custom Element will no
 /// longer dispatch events such as `"perspective-config-change"`.
 #[derive(Clone)]
-pub struct CustomEvents(Rc<(CustomEventsDataRc, [Subscription; 6])>);
+pub struct CustomEvents(Rc<(CustomEventsDataRc, [Subscription; 8])>);
 
 #[derive(Clone)]
 struct CustomEventsDataRc(Rc<CustomEventsData>);
@@ -106,6 +106,11 @@ impl CustomEvents {
             }
         });
 
+        let column_attributes_sub = renderer.column_attributes_changed.add_listener({
+            clone!(data);
+            move |_| data.clone().dispatch_config_update()
+        });
+
         let view_sub = session.view_created.add_listener({
             clone!(data);
             move |_| {
@@ -113,13 +118,20 @@ impl CustomEvents {
             }
         });
 
+        let linked_filters_sub = session.linked_filters_changed.add_listener({
+            clone!(data);
+            move |_| data.dispatch_linked_filters_changed()
+        });
+
         Self(Rc::new((data, [
             theme_sub,
             settings_sub,
             column_settings_sub,
             column_settings_updated,
             plugin_sub,
+            column_attributes_sub,
             view_sub,
+            linked_filters_sub,
         ])))
     }
 }
@@ -162,6 +174,18 @@ impl CustomEventsDataRc {
         self.elem.dispatch_event(&event.unwrap()).unwrap();
     }
 
+    fn dispatch_linked_filters_changed(&self) {
+        let filters = self.session.get_linked_filters();
+        let mut event_init = web_sys::CustomEventInit::new();
+        event_init.detail(&JsValue::from_serde_ext(&filters).unwrap());
+        let event = web_sys::CustomEvent::new_with_event_init_dict(
+            "perspective-link-filters-change",
+            &event_init,
+        );
+
+        self.elem.dispatch_event(&event.unwrap()).unwrap();
+    }
+
     fn dispatch_plugin_changed(&self, plugin: &JsPerspectiveViewerPlugin) {
         let mut event_init = web_sys::CustomEventInit::new();
         event_init.detail(plugin);
@@ -30,6 +30,11 @@ pub enum DragTarget {
     SplitBy,
     Sort,
     Filter,
+    Having,
+
+    /// The values of a `CustomOrder`, which are reordered with their own
+    /// `DragDrop` rather than the viewer's.
+    CustomOrder,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
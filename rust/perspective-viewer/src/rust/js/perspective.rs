@@ -65,6 +65,9 @@ extern "C" {
     #[wasm_bindgen(method, catch, js_name = delete)]
     pub async fn _delete(this: &JsPerspectiveTable) -> ApiResult<JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = get_features)]
+    pub async fn _get_features(this: &JsPerspectiveTable) -> ApiResult<JsValue>;
+
     #[wasm_bindgen(method, catch, js_name = make_port)]
     pub async fn _make_port(this: &JsPerspectiveTable) -> ApiResult<JsValue>;
 
@@ -74,6 +77,9 @@ extern "C" {
     #[wasm_bindgen(method, catch, js_name = size)]
     pub async fn _size(this: &JsPerspectiveTable) -> ApiResult<JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = update)]
+    pub fn update(this: &JsPerspectiveTable, data: &js_sys::Object) -> ApiResult<JsValue>;
+
     #[wasm_bindgen(method, catch, js_name = validate_expressions)]
     pub async fn _validate_expressions(this: &JsPerspectiveTable, exprs: Array) -> ApiResult<JsValue>;
 
@@ -106,9 +112,21 @@ extern "C" {
         this: &JsPerspectiveView,
     ) -> ApiResult<JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = to_columns)]
+    pub async fn _to_columns_with_options(
+        this: &JsPerspectiveView,
+        options: js_sys::Object,
+    ) -> ApiResult<JsValue>;
+
     #[wasm_bindgen(method, catch, js_name = dimensions)]
     pub async fn _dimensions(this: &JsPerspectiveView) -> ApiResult<JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = get_min_max)]
+    pub async fn _get_min_max(
+        this: &JsPerspectiveView,
+        column: js_sys::JsString,
+    ) -> ApiResult<JsValue>;
+
     #[wasm_bindgen(method)]
     pub fn on_update(this: &JsPerspectiveView, callback: &js_sys::Function);
 
@@ -162,6 +180,8 @@ impl JsPerspectiveTable {
 
     async_typed!(_delete, delete(self) -> ());
 
+    async_typed!(_get_features, get_features(&self) -> js_sys::Object);
+
     async_typed!(_make_port, make_port(&self) -> f64);
 
     async_typed!(_validate_expressions, validate_expressions(&self, exprs: Array) -> JsPerspectiveValidatedExpressions);
@@ -180,8 +200,12 @@ impl JsPerspectiveView {
 
     async_typed!(_to_columns, to_columns(&self) -> js_sys::Object);
 
+    async_typed!(_to_columns_with_options, to_columns_with_options(&self, options: js_sys::Object) -> js_sys::Object);
+
     async_typed!(_dimensions, dimensions(&self) ->  JsPerspectiveViewDimensions);
 
+    async_typed!(_get_min_max, get_min_max(&self, column: js_sys::JsString) -> js_sys::Array);
+
     async_typed!(_schema, schema(&self) -> JsPerspectiveViewSchema);
 
     async_typed!(_delete, delete(self) -> ());
@@ -14,6 +14,7 @@ use serde::*;
 use wasm_bindgen::prelude::*;
 
 use super::perspective::JsPerspectiveView;
+use crate::config::PluginColumnKeys;
 use crate::utils::*;
 
 /// Perspective FFI
@@ -54,6 +55,9 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn default_config(this: &JsPerspectiveViewerPlugin) -> Option<js_sys::Object>;
 
+    #[wasm_bindgen(method, getter)]
+    pub fn supports_column_attributes(this: &JsPerspectiveViewerPlugin) -> Option<bool>;
+
     #[wasm_bindgen(method, getter)]
     pub fn priority(this: &JsPerspectiveViewerPlugin) -> Option<i32>;
 
@@ -136,6 +140,37 @@ impl ViewConfigRequirements {
 }
 
 impl JsPerspectiveViewerPlugin {
+    /// Whether this plugin opts-in to column styles, which it does by
+    /// defining a `default_config` property.  This checks for the property
+    /// without invoking it, as plugins may not be able to compute their
+    /// defaults before they have been drawn.
+    pub fn is_stylable(&self) -> bool {
+        js_sys::Reflect::has(self, &JsValue::from("default_config")).unwrap_or_default()
+    }
+
+    /// Whether this plugin opts-in to column attributes, which it does by
+    /// defining a `supports_column_attributes` property which is `true`.
+    pub fn has_column_attributes(&self) -> bool {
+        self.supports_column_attributes().unwrap_or_default()
+    }
+
+    /// The viewer-owned keys of this plugin's per-column config, derived from
+    /// its `default_config` if it declares style support.
+    pub fn column_keys(&self) -> PluginColumnKeys {
+        let keys = if !self.is_stylable() {
+            PluginColumnKeys::default()
+        } else {
+            js_sys::Reflect::get(self, &JsValue::from("default_config"))
+                .ok()
+                .filter(|x| x.is_object())
+                .and_then(|x| x.into_serde_ext::<serde_json::Value>().ok())
+                .map(|x| PluginColumnKeys::from_default_config(&x))
+                .unwrap_or_else(PluginColumnKeys::all_styles)
+        };
+
+        keys.with_attributes(self.has_column_attributes())
+    }
+
     pub fn get_requirements(&self) -> ApiResult<ViewConfigRequirements> {
         Ok(ViewConfigRequirements {
             min: self.min_config_columns(),
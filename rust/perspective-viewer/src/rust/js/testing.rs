@@ -37,6 +37,16 @@ extern "C" {
 /// will figuratively literally run forever.
 #[cfg(test)]
 pub async fn get_mock_table() -> JsPerspectiveTable {
+    get_mock_table_with_data(json!({
+        "A": [1, 2, 3]
+    }))
+    .await
+}
+
+/// Generate a test `Table` from `data`, e.g. a column-oriented `json!()`
+/// object, on the same webworker as `get_mock_table()`.
+#[cfg(test)]
+pub async fn get_mock_table_with_data(data: js_sys::Object) -> JsPerspectiveTable {
     thread_local! {
         static WORKER: RefCell<Option<JsPerspectiveWorker>> = RefCell::new(None);
     }
@@ -50,15 +60,7 @@ pub async fn get_mock_table() -> JsPerspectiveTable {
         *x.borrow_mut() = Some(worker.clone());
     });
 
-    worker
-        .table(
-            json!({
-                "A": [1, 2, 3]
-            })
-            .unchecked_into(),
-        )
-        .await
-        .unwrap()
+    worker.table(data).await.unwrap()
 }
 
 /// A macro which set a property called `weak_link` on the container
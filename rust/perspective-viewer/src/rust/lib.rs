@@ -30,6 +30,7 @@ mod custom_events;
 mod dragdrop;
 mod exprtk;
 mod js;
+mod link_group;
 mod model;
 mod presentation;
 mod renderer;
@@ -0,0 +1,178 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Link groups cross-filter `<perspective-viewer>`s on the same page.  Each
+//! member of a named group publishes its selection as filters on its own
+//! columns, which the other members apply to their `View`s after mapping them
+//! to their own columns.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::config::*;
+use crate::utils::*;
+
+thread_local! {
+    static LINK_GROUPS: RefCell<HashMap<String, Rc<PubSub<LinkSelection>>>> = Default::default();
+    static NEXT_MEMBER_ID: Cell<usize> = Cell::new(0);
+}
+
+/// A selection published by the member `source` of a link group.  An empty
+/// `filters` clears the member's selection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkSelection {
+    pub source: usize,
+    pub filters: Vec<Filter>,
+}
+
+/// A viewer's membership of a named link group, which leaves the group (and
+/// clears its selection from the other members) when dropped.
+pub struct LinkGroupMember {
+    id: usize,
+    name: String,
+    group: Rc<PubSub<LinkSelection>>,
+    selection: RefCell<Vec<Filter>>,
+    _sub: Subscription,
+}
+
+impl LinkGroupMember {
+    /// Join the link group `name`, calling `on_select` with the selections
+    /// published by the other members, their filters mapped to this member's
+    /// columns.
+    ///
+    /// # Arguments
+    /// - `name` The name of the link group, which is created if necessary.
+    /// - `column_map` A map of the other members' column names to this
+    ///   member's, for columns which are not named the same.
+    /// - `on_select` Called with each selection of the other members.
+    pub fn join(
+        name: &str,
+        column_map: HashMap<String, String>,
+        on_select: impl Fn(LinkSelection) + 'static,
+    ) -> Self {
+        let group = LINK_GROUPS.with(|groups| {
+            groups
+                .borrow_mut()
+                .entry(name.to_owned())
+                .or_default()
+                .clone()
+        });
+
+        let id = NEXT_MEMBER_ID.with(|next| next.replace(next.get() + 1));
+        let _sub = group.add_listener(move |selection: LinkSelection| {
+            if selection.source != id {
+                on_select(LinkSelection {
+                    source: selection.source,
+                    filters: map_filters(&column_map, selection.filters),
+                })
+            }
+        });
+
+        Self {
+            id,
+            name: name.to_owned(),
+            group,
+            selection: Default::default(),
+            _sub,
+        }
+    }
+
+    pub fn name(&self) -> &'_ str {
+        &self.name
+    }
+
+    /// Publish `filters` as this member's selection to the other members of
+    /// its link group, or clear it if `filters` is already the selection
+    /// (i.e. the same cell was clicked twice).
+    pub fn publish(&self, filters: Vec<Filter>) {
+        let filters = if *self.selection.borrow() == filters {
+            vec![]
+        } else {
+            filters
+        };
+
+        *self.selection.borrow_mut() = filters.clone();
+        self.group.emit_all(LinkSelection {
+            source: self.id,
+            filters,
+        });
+    }
+}
+
+impl Drop for LinkGroupMember {
+    fn drop(&mut self) {
+        if !self.selection.borrow().is_empty() {
+            self.group.emit_all(LinkSelection {
+                source: self.id,
+                filters: vec![],
+            });
+        }
+    }
+}
+
+/// Rename the columns of `filters` by `column_map`, keeping the names of
+/// unmapped columns.
+fn map_filters(column_map: &HashMap<String, String>, filters: Vec<Filter>) -> Vec<Filter> {
+    filters
+        .into_iter()
+        .map(|Filter(column, op, term)| {
+            let column = column_map.get(&column).cloned().unwrap_or(column);
+            Filter(column, op, term)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_link_group_publish() {
+        let received = Rc::new(RefCell::new(vec![]));
+        let column_map = [("Region".to_owned(), "Area".to_owned())]
+            .into_iter()
+            .collect();
+
+        let publisher = LinkGroupMember::join("test", HashMap::default(), |_| {
+            panic!("Received own selection")
+        });
+
+        let subscriber = LinkGroupMember::join("test", column_map, {
+            let received = received.clone();
+            move |selection: LinkSelection| received.borrow_mut().push(selection.filters)
+        });
+
+        let filter = |column: &str| {
+            Filter(
+                column.to_owned(),
+                FilterOp::EQ,
+                FilterTerm::Scalar(Scalar::String("East".to_owned())),
+            )
+        };
+
+        publisher.publish(vec![filter("Region")]);
+        publisher.publish(vec![filter("Region")]);
+        publisher.publish(vec![filter("State")]);
+        drop(publisher);
+        assert_eq!(*received.borrow(), vec![
+            vec![filter("Area")],
+            vec![],
+            vec![filter("State")],
+            vec![],
+        ]);
+
+        drop(subscriber);
+    }
+}
@@ -0,0 +1,59 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::structural::*;
+use super::update_and_render::*;
+use crate::config::*;
+use crate::utils::*;
+use crate::*;
+
+/// Column attributes are stored by the `Renderer`, but changing them must
+/// re-render the plugin from the `Session`'s current `View`.  A changed
+/// `show_values_as` transform changes the `View` the plugin is given, so
+/// requires a full draw rather than an update, and re-creates the `View` when
+/// it changes the transforms the engine computes.
+pub trait ColumnAttributesModel: HasRenderer + HasSession + UpdateAndRender {
+    /// Set (or clear, for `None`) the attributes of a column, then re-render.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    /// - `attributes` The new attributes for this column.
+    fn set_column_attributes(
+        &self,
+        name: &str,
+        attributes: Option<ColumnAttributes>,
+    ) -> ApiFuture<()> {
+        let show_values_as = |x: Option<&ColumnAttributes>| x.and_then(|x| x.show_values_as);
+        let old = self.renderer().get_column_attributes(name);
+        let redraw = show_values_as(old.as_ref()) != show_values_as(attributes.as_ref());
+        let result = self.renderer().set_column_attributes(name, attributes);
+        let recreate = redraw
+            && self
+                .session()
+                .set_show_values_as(self.renderer().get_show_values_as());
+
+        let validate_task = recreate.then(|| self.validate_and_render());
+        clone!(self.session(), self.renderer());
+        ApiFuture::new(async move {
+            result?;
+            if let Some(task) = validate_task {
+                task.await
+            } else if redraw {
+                renderer.draw(async { Ok(&session) }).await
+            } else {
+                renderer.update(&session).await
+            }
+        })
+    }
+}
+
+impl<T: HasRenderer + HasSession> ColumnAttributesModel for T {}
@@ -0,0 +1,93 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen::JsValue;
+
+use super::structural::*;
+use crate::config::*;
+use crate::utils::*;
+use crate::*;
+
+/// Column styles are stored by the `Renderer`, but are typed by the column's
+/// type from the `Session`, and must be re-rendered and announced (via the
+/// `Presentation`) when changed.  `ColumnStyleModel` is shared by the style
+/// editor UI and the `<perspective-viewer>` style API.
+pub trait ColumnStyleModel: HasRenderer + HasSession + HasPresentation {
+    /// The `Type` a style for column `name` must validate as - its `View`
+    /// type if it is in the current `View`, or its `Table` type otherwise.
+    fn get_column_style_type(&self, name: &str) -> ApiResult<Type> {
+        let metadata = self.session().metadata();
+        metadata
+            .get_column_view_type(name)
+            .or_else(|| metadata.get_column_table_type(name))
+            .ok_or_else(|| format!("Unknown column \"{}\"", name).into())
+    }
+
+    /// Validate a user-provided JSON style for column `name`.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    /// - `value` The JSON style record.
+    fn parse_column_style(
+        &self,
+        name: &str,
+        value: serde_json::Value,
+    ) -> ApiResult<ColumnStyleConfig> {
+        let ty = self.get_column_style_type(name)?;
+        Ok(ColumnStyleConfig::from_value_strict(ty, value)?)
+    }
+
+    /// Set (or clear, for `None`) the styles of some columns, then restyle
+    /// the plugin and emit a `column_settings_updated` event.
+    ///
+    /// # Arguments
+    /// - `styles` Pairs of column name and new style.
+    fn set_column_styles(
+        &self,
+        styles: impl IntoIterator<Item = (String, Option<ColumnStyleConfig>)>,
+    ) -> ApiFuture<()> {
+        let result = self.renderer().set_column_styles(styles);
+        clone!(self.session(), self.renderer(), self.presentation());
+        ApiFuture::new(async move {
+            result?;
+            renderer.update(&session).await?;
+
+            // send a config update event in case we need to listen for it
+            // outside of the viewer
+            let js_config = JsValue::from_serde_ext(&renderer.get_column_config())?;
+            presentation.column_settings_updated.emit_all(js_config);
+            Ok(())
+        })
+    }
+
+    /// Set the style of every column in the current `View` of type `ty`.
+    ///
+    /// # Arguments
+    /// - `ty` The column type to style.
+    /// - `style` The new style, which must be compatible with `ty`.
+    fn apply_style_to_type(&self, ty: Type, style: ColumnStyleConfig) -> ApiFuture<()> {
+        let names = self
+            .session()
+            .get_view_config()
+            .columns
+            .iter()
+            .flatten()
+            .filter(|name| self.session().metadata().get_column_view_type(name) == Some(ty))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let styles = names.into_iter().map(|name| (name, Some(style.clone())));
+        self.set_column_styles(styles)
+    }
+}
+
+impl<T: HasRenderer + HasSession + HasPresentation> ColumnStyleModel for T {}
@@ -52,6 +52,7 @@ pub struct ColumnsIteratorSet<'a> {
     renderer: &'a Renderer,
     metadata: MetadataRef<'a>,
     is_dragover_column: Option<(usize, String)>,
+    is_dragover_copy: bool,
     named_columns: Vec<String>,
 }
 
@@ -63,6 +64,7 @@ impl<'a> ColumnsIteratorSet<'a> {
         dragdrop: &DragDrop,
     ) -> ColumnsIteratorSet<'a> {
         let is_dragover_column = dragdrop.is_dragover(DragTarget::Active);
+        let is_dragover_copy = dragdrop.get_drag_target().is_none();
         let named_columns = renderer.metadata().names.clone().unwrap_or_default();
         ColumnsIteratorSet {
             config,
@@ -70,6 +72,7 @@ impl<'a> ColumnsIteratorSet<'a> {
             renderer,
             metadata: session.metadata(),
             is_dragover_column,
+            is_dragover_copy,
             named_columns,
         }
     }
@@ -101,11 +104,13 @@ impl<'a> ColumnsIteratorSet<'a> {
                 let is_to_swap = self.renderer.metadata().is_swap(*to_index);
                 let to_column = self.config.columns.get(*to_index);
                 let is_to_empty = to_column.map(|x| x.is_none()).unwrap_or_default();
+                // A copied column is not removed from its original position.
+                let is_copy = self.is_dragover_copy;
                 let from_index = self
                     .config
                     .columns
                     .iter()
-                    .position(|x| x.as_ref() == Some(from_column));
+                    .position(|x| !is_copy && x.as_ref() == Some(from_column));
 
                 let is_from_required = from_index
                     .and_then(|x| self.renderer.metadata().min.map(|y| x < y))
@@ -120,7 +125,7 @@ impl<'a> ColumnsIteratorSet<'a> {
                     self.to_active_column_state(Box::new(self.config.columns.iter().map(Some)))
                 } else if is_to_swap || is_from_required {
                     let all_columns = self.config.columns.iter().filter_map(move |x| match x {
-                        Some(x) if x == from_column => {
+                        Some(x) if !is_copy && x == from_column => {
                             if is_to_empty && !is_from_swap {
                                 None
                             } else {
@@ -147,7 +152,7 @@ impl<'a> ColumnsIteratorSet<'a> {
                     };
 
                     let all_columns = self.config.columns.iter().filter_map(move |x| match x {
-                        Some(x) if x == from_column => {
+                        Some(x) if !is_copy && x == from_column => {
                             if !is_from_swap {
                                 None
                             } else {
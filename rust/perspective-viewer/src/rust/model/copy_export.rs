@@ -23,7 +23,10 @@ use super::get_viewer_config::*;
 use super::structural::*;
 use crate::config::*;
 use crate::js::JsPerspectiveViewerPlugin;
+use crate::renderer::*;
+use crate::session::*;
 use crate::utils::*;
+use crate::*;
 
 fn tag_name_to_package(plugin: &JsPerspectiveViewerPlugin) -> String {
     let tag_name = plugin.unchecked_ref::<web_sys::HtmlElement>().tag_name();
@@ -31,6 +34,40 @@ fn tag_name_to_package(plugin: &JsPerspectiveViewerPlugin) -> String {
     Itertools::intersperse(tag_parts, "-".to_owned()).collect::<String>()
 }
 
+/// The data formats `CopyExportModel` reads from a `View`.
+#[derive(Clone, Copy)]
+enum ViewExportFormat {
+    Arrow,
+    Csv,
+    Json,
+}
+
+/// Read the current `View` (or if `flat`, a `View` of the whole `Table`) in
+/// `format`.  The current `View` is read as the plugin sees it, so any
+/// `show_values_as` column transforms apply.
+async fn export_view(
+    session: &Session,
+    renderer: &Renderer,
+    flat: bool,
+    format: ViewExportFormat,
+) -> ApiResult<web_sys::Blob> {
+    let view = session.get_export_view(flat).await?;
+    let view = if flat {
+        view.js_get()
+    } else {
+        renderer.get_plugin_view(&view)
+    };
+
+    match format {
+        ViewExportFormat::Arrow => view.to_arrow().await?.as_blob(),
+        ViewExportFormat::Csv => {
+            let opts = json!({"formatted": true});
+            view.to_csv(opts.unchecked_into()).await?.as_blob()
+        }
+        ViewExportFormat::Json => view.to_columns().await?.as_blob(),
+    }
+}
+
 /// Export functionality, for downloads and copy-to-clipboard, are mostly shared
 /// behavior, but require access to a few state objects depending on which
 /// format is desired.  The `CopyExportModel` groups this functionality in a
@@ -52,8 +89,9 @@ pub trait CopyExportModel:
             .collect::<Vec<_>>();
 
         ApiFuture::new(async move {
-            let (arrow, config) = join!(session.arrow_as_vec(true), view_config);
-            let arrow = arrow?;
+            let arrow = async { session.get_export_view(true).await?.to_arrow().await };
+            let (arrow, config) = join!(arrow, view_config);
+            let arrow = js_sys::Uint8Array::new(&arrow?.into()).to_vec();
             let mut config = config?;
             config.settings = false;
             let js_config = serde_json::to_string(&config)?;
@@ -86,31 +124,19 @@ pub trait CopyExportModel:
 
     /// Generate a result `Blob` for all types of `ExportMethod`.
     fn export_method_to_jsvalue(&self, method: ExportMethod) -> ApiFuture<web_sys::Blob> {
+        let view_as_jsvalue = |flat, format| {
+            let session = self.session().clone();
+            let renderer = self.renderer().clone();
+            ApiFuture::new(async move { export_view(&session, &renderer, flat, format).await })
+        };
+
         match method {
-            ExportMethod::Csv => {
-                let session = self.session().clone();
-                ApiFuture::new(async move { session.csv_as_jsvalue(false).await?.as_blob() })
-            }
-            ExportMethod::CsvAll => {
-                let session = self.session().clone();
-                ApiFuture::new(async move { session.csv_as_jsvalue(true).await?.as_blob() })
-            }
-            ExportMethod::Json => {
-                let session = self.session().clone();
-                ApiFuture::new(async move { session.json_as_jsvalue(false).await?.as_blob() })
-            }
-            ExportMethod::JsonAll => {
-                let session = self.session().clone();
-                ApiFuture::new(async move { session.json_as_jsvalue(true).await?.as_blob() })
-            }
-            ExportMethod::Arrow => {
-                let session = self.session().clone();
-                ApiFuture::new(async move { session.arrow_as_jsvalue(false).await?.as_blob() })
-            }
-            ExportMethod::ArrowAll => {
-                let session = self.session().clone();
-                ApiFuture::new(async move { session.arrow_as_jsvalue(true).await?.as_blob() })
-            }
+            ExportMethod::Csv => view_as_jsvalue(false, ViewExportFormat::Csv),
+            ExportMethod::CsvAll => view_as_jsvalue(true, ViewExportFormat::Csv),
+            ExportMethod::Json => view_as_jsvalue(false, ViewExportFormat::Json),
+            ExportMethod::JsonAll => view_as_jsvalue(true, ViewExportFormat::Json),
+            ExportMethod::Arrow => view_as_jsvalue(false, ViewExportFormat::Arrow),
+            ExportMethod::ArrowAll => view_as_jsvalue(true, ViewExportFormat::Arrow),
             ExportMethod::Html => {
                 let html_task = self.html_as_jsvalue();
                 ApiFuture::new(async move { html_task.await })
@@ -0,0 +1,44 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::structural::*;
+use super::update_and_render::*;
+use crate::config::*;
+use crate::utils::*;
+
+/// Drill-down navigation, which applies the `Session`'s drill-down updates
+/// and renders them.
+pub trait DrillDownModel: HasSession + HasRenderer + UpdateAndRender {
+    /// Drill into the grouped row `row_path`, resolving to `true` if there was
+    /// a level to drill into.
+    fn drill_down(&self, row_path: &[Scalar]) -> ApiFuture<bool> {
+        let update = self.session().drill_down(row_path);
+        let task = update.map(|update| self.update_and_render(update));
+        ApiFuture::new(async move {
+            match task {
+                Some(task) => task.await.map(|_| true),
+                None => Ok(false),
+            }
+        })
+    }
+
+    /// Return to the drill-down level at `depth`, where `0` is the view
+    /// before any drill-down.
+    fn drill_up(&self, depth: usize) -> ApiFuture<()> {
+        match self.session().drill_up(depth) {
+            Some(update) => self.update_and_render(update),
+            None => ApiFuture::new(async { Ok(()) }),
+        }
+    }
+}
+
+impl<T: HasSession + HasRenderer> DrillDownModel for T {}
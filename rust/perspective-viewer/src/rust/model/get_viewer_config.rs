@@ -48,13 +48,28 @@ pub trait GetViewerConfigModel: HasSession + HasRenderer + HasPresentation {
             let js_plugin = renderer.get_active_plugin()?;
             let settings = presentation.is_settings_open();
             let plugin = js_plugin.name();
-            let plugin_config: serde_json::Value = js_plugin.save().into_serde_ext()?;
+            let mut plugin_config: serde_json::Value = js_plugin.save().into_serde_ext()?;
+            take_column_styles(&mut plugin_config, &js_plugin.column_keys());
+            if !js_plugin.is_stylable() && js_plugin.has_column_attributes() {
+                // Only column attributes are pushed to this plugin, so an
+                // empty `columns` record left once they are taken is ours.
+                strip_empty_columns(&mut plugin_config);
+            }
+
+            let column_config = renderer.get_column_config();
+            let column_attributes = renderer.get_column_attributes_config();
             let theme = presentation.get_selected_theme_name().await;
             let title = presentation.get_title();
+            let time_zone = session.get_time_zone();
+            let drill_down = session.get_drill_down();
             Ok(ViewerConfig {
                 plugin,
                 title,
+                time_zone,
                 plugin_config,
+                column_config,
+                column_attributes,
+                drill_down,
                 settings,
                 view_config,
                 theme,
@@ -65,16 +65,22 @@
 //! }
 //! ```
 
+mod column_attributes;
+mod column_style;
 mod columns_iter_set;
 mod copy_export;
+mod drill_down;
 mod export_app;
 mod export_method;
 mod get_viewer_config;
 mod structural;
 mod update_and_render;
 
+pub use self::column_attributes::*;
+pub use self::column_style::*;
 pub use self::columns_iter_set::*;
 pub use self::copy_export::*;
+pub use self::drill_down::*;
 pub use self::export_method::*;
 pub use self::get_viewer_config::*;
 pub use self::structural::*;
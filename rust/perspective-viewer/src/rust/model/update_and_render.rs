@@ -36,6 +36,13 @@ pub trait UpdateAndRender: HasRenderer + HasSession {
         })
     }
 
+    /// Re-create the current `View` if it has been invalidated, e.g. by a
+    /// change to the `Session` outside of its `ViewConfig`, and render.
+    fn validate_and_render(&self) -> ApiFuture<()> {
+        clone!(self.session(), self.renderer());
+        ApiFuture::new(update_and_render(session, renderer))
+    }
+
     /// Apply a `ViewConfigUpdate` to the current `View` and render.
     fn update_and_render(&self, update: crate::config::ViewConfigUpdate) -> ApiFuture<()> {
         self.session().update_view_config(update);
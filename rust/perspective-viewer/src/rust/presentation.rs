@@ -11,6 +11,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use std::cell::RefCell;
+use std::fmt::Display;
 use std::ops::Deref;
 use std::rc::Rc;
 
@@ -20,8 +21,46 @@ use wasm_bindgen::JsCast;
 use web_sys::*;
 use yew::html::ImplicitClone;
 
+use crate::config::*;
 use crate::utils::*;
 
+thread_local! {
+    /// The style presets shared by every viewer in this document, parsed from
+    /// `localStorage` on first use and invalidated by `storage` events from
+    /// other documents.
+    static STYLE_PRESETS: RefCell<Option<Vec<ColumnStylePreset>>> = Default::default();
+}
+
+/// What clicking a cell of the plugin does, as chosen from the status bar.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ClickAction {
+    /// Leave clicks to the plugin, e.g. for selection or editing.
+    #[default]
+    None,
+
+    /// Drill into the clicked row's group.
+    DrillDown,
+
+    /// Show the underlying rows of the clicked aggregated cell.
+    DrillThrough,
+}
+
+impl ClickAction {
+    pub const ALL: [Self; 3] = [Self::None, Self::DrillDown, Self::DrillThrough];
+}
+
+impl Display for ClickAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::None => "Select",
+            Self::DrillDown => "Drill down",
+            Self::DrillThrough => "Drill through",
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
 /// The available themes as detected in the browser environment or set
 /// explicitly when CORS prevents detection.  Detection is expensive and
 /// typically must be performed only once, when `document.styleSheets` is
@@ -50,12 +89,18 @@ pub struct PresentationHandle {
     theme_data: Mutex<ThemeData>,
     name: RefCell<Option<String>>,
     is_settings_open: RefCell<bool>,
+    click_action: RefCell<ClickAction>,
+    stale_threshold: RefCell<Option<f64>>,
     is_workspace: RefCell<Option<bool>>,
+    style_clipboard: RefCell<Option<ColumnStyleConfig>>,
+    _storage_listener: Option<crate::utils::EventListener>,
     pub settings_open_changed: PubSub<bool>,
     pub column_settings_open_changed: PubSub<(bool, Option<String>)>,
     pub column_settings_updated: PubSub<JsValue>,
     pub theme_config_updated: PubSub<(Vec<String>, Option<usize>)>,
     pub title_changed: PubSub<Option<String>>,
+    pub stale_threshold_changed: PubSub<Option<f64>>,
+    pub style_presets_changed: PubSub<()>,
 }
 
 #[derive(Default)]
@@ -65,6 +110,18 @@ pub struct ThemeData {
 
 impl Presentation {
     pub fn new(elem: &HtmlElement) -> Self {
+        let style_presets_changed = PubSub::default();
+        let storage_listener = {
+            let on_change = style_presets_changed.callback();
+            crate::utils::EventListener::new(&global::window(), "storage", move |event| {
+                let key = event.unchecked_into::<StorageEvent>().key();
+                if key.map_or(true, |key| key == STYLE_PRESETS_STORAGE_KEY) {
+                    STYLE_PRESETS.with(|x| *x.borrow_mut() = None);
+                    on_change.emit(());
+                }
+            })
+        };
+
         let theme = Self(Rc::new(PresentationHandle {
             viewer_elem: elem.clone(),
             name: Default::default(),
@@ -73,9 +130,15 @@ impl Presentation {
             column_settings_open_changed: Default::default(),
             column_settings_updated: Default::default(),
             is_settings_open: Default::default(),
+            click_action: Default::default(),
+            stale_threshold: Default::default(),
             is_workspace: Default::default(),
+            style_clipboard: Default::default(),
+            _storage_listener: storage_listener.ok(),
             theme_config_updated: PubSub::default(),
             title_changed: PubSub::default(),
+            stale_threshold_changed: PubSub::default(),
+            style_presets_changed,
         }));
 
         ApiFuture::spawn(theme.clone().init());
@@ -126,6 +189,90 @@ impl Presentation {
         Ok(open_state)
     }
 
+    /// What clicking a cell of the plugin does.
+    pub fn get_click_action(&self) -> ClickAction {
+        *self.click_action.borrow()
+    }
+
+    pub fn set_click_action(&self, action: ClickAction) {
+        *self.click_action.borrow_mut() = action;
+    }
+
+    /// How many seconds without a `Table` update before the viewer is
+    /// highlighted as stale, or `None` to never highlight it.
+    pub fn get_stale_threshold(&self) -> Option<f64> {
+        *self.stale_threshold.borrow()
+    }
+
+    pub fn set_stale_threshold(&self, seconds: Option<f64>) {
+        *self.stale_threshold.borrow_mut() = seconds;
+        self.stale_threshold_changed.emit_all(seconds);
+    }
+
+    pub fn set_stale_attribute(&self, opt: bool) {
+        self.viewer_elem
+            .toggle_attribute_with_force("stale", opt)
+            .unwrap();
+    }
+
+    /// The column style most recently copied from the column settings
+    /// sidebar, if any.
+    pub fn get_style_clipboard(&self) -> Option<ColumnStyleConfig> {
+        self.style_clipboard.borrow().clone()
+    }
+
+    pub fn set_style_clipboard(&self, style: ColumnStyleConfig) {
+        *self.style_clipboard.borrow_mut() = Some(style);
+    }
+
+    /// The named column style presets, in the order they were saved.  These
+    /// are persisted to `localStorage` when it is available, so they are
+    /// shared with other viewers and survive a reload; otherwise they are kept
+    /// for this document only.
+    pub fn get_style_presets(&self) -> Vec<ColumnStylePreset> {
+        STYLE_PRESETS.with(|cached| {
+            cached
+                .borrow_mut()
+                .get_or_insert_with(load_style_presets)
+                .clone()
+        })
+    }
+
+    /// Add presets, replacing any existing presets of the same name.
+    ///
+    /// # Arguments
+    /// - `presets` The presets to add.
+    pub fn save_style_presets(&self, presets: impl IntoIterator<Item = ColumnStylePreset>) {
+        let mut style_presets = self.get_style_presets();
+        merge_style_presets(&mut style_presets, presets);
+        self.set_style_presets(style_presets);
+    }
+
+    /// Delete the preset named `name`, returning whether it existed.
+    pub fn delete_style_preset(&self, name: &str) -> bool {
+        let mut style_presets = self.get_style_presets();
+        let len = style_presets.len();
+        style_presets.retain(|x| x.name != name);
+        let is_deleted = style_presets.len() != len;
+        self.set_style_presets(style_presets);
+        is_deleted
+    }
+
+    fn set_style_presets(&self, presets: Vec<ColumnStylePreset>) {
+        if let Some(storage) = global::local_storage() {
+            let result = serde_json::to_string(&presets)
+                .map_err(|e| JsValue::from(e.to_string()))
+                .and_then(|json| storage.set_item(STYLE_PRESETS_STORAGE_KEY, &json));
+
+            if let Err(err) = result {
+                tracing::warn!("Failed to persist style presets: {:?}", err);
+            }
+        }
+
+        STYLE_PRESETS.with(|x| *x.borrow_mut() = Some(presets));
+        self.style_presets_changed.emit_all(());
+    }
+
     async fn init(self) -> ApiResult<()> {
         self.set_theme_attribute(self.get_selected_theme_name().await.as_deref())
     }
@@ -196,6 +343,22 @@ impl Presentation {
     }
 }
 
+/// Read the style presets from `localStorage`, or none if it is unavailable
+/// or its presets are invalid.
+fn load_style_presets() -> Vec<ColumnStylePreset> {
+    let stored = global::local_storage()
+        .and_then(|storage| storage.get_item(STYLE_PRESETS_STORAGE_KEY).ok().flatten());
+
+    match stored.map(|json| serde_json::from_str(&json)) {
+        Some(Ok(presets)) => presets,
+        Some(Err(err)) => {
+            tracing::warn!("Ignoring invalid stored style presets: {}", err);
+            vec![]
+        }
+        None => vec![],
+    }
+}
+
 macro_rules! iter_index {
     ($x:expr) => {
         (0..$x.length()).map(|x| $x.item(x))
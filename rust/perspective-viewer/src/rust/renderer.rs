@@ -21,10 +21,12 @@ mod activate;
 mod limits;
 mod plugin_store;
 mod registry;
+mod render_stats;
 mod render_timer;
+mod show_values_as;
 
-use std::cell::{Ref, RefCell};
-use std::collections::HashMap;
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::{HashMap, VecDeque};
 use std::future::Future;
 use std::ops::Deref;
 use std::pin::Pin;
@@ -40,7 +42,9 @@ use self::activate::*;
 use self::limits::*;
 use self::plugin_store::*;
 pub use self::registry::*;
+pub use self::render_stats::*;
 use self::render_timer::*;
+use self::show_values_as::*;
 use crate::config::*;
 use crate::js::perspective::*;
 use crate::js::plugin::*;
@@ -57,6 +61,11 @@ pub struct RendererData {
     draw_lock: DebounceMutex,
     pub plugin_changed: PubSub<JsPerspectiveViewerPlugin>,
     pub session_changed: PubSub<(bool, RenderLimits)>,
+    pub column_attributes_changed: PubSub<()>,
+
+    /// Emitted when the render stats overlay is toggled, and when the
+    /// `RenderStats` change while it is shown.
+    pub render_stats_changed: PubSub<()>,
 }
 
 /// Mutable state
@@ -66,6 +75,20 @@ pub struct RendererMutData {
     plugin_store: PluginStore,
     plugins_idx: Option<usize>,
     timer: MovingWindowRenderTimer,
+    render_stats: RenderStats,
+    render_stats_overlay: bool,
+
+    /// The names of this viewer's `performance` marks and measures, oldest
+    /// first, see `Renderer::measure()`.
+    performance_entries: VecDeque<String>,
+    column_config: ColumnConfig,
+    column_attributes: ColumnAttributesConfig,
+    time_zone: Option<String>,
+
+    /// The last `View` wrapped to apply `show_values_as` transforms, cached so
+    /// plugins are given the same wrapper (and its cached data) for each
+    /// update of the same `View`.
+    transformed_view: Option<TransformedView>,
 }
 
 type RenderLimits = (usize, usize, Option<usize>, Option<usize>);
@@ -109,15 +132,27 @@ impl Renderer {
                 plugin_store: PluginStore::default(),
                 plugins_idx: None,
                 timer: MovingWindowRenderTimer::default(),
+                render_stats: RenderStats::default(),
+                render_stats_overlay: false,
+                performance_entries: VecDeque::default(),
+                column_config: ColumnConfig::default(),
+                column_attributes: ColumnAttributesConfig::default(),
+                time_zone: None,
+                transformed_view: None,
             }),
             draw_lock: Default::default(),
             plugin_changed: Default::default(),
             session_changed: Default::default(),
+            column_attributes_changed: Default::default(),
+            render_stats_changed: Default::default(),
         }))
     }
 
     pub async fn reset(&self) {
         self.0.borrow_mut().plugins_idx = None;
+        self.0.borrow_mut().column_config.clear();
+        self.0.borrow_mut().column_attributes.clear();
+        self.column_attributes_changed.emit_all(());
         if let Ok(plugin) = self.get_active_plugin() {
             plugin.restore(&json!({}));
         }
@@ -169,6 +204,7 @@ impl Renderer {
     }
 
     pub async fn restyle_all(&self, view: &JsPerspectiveView) -> ApiResult<JsValue> {
+        let view = &self.get_plugin_view(view);
         let plugins = self.get_all_plugins();
         let tasks = plugins.iter().map(|plugin| plugin.restyle(view));
 
@@ -179,6 +215,181 @@ impl Renderer {
             .map(|_| JsValue::UNDEFINED)
     }
 
+    /// The column styles owned by this viewer.  Unlike a plugin's
+    /// `plugin_config`, these persist across plugin changes.
+    pub fn get_column_config(&self) -> ColumnConfig {
+        self.0.borrow().column_config.clone()
+    }
+
+    pub fn get_column_style(&self, name: &str) -> Option<ColumnStyleConfig> {
+        self.0.borrow().column_config.get(name).cloned()
+    }
+
+    /// Set the style for a column, or clear it if `None` (or an empty style)
+    /// is provided, then push the new column styles to the active plugin.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    /// - `style` The new style for this column.
+    pub fn set_column_style(&self, name: &str, style: Option<ColumnStyleConfig>) -> ApiResult<()> {
+        self.set_column_styles([(name.to_owned(), style)])
+    }
+
+    /// Like `set_column_style()`, but for many columns at once, pushing the
+    /// new column styles to the active plugin only once.
+    ///
+    /// # Arguments
+    /// - `styles` Pairs of column name and new style (or `None` to clear).
+    pub fn set_column_styles(
+        &self,
+        styles: impl IntoIterator<Item = (String, Option<ColumnStyleConfig>)>,
+    ) -> ApiResult<()> {
+        {
+            let column_config = &mut self.0.borrow_mut().column_config;
+            for (name, style) in styles.into_iter() {
+                match style {
+                    Some(style) if !style.is_empty() => {
+                        column_config.insert(name, style);
+                    }
+                    _ => {
+                        column_config.remove(&name);
+                    }
+                }
+            }
+        }
+
+        self.push_column_config()
+    }
+
+    /// Apply a `ColumnConfigUpdate`, typing each record by the column's type in
+    /// `session`.  Records which fail to validate, or which refer to columns
+    /// that don't exist, are dropped with a warning.
+    ///
+    /// # Arguments
+    /// - `update` The column styles to apply.
+    /// - `session` The `Session` to look up column types from.
+    pub fn update_column_config(
+        &self,
+        update: ColumnConfigUpdate,
+        session: &Session,
+    ) -> ApiResult<()> {
+        let records = match update {
+            ColumnConfigUpdate::Missing => return Ok(()),
+            ColumnConfigUpdate::SetDefault => HashMap::default(),
+            ColumnConfigUpdate::Update(records) => records,
+        };
+
+        let mut column_config = ColumnConfig::default();
+        for (name, record) in records.into_iter() {
+            let ty = {
+                let metadata = session.metadata();
+                metadata
+                    .get_column_view_type(&name)
+                    .or_else(|| metadata.get_column_table_type(&name))
+            };
+
+            match ty.map(|ty| ColumnStyleConfig::from_value(ty, record)) {
+                Some(Ok(style)) if !style.is_empty() => {
+                    column_config.insert(name, style);
+                }
+                Some(Ok(_)) => (),
+                Some(Err(err)) => tracing::warn!("Column \"{}\": {}", name, err),
+                None => tracing::warn!("Unknown column \"{}\" in `column_config`", name),
+            }
+        }
+
+        self.0.borrow_mut().column_config = column_config;
+        self.push_column_config()
+    }
+
+    /// The column attributes (labels, descriptions and units) owned by this
+    /// viewer.
+    pub fn get_column_attributes_config(&self) -> ColumnAttributesConfig {
+        self.0.borrow().column_attributes.clone()
+    }
+
+    pub fn get_column_attributes(&self, name: &str) -> Option<ColumnAttributes> {
+        self.0.borrow().column_attributes.get(name).cloned()
+    }
+
+    /// The `show_values_as` transform of each column which has one.
+    pub fn get_show_values_as(&self) -> HashMap<String, ShowValuesAs> {
+        self.0
+            .borrow()
+            .column_attributes
+            .iter()
+            .filter_map(|(name, attrs)| Some((name.to_owned(), attrs.show_values_as?)))
+            .collect()
+    }
+
+    /// The text to display for a column in the UI, its label and units if it
+    /// has any, or its name otherwise.
+    pub fn get_column_display_name(&self, name: &str) -> String {
+        self.get_column_attributes(name)
+            .unwrap_or_default()
+            .display_name(name)
+    }
+
+    /// Set the attributes for a column, or clear them if `None` (or empty
+    /// attributes) are provided, then push them to the active plugin.
+    ///
+    /// # Arguments
+    /// - `name` The column name.
+    /// - `attributes` The new attributes for this column.
+    pub fn set_column_attributes(
+        &self,
+        name: &str,
+        attributes: Option<ColumnAttributes>,
+    ) -> ApiResult<()> {
+        match attributes {
+            Some(attributes) if !attributes.is_empty() => {
+                self.0
+                    .borrow_mut()
+                    .column_attributes
+                    .insert(name.to_owned(), attributes);
+            }
+            _ => {
+                self.0.borrow_mut().column_attributes.remove(name);
+            }
+        }
+
+        self.column_attributes_changed.emit_all(());
+        self.push_column_config()
+    }
+
+    /// Apply a `ColumnAttributesUpdate`.  Unlike column styles, attributes
+    /// don't depend on the column's type, so may refer to columns which are
+    /// not (yet) in the `Table`.
+    ///
+    /// # Arguments
+    /// - `update` The column attributes to apply.
+    pub fn update_column_attributes(&self, update: ColumnAttributesUpdate) -> ApiResult<()> {
+        let column_attributes = match update {
+            ColumnAttributesUpdate::Missing => return Ok(()),
+            ColumnAttributesUpdate::SetDefault => ColumnAttributesConfig::default(),
+            ColumnAttributesUpdate::Update(records) => records
+                .into_iter()
+                .filter(|(_, attributes)| !attributes.is_empty())
+                .collect(),
+        };
+
+        self.0.borrow_mut().column_attributes = column_attributes;
+        self.column_attributes_changed.emit_all(());
+        self.push_column_config()
+    }
+
+    /// Set the time zone in which the active plugin should display datetime
+    /// columns which have no `timeZone` style of their own, then push it to
+    /// the active plugin.
+    ///
+    /// # Arguments
+    /// - `time_zone` An IANA time zone name, or `None` for the browser's time
+    ///   zone.
+    pub fn set_time_zone(&self, time_zone: Option<String>) -> ApiResult<()> {
+        self.0.borrow_mut().time_zone = time_zone;
+        self.push_column_config()
+    }
+
     pub fn set_throttle(&self, val: Option<f64>) {
         self.0.borrow_mut().timer.set_throttle(val);
     }
@@ -217,6 +428,7 @@ impl Renderer {
             self.borrow_mut().plugins_idx = Some(idx);
             let plugin: JsPerspectiveViewerPlugin = self.get_active_plugin()?;
             self.borrow_mut().metadata = plugin.get_requirements()?;
+            self.push_column_config()?;
             self.plugin_changed.emit_all(plugin);
         }
 
@@ -235,7 +447,7 @@ impl Renderer {
             .debounce(async {
                 set_timeout(timer.get_avg()).await?;
                 let jsplugin = self.get_active_plugin()?;
-                jsplugin.resize().await?;
+                self.measure(RenderStage::Resize, jsplugin.resize()).await?;
                 Ok(())
             })
             .await
@@ -249,6 +461,10 @@ impl Renderer {
     }
 
     pub async fn update(&self, session: &Session) -> ApiResult<()> {
+        if let Some(transformed_view) = &self.0.borrow().transformed_view {
+            transformed_view.invalidate();
+        }
+
         self.draw_plugin(async { Ok(session) }, true).await
     }
 
@@ -258,13 +474,26 @@ impl Renderer {
         is_update: bool,
     ) -> ApiResult<()> {
         let timer = self.render_timer();
+        let is_drawn = &Cell::new(false);
         let task = async move {
-            if is_update {
+            is_drawn.set(true);
+            let session = if is_update {
                 set_timeout(timer.get_avg()).await?;
-            }
-
-            if let Some(view) = session.await?.get_view() {
-                timer.capture_time(self.draw_view(&view, is_update)).await
+                session.await?
+            } else {
+                self.measure(RenderStage::CreateView, session).await?
+            };
+
+            if let Some(view) = session.get_view() {
+                let stage = if is_update {
+                    RenderStage::Update
+                } else {
+                    RenderStage::Draw
+                };
+
+                let view = self.get_transformed_view(&view, session);
+                let task = self.measure(stage, self.draw_view(&view, is_update));
+                timer.capture_time(task).await
             } else {
                 Ok(())
             }
@@ -272,7 +501,14 @@ impl Renderer {
 
         let draw_mutex = self.draw_lock();
         if is_update {
-            draw_mutex.debounce(task).await
+            let result = draw_mutex.debounce(task).await;
+            if !is_drawn.get() {
+                self.count_debounced_update();
+            } else if result.is_err() {
+                self.count_dropped_update();
+            }
+
+            result
         } else {
             draw_mutex.lock(task).await
         }
@@ -293,6 +529,54 @@ impl Renderer {
         }
     }
 
+    /// The `View` to give the plugin, which is `view` wrapped to apply any
+    /// `show_values_as` column transforms the engine does not compute.
+    fn get_transformed_view(
+        &self,
+        view: &JsPerspectiveView,
+        session: &Session,
+    ) -> JsPerspectiveView {
+        let transforms = session.get_client_show_values_as();
+
+        if transforms.is_empty() {
+            self.0.borrow_mut().transformed_view = None;
+            return view.unchecked_ref::<JsValue>().clone().unchecked_into();
+        }
+
+        let mut data = self.0.borrow_mut();
+        match &data.transformed_view {
+            Some(cached) if cached.is_for(view, &transforms) => cached.get_view(),
+            _ => {
+                let config = session.get_view_config();
+                let metadata = session.metadata();
+                let columns = config.columns.iter().flatten().cloned().collect();
+                let group_by = config
+                    .group_by
+                    .iter()
+                    .map(|name| {
+                        let ty = metadata.get_column_table_type(name).unwrap_or(Type::String);
+                        (name.clone(), ty)
+                    })
+                    .collect();
+
+                let transformed_view = TransformedView::new(view, transforms, columns, group_by);
+                let wrapper = transformed_view.get_view();
+                data.transformed_view = Some(transformed_view);
+                wrapper
+            }
+        }
+    }
+
+    /// The `View` as given to the plugin, which is `view` wrapped to apply any
+    /// `show_values_as` transforms if it is the `View` last drawn, e.g. for
+    /// exporting the data the plugin shows.
+    pub fn get_plugin_view(&self, view: &JsPerspectiveView) -> JsPerspectiveView {
+        match &self.0.borrow().transformed_view {
+            Some(cached) if cached.is_for_view(view) => cached.get_view(),
+            _ => view.unchecked_ref::<JsValue>().clone().unchecked_into(),
+        }
+    }
+
     /// Decide whether to draw plugin or self first based on whether the panel
     /// is opening or closing, then draw with a timeout.  If the timeout
     /// triggers, draw self and resolve `on_toggle` but still await the
@@ -325,13 +609,13 @@ impl Renderer {
     /// type, `Result::Err` contains the continuation task, which must be
     /// awaited lest the plugin draw itself never trigger.
     async fn resize_with_timeout(&self, open: bool) -> Result<TaskResult, TimeoutTask<'_>> {
-        let task = async move {
+        let task = self.measure(RenderStage::Resize, async move {
             if open {
                 self.get_active_plugin()?.resize().await
             } else {
                 self.resize_with_explicit_dimensions().await
             }
-        };
+        });
 
         let draw_lock = self.draw_lock();
         let tasks: [TimeoutTask<'_>; 2] = [
@@ -360,10 +644,61 @@ impl Renderer {
         result
     }
 
+    /// Send the column attributes, styles and default time zone to the active
+    /// plugin via its `restore()` method, preserving any plugin-specific
+    /// per-column state.  Only plugins which declare style support (via a
+    /// `default_config` property) receive the styles and time zone, and only
+    /// plugins which declare attribute support (via a
+    /// `supports_column_attributes` property) receive the column attributes.
+    /// Plugins which declare neither are left alone.
+    pub fn push_column_config(&self) -> ApiResult<()> {
+        let plugin = self.get_active_plugin()?;
+        let is_stylable = plugin.is_stylable();
+        let has_attributes = plugin.has_column_attributes();
+        if !is_stylable && !has_attributes {
+            return Ok(());
+        }
+
+        let mut plugin_config: serde_json::Value = plugin.save().into_serde_ext()?;
+        take_column_styles(&mut plugin_config, &plugin.column_keys());
+        {
+            let data = self.0.borrow();
+            let unstyled = ColumnConfig::default();
+            let unattributed = ColumnAttributesConfig::default();
+            merge_column_styles(
+                &mut plugin_config,
+                if is_stylable {
+                    &data.column_config
+                } else {
+                    &unstyled
+                },
+                if has_attributes {
+                    &data.column_attributes
+                } else {
+                    &unattributed
+                },
+            );
+
+            if is_stylable && let serde_json::Value::Object(config) = &mut plugin_config {
+                let time_zone = data.time_zone.clone().map(serde_json::Value::String);
+                config.insert("time_zone".to_owned(), time_zone.unwrap_or_default());
+            }
+        }
+
+        plugin.restore(&JsValue::from_serde_ext(&plugin_config)?);
+        Ok(())
+    }
+
     fn draw_lock(&self) -> DebounceMutex {
         self.draw_lock.clone()
     }
 
+    /// The time the active plugin takes to render, on average, which bounds
+    /// how often work done per update is useful.
+    pub fn get_render_interval(&self) -> i32 {
+        self.render_timer().get_avg()
+    }
+
     fn render_timer(&self) -> MovingWindowRenderTimer {
         self.0.borrow().timer.clone()
     }
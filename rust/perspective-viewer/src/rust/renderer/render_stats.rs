@@ -0,0 +1,300 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::future::Future;
+
+use serde::Serialize;
+
+use super::Renderer;
+use crate::utils::*;
+
+/// The upper bounds of the `Histogram` buckets, in milliseconds.  The last
+/// bucket counts durations of `1024` milliseconds or more.
+pub const HISTOGRAM_BOUNDS_MS: [f64; 11] = [
+    1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0, 1024.0,
+];
+
+/// The number of `performance` entries each viewer keeps, beyond which its
+/// oldest are cleared so the performance timeline does not grow unbounded.
+const MAX_PERFORMANCE_ENTRIES: usize = 100;
+
+thread_local! {
+    static NEXT_MARK_ID: Cell<u64> = Cell::new(0);
+}
+
+/// A timed stage of rendering, named in the `performance.measure()` entries
+/// (e.g. `"perspective-viewer:draw:12"`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RenderStage {
+    CreateView,
+    Draw,
+    Update,
+    Resize,
+}
+
+impl RenderStage {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::CreateView => "view",
+            Self::Draw => "draw",
+            Self::Update => "update",
+            Self::Resize => "resize",
+        }
+    }
+}
+
+/// A histogram of durations, bucketed by `HISTOGRAM_BOUNDS_MS`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Histogram {
+    pub count: u32,
+    pub total_ms: f64,
+    pub max_ms: f64,
+    pub buckets: [u32; HISTOGRAM_BOUNDS_MS.len() + 1],
+}
+
+impl Histogram {
+    pub fn record(&mut self, ms: f64) {
+        let idx = HISTOGRAM_BOUNDS_MS
+            .iter()
+            .position(|bound| ms < *bound)
+            .unwrap_or(HISTOGRAM_BOUNDS_MS.len());
+
+        self.buckets[idx] += 1;
+        self.count += 1;
+        self.total_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+    }
+
+    pub fn mean_ms(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.total_ms / self.count as f64)
+    }
+
+    /// The upper bound of the bucket containing the `p`th percentile (of
+    /// `0.0..=1.0`), or the `max_ms` for the last bucket.
+    pub fn percentile_ms(&self, p: f64) -> Option<f64> {
+        let target = (p * self.count as f64).ceil().max(1.0) as u32;
+        let mut seen = 0;
+        for (idx, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return Some(
+                    HISTOGRAM_BOUNDS_MS
+                        .get(idx)
+                        .map(|x| x.min(self.max_ms))
+                        .unwrap_or(self.max_ms),
+                );
+            }
+        }
+
+        None
+    }
+}
+
+/// The `Histogram`s of the stages a plugin is responsible for.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PluginRenderStats {
+    pub draw: Histogram,
+    pub update: Histogram,
+    pub resize: Histogram,
+}
+
+/// Render timings since the `Renderer` was created (or the stats were reset),
+/// as returned by `getRenderStats()`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RenderStats {
+    pub view: Histogram,
+    pub draw: Histogram,
+    pub update: Histogram,
+    pub resize: Histogram,
+
+    /// Updates superseded by a later update before they were drawn.
+    pub debounced_updates: u32,
+
+    /// Updates which failed to draw, e.g. because the `View` was deleted.
+    pub dropped_updates: u32,
+
+    pub plugins: BTreeMap<String, PluginRenderStats>,
+    pub bucket_bounds_ms: [f64; HISTOGRAM_BOUNDS_MS.len()],
+}
+
+impl Default for RenderStats {
+    fn default() -> Self {
+        Self {
+            view: Histogram::default(),
+            draw: Histogram::default(),
+            update: Histogram::default(),
+            resize: Histogram::default(),
+            debounced_updates: 0,
+            dropped_updates: 0,
+            plugins: BTreeMap::default(),
+            bucket_bounds_ms: HISTOGRAM_BOUNDS_MS,
+        }
+    }
+}
+
+impl RenderStats {
+    pub fn record(&mut self, stage: RenderStage, plugin: Option<&str>, ms: f64) {
+        match stage {
+            RenderStage::CreateView => self.view.record(ms),
+            RenderStage::Draw => self.draw.record(ms),
+            RenderStage::Update => self.update.record(ms),
+            RenderStage::Resize => self.resize.record(ms),
+        };
+
+        if let Some(plugin) = plugin {
+            let stats = self.plugins.entry(plugin.to_owned()).or_default();
+            match stage {
+                RenderStage::CreateView => (),
+                RenderStage::Draw => stats.draw.record(ms),
+                RenderStage::Update => stats.update.record(ms),
+                RenderStage::Resize => stats.resize.record(ms),
+            }
+        }
+    }
+
+    /// The stages in display order, with their `Histogram`s.
+    pub fn stages(&self) -> [(RenderStage, &Histogram); 4] {
+        [
+            (RenderStage::CreateView, &self.view),
+            (RenderStage::Draw, &self.draw),
+            (RenderStage::Update, &self.update),
+            (RenderStage::Resize, &self.resize),
+        ]
+    }
+}
+
+impl Renderer {
+    pub fn get_render_stats(&self) -> RenderStats {
+        self.0.borrow().render_stats.clone()
+    }
+
+    pub fn reset_render_stats(&self) {
+        self.0.borrow_mut().render_stats = RenderStats::default();
+        self.notify_render_stats_changed();
+    }
+
+    /// Notify `render_stats_changed` listeners, which only render the
+    /// `RenderStats` while the overlay is shown, so this is skipped otherwise
+    /// to keep re-renders off of the render path.
+    fn notify_render_stats_changed(&self) {
+        if self.is_render_stats_overlay() {
+            self.render_stats_changed.emit_all(());
+        }
+    }
+
+    pub fn is_render_stats_overlay(&self) -> bool {
+        self.0.borrow().render_stats_overlay
+    }
+
+    /// Show (or hide) the render stats debug overlay, or toggle it if `force`
+    /// is `None`, returning whether it is now shown.
+    pub fn toggle_render_stats_overlay(&self, force: Option<bool>) -> bool {
+        let show = force.unwrap_or_else(|| !self.is_render_stats_overlay());
+        self.0.borrow_mut().render_stats_overlay = show;
+        self.render_stats_changed.emit_all(());
+        show
+    }
+
+    /// Time `f` as `stage` of rendering for the active plugin, recording it in
+    /// the `RenderStats` and as a `performance.measure()` entry.  Each entry
+    /// has a unique name, so this viewer can clear its own oldest entries
+    /// without clearing those of other viewers.
+    pub(super) async fn measure<T>(&self, stage: RenderStage, f: impl Future<Output = T>) -> T {
+        let plugin = match stage {
+            RenderStage::CreateView => None,
+            _ => self.get_active_plugin().ok().map(|x| x.name()),
+        };
+
+        let perf = global::performance();
+        let name = NEXT_MARK_ID.with(|id| {
+            id.set(id.get() + 1);
+            format!("perspective-viewer:{}:{}", stage.name(), id.get())
+        });
+
+        let start = perf.now();
+        let _ = perf.mark(&name);
+        let result = f.await;
+        let _ = perf.measure_with_start_mark(&name, &name);
+        let ms = perf.now() - start;
+        let expired = {
+            let mut data = self.0.borrow_mut();
+            data.render_stats.record(stage, plugin.as_deref(), ms);
+            data.performance_entries.push_back(name);
+            let excess = data
+                .performance_entries
+                .len()
+                .saturating_sub(MAX_PERFORMANCE_ENTRIES);
+
+            data.performance_entries.drain(..excess).collect::<Vec<_>>()
+        };
+
+        for name in expired {
+            perf.clear_marks_with_mark_name(&name);
+            perf.clear_measures_with_measure_name(&name);
+        }
+
+        self.notify_render_stats_changed();
+        result
+    }
+
+    pub(super) fn count_debounced_update(&self) {
+        self.0.borrow_mut().render_stats.debounced_updates += 1;
+        self.notify_render_stats_changed();
+    }
+
+    pub(super) fn count_dropped_update(&self) {
+        self.0.borrow_mut().render_stats.dropped_updates += 1;
+        self.notify_render_stats_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_histogram() {
+        let mut hist = Histogram::default();
+        assert_eq!(hist.mean_ms(), None);
+        assert_eq!(hist.percentile_ms(0.5), None);
+        for ms in [0.5, 3.0, 3.5, 10.0, 2000.0] {
+            hist.record(ms);
+        }
+
+        assert_eq!(hist.count, 5);
+        assert_eq!(hist.buckets[0], 1);
+        assert_eq!(hist.buckets[2], 2);
+        assert_eq!(hist.buckets[4], 1);
+        assert_eq!(hist.buckets[11], 1);
+        assert_eq!(hist.mean_ms(), Some(403.4));
+        assert_eq!(hist.percentile_ms(0.5), Some(4.0));
+        assert_eq!(hist.percentile_ms(0.95), Some(2000.0));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_render_stats_plugins() {
+        let mut stats = RenderStats::default();
+        stats.record(RenderStage::CreateView, None, 5.0);
+        stats.record(RenderStage::Draw, Some("Datagrid"), 20.0);
+        stats.record(RenderStage::Update, Some("Datagrid"), 10.0);
+        stats.record(RenderStage::Draw, Some("Y Bar"), 40.0);
+        assert_eq!(stats.view.count, 1);
+        assert_eq!(stats.draw.count, 2);
+        assert_eq!(stats.plugins["Datagrid"].draw.count, 1);
+        assert_eq!(stats.plugins["Datagrid"].update.count, 1);
+        assert_eq!(stats.plugins["Y Bar"].draw.max_ms, 40.0);
+    }
+}
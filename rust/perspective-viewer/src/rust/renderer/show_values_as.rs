@@ -0,0 +1,1144 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Viewer-side evaluation of the `ShowValuesAs` column transforms the engine
+//! does not compute, e.g. a running total or rank within a group.  The plugin
+//! is given a wrapper of the `View` whose data accessors return the
+//! transformed values, computed from only the window of rows requested and
+//! the rows each transform of it depends on.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::config::*;
+use crate::js::perspective::*;
+use crate::utils::*;
+use crate::*;
+
+/// The number of rows fetched at a time when searching a `View` for the
+/// bounds of the sibling groups of a window of rows.
+const CONTEXT_BLOCK_ROWS: u32 = 256;
+
+/// Derived data of a `View` which is valid until the `View` next updates.
+#[derive(Default)]
+struct TransformCache {
+    generation: Cell<u64>,
+
+    /// The total of each `to_columns()` key, and whether every key's total
+    /// is cached.
+    totals: RefCell<HashMap<String, Option<f64>>>,
+    has_all_totals: Cell<bool>,
+
+    /// The `[min, max]` of each transformed column name in the window
+    /// `to_columns()` last returned, which is kept across updates until the
+    /// plugin next reads that column.
+    min_max: RefCell<HashMap<String, (f64, f64)>>,
+}
+
+/// The `start_col` and `end_col` options of a `to_columns()` call, both
+/// `None` for every column.
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+struct ColumnWindow {
+    start: Option<u32>,
+    end: Option<u32>,
+}
+
+/// A contiguous block of a `View`'s rows, read as numbers.
+#[derive(Default)]
+struct RowBlock {
+    /// The row path length of each row, or `None` if the `View` has no row
+    /// paths.
+    depths: Option<Vec<u32>>,
+
+    /// Each column's values, by `to_columns()` key.
+    columns: Vec<(String, Vec<Option<f64>>)>,
+}
+
+#[derive(Clone)]
+struct TransformContext {
+    view: JsValue,
+    transforms: Rc<HashMap<String, ShowValuesAs>>,
+
+    /// The `View`'s column names, which `to_columns()` keys may prefix with a
+    /// `split_by` path.
+    columns: Rc<Vec<String>>,
+
+    /// The `View`'s `group_by` columns and their `Table` types, which
+    /// `to_csv()` and `to_arrow()` emit as `"Name (Group by N)"` columns.
+    group_by: Rc<Vec<(String, Type)>>,
+    cache: Rc<TransformCache>,
+}
+
+/// A `View` wrapped so that its data accessors reflect a set of `ShowValuesAs`
+/// transforms.
+pub struct TransformedView {
+    ctx: TransformContext,
+    wrapper: JsPerspectiveView,
+}
+
+impl TransformedView {
+    /// Wrap a `View` so that its `to_columns()`, `to_columns_string()`,
+    /// `to_json()`, `to_csv()`, `to_arrow()`, `schema()` and `get_min_max()`
+    /// methods reflect `transforms`.  All other properties are inherited from
+    /// `view`.
+    ///
+    /// # Arguments
+    /// - `view` The `View` to wrap.
+    /// - `transforms` The `ShowValuesAs` transform for each column name.
+    /// - `columns` The `View`'s column names.
+    /// - `group_by` The `View`'s `group_by` column names and types.
+    pub fn new(
+        view: &JsPerspectiveView,
+        transforms: HashMap<String, ShowValuesAs>,
+        columns: Vec<String>,
+        group_by: Vec<(String, Type)>,
+    ) -> Self {
+        let ctx = TransformContext {
+            view: view.unchecked_ref::<JsValue>().clone(),
+            transforms: Rc::new(transforms),
+            columns: Rc::new(columns),
+            group_by: Rc::new(group_by),
+            cache: Rc::default(),
+        };
+
+        let wrapper = ctx.create_wrapper();
+        Self { ctx, wrapper }
+    }
+
+    /// Whether this wraps `view` with exactly `transforms`.
+    pub fn is_for(
+        &self,
+        view: &JsPerspectiveView,
+        transforms: &HashMap<String, ShowValuesAs>,
+    ) -> bool {
+        view.unchecked_ref::<JsValue>() == &self.ctx.view && *self.ctx.transforms == *transforms
+    }
+
+    /// Whether this wraps `view`, regardless of its transforms.
+    pub fn is_for_view(&self, view: &JsPerspectiveView) -> bool {
+        view.unchecked_ref::<JsValue>() == &self.ctx.view
+    }
+
+    /// Discard the data cached from the `View`, e.g. when it updates.
+    pub fn invalidate(&self) {
+        let cache = &self.ctx.cache;
+        cache.generation.set(cache.generation.get() + 1);
+        cache.totals.borrow_mut().clear();
+        cache.has_all_totals.set(false);
+    }
+
+    pub fn get_view(&self) -> JsPerspectiveView {
+        self.wrapper
+            .unchecked_ref::<JsValue>()
+            .clone()
+            .unchecked_into()
+    }
+}
+
+impl TransformContext {
+    fn create_wrapper(&self) -> JsPerspectiveView {
+        let wrapper = js_sys::Object::create(self.view.unchecked_ref());
+        let define = |name: &str, method: JsValue| {
+            js_sys::Reflect::set(&wrapper, &name.into(), &method).unwrap();
+        };
+
+        define("to_columns", {
+            let ctx = self.clone();
+            (move |options: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(
+                    async move { ctx.to_columns(&options).await },
+                ))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        define("to_columns_string", {
+            let ctx = self.clone();
+            (move |options: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(async move {
+                    let columns = ctx.to_columns(&options).await?;
+                    Ok(js_sys::JSON::stringify(&columns)?)
+                }))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        define("to_json", {
+            let ctx = self.clone();
+            (move |options: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(async move {
+                    let columns = ctx.to_columns(&options).await?;
+                    Ok(columns_to_rows(&columns))
+                }))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        define("to_csv", {
+            let ctx = self.clone();
+            (move |options: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(async move {
+                    let (table, view) = ctx.to_flat_view(&options).await?;
+                    let csv = view.to_csv(js_sys::Object::new()).await;
+                    view.delete().await?;
+                    table.delete().await?;
+                    csv
+                }))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        define("to_arrow", {
+            let ctx = self.clone();
+            (move |options: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(async move {
+                    let (table, view) = ctx.to_flat_view(&options).await?;
+                    let arrow = view.to_arrow().await;
+                    view.delete().await?;
+                    table.delete().await?;
+                    arrow
+                }))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        define("schema", {
+            let ctx = self.clone();
+            (move |_: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(async move { ctx.schema().await }))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        define("get_min_max", {
+            let ctx = self.clone();
+            (move |name: JsValue| {
+                clone!(ctx);
+                js_sys::Promise::from(ApiFuture::new(async move {
+                    match name.as_string().filter(|x| ctx.transforms.contains_key(x)) {
+                        Some(name) => Ok(ctx.get_min_max(&name)),
+                        None => {
+                            let method = js_sys::Reflect::get(&ctx.view, &"get_min_max".into())?;
+                            let promise = method
+                                .unchecked_into::<js_sys::Function>()
+                                .call1(&ctx.view, &name)?;
+
+                            Ok(JsFuture::from(promise.unchecked_into::<js_sys::Promise>()).await?)
+                        }
+                    }
+                }))
+            })
+            .into_closure()
+            .into_js_value()
+        });
+
+        wrapper.unchecked_into()
+    }
+
+    fn view(&self) -> &JsPerspectiveView {
+        self.view.unchecked_ref()
+    }
+
+    /// The `View`'s schema, with the types of transformed columns replaced.
+    async fn schema(&self) -> ApiResult<js_sys::Object> {
+        let schema = self
+            .view()
+            .schema()
+            .await?
+            .unchecked_into::<js_sys::Object>();
+        for (name, transform) in self.transforms.iter() {
+            let view_type = js_sys::Reflect::get(&schema, &name.into())?;
+            if let Some(view_type) = view_type.as_string() {
+                let new_type = transform.view_type(&view_type);
+                js_sys::Reflect::set(&schema, &name.into(), &new_type.into())?;
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// `to_columns()` with `transforms` applied to the window of rows and
+    /// columns `options` selects.  Only this window, and the rows and totals
+    /// its transforms depend on, are read from the `View`.
+    async fn to_columns(&self, options: &JsValue) -> ApiResult<js_sys::Object> {
+        let full_options = js_sys::Object::new();
+        if options.is_object() {
+            js_sys::Object::assign(&full_options, options.unchecked_ref());
+        }
+
+        let get_option = |name: &str| {
+            js_sys::Reflect::get(&full_options, &name.into())
+                .ok()
+                .and_then(|x| x.as_f64())
+                .map(|x| x as u32)
+        };
+
+        let start_row = get_option("start_row").unwrap_or(0);
+        let cols = ColumnWindow {
+            start: get_option("start_col"),
+            end: get_option("end_col"),
+        };
+
+        let is_formatted = js_sys::Reflect::get(&full_options, &"formatted".into())?.is_truthy();
+        let columns = self.view().to_columns_with_options(full_options).await?;
+        let mut window = RowBlock::from_columns(&columns)?;
+        let transforms = window
+            .columns
+            .iter()
+            .filter_map(|(key, _)| self.transforms.get(self.leaf_name(key)?))
+            .copied()
+            .collect::<HashSet<_>>();
+
+        if transforms.is_empty() {
+            return Ok(columns);
+        }
+
+        let end_row = start_row + window.len() as u32;
+        if is_formatted {
+            window = self.fetch_rows(start_row, Some(end_row), cols).await?;
+        }
+
+        let window_depths = window.depths.clone();
+        let has_total_row = window_depths.is_some();
+        let (offset, context) = self
+            .get_context(window, start_row, cols, &transforms)
+            .await?;
+        let row_context = match cols {
+            _ if !transforms.contains(&ShowValuesAs::PctOfRow) => None,
+            ColumnWindow {
+                start: None,
+                end: None,
+            } => None,
+            _ => Some(
+                self.fetch_rows(start_row, Some(end_row), ColumnWindow::default())
+                    .await?,
+            ),
+        };
+
+        let totals = if transforms.iter().any(|x| {
+            matches!(
+                x,
+                ShowValuesAs::PctOfColumn
+                    | ShowValuesAs::PctOfGrandTotal
+                    | ShowValuesAs::PctOfParent
+            )
+        }) {
+            let all = transforms.contains(&ShowValuesAs::PctOfGrandTotal);
+            self.get_totals(has_total_row, cols, &context, all).await?
+        } else {
+            HashMap::new()
+        };
+
+        let mut min_max: HashMap<&str, (f64, f64)> = HashMap::new();
+        for (key, _) in context.columns.iter() {
+            let Some(name) = self.leaf_name(key) else {
+                continue;
+            };
+
+            let Some(transform) = self.transforms.get(name) else {
+                continue;
+            };
+
+            let (block, block_offset) = match &row_context {
+                Some(row_context) if *transform == ShowValuesAs::PctOfRow => {
+                    (row_context, start_row)
+                }
+                _ => (&context, offset),
+            };
+
+            let output = block.transform(*transform, key, &totals, |x| {
+                self.leaf_name(x) == Some(name)
+            });
+
+            let window_range =
+                (start_row - block_offset) as usize..(end_row - block_offset) as usize;
+            let output = &output[window_range];
+            for (idx, value) in output.iter().enumerate() {
+                let is_total = window_depths.as_ref().map_or(false, |x| x[idx] == 0);
+                if let Some(value) = value.filter(|_| !is_total) {
+                    let entry = min_max.entry(name).or_insert((value, value));
+                    *entry = (entry.0.min(value), entry.1.max(value));
+                }
+            }
+
+            let array = output
+                .iter()
+                .map(|x| x.map(JsValue::from).unwrap_or(JsValue::NULL))
+                .collect::<js_sys::Array>();
+
+            js_sys::Reflect::set(&columns, &key.into(), &array)?;
+        }
+
+        let mut cache = self.cache.min_max.borrow_mut();
+        cache.extend(min_max.into_iter().map(|(name, x)| (name.to_owned(), x)));
+        Ok(columns)
+    }
+
+    /// Read the rows `start..end` (or to the last row, if `end` is `None`) of
+    /// the columns `cols` of the `View`.
+    async fn fetch_rows(
+        &self,
+        start: u32,
+        end: Option<u32>,
+        cols: ColumnWindow,
+    ) -> ApiResult<RowBlock> {
+        if end.map_or(false, |end| start >= end) {
+            return Ok(RowBlock::default());
+        }
+
+        let options = js_sys::Object::new();
+        js_sys::Reflect::set(&options, &"start_row".into(), &start.into())?;
+        let window = [
+            ("end_row", end),
+            ("start_col", cols.start),
+            ("end_col", cols.end),
+        ];
+        for (name, value) in window {
+            if let Some(value) = value {
+                js_sys::Reflect::set(&options, &name.into(), &value.into())?;
+            }
+        }
+
+        let columns = self.view().to_columns_with_options(options).await?;
+        RowBlock::from_columns(&columns)
+    }
+
+    /// Extend `window`, the rows from `start`, to the rows its `transforms`
+    /// depend on, returning the first row of the result.  Within a `View`'s
+    /// row paths, this is back to the parent of the window's shallowest rows
+    /// for transforms which depend on all (earlier) siblings, and forward to
+    /// the end of their sibling group for "rank", but for a flat `View` it is
+    /// the whole column.
+    async fn get_context(
+        &self,
+        mut window: RowBlock,
+        start: u32,
+        cols: ColumnWindow,
+        transforms: &HashSet<ShowValuesAs>,
+    ) -> ApiResult<(u32, RowBlock)> {
+        let end = start + window.len() as u32;
+        let by_siblings = transforms.iter().any(|x| {
+            matches!(
+                x,
+                ShowValuesAs::RunningTotal | ShowValuesAs::Rank | ShowValuesAs::PctOfParent
+            )
+        });
+
+        let by_previous = transforms
+            .iter()
+            .any(|x| matches!(x, ShowValuesAs::Difference | ShowValuesAs::PctChange));
+
+        let is_rank = transforms.contains(&ShowValuesAs::Rank);
+        let Some(depths) = &window.depths else {
+            let lo = if transforms.contains(&ShowValuesAs::RunningTotal) || is_rank {
+                0
+            } else if by_previous {
+                start.saturating_sub(1)
+            } else {
+                start
+            };
+
+            let mut context = self.fetch_rows(lo, Some(start), cols).await?;
+            context.append(window);
+            if is_rank {
+                context.append(self.fetch_rows(end, None, cols).await?);
+            }
+
+            return Ok((lo, context));
+        };
+
+        // The total row is a group of its own.
+        let Some(min_depth) = depths.iter().copied().filter(|x| *x > 0).min() else {
+            return Ok((start, window));
+        };
+
+        let mut lo = start;
+        if by_siblings || by_previous {
+            let is_bound = |depth: u32| depth < min_depth || (!by_siblings && depth == min_depth);
+            while lo > 0 {
+                let block_start = lo.saturating_sub(CONTEXT_BLOCK_ROWS);
+                let mut block = self.fetch_rows(block_start, Some(lo), cols).await?;
+                let bound = block
+                    .depths
+                    .as_ref()
+                    .and_then(|x| x.iter().rposition(|x| is_bound(*x)));
+
+                if let Some(idx) = bound {
+                    let mut tail = block.split_off(idx);
+                    tail.append(window);
+                    window = tail;
+                    lo = block_start + idx as u32;
+                    break;
+                }
+
+                block.append(window);
+                window = block;
+                lo = block_start;
+            }
+        }
+
+        if is_rank {
+            let mut hi = end;
+            loop {
+                let mut block = self
+                    .fetch_rows(hi, Some(hi + CONTEXT_BLOCK_ROWS), cols)
+                    .await?;
+
+                let len = block.len() as u32;
+                let bound = block
+                    .depths
+                    .as_ref()
+                    .and_then(|x| x.iter().position(|x| *x < min_depth));
+
+                if let Some(idx) = bound {
+                    block.split_off(idx);
+                    window.append(block);
+                    break;
+                }
+
+                window.append(block);
+                hi += len;
+                if len < CONTEXT_BLOCK_ROWS {
+                    break;
+                }
+            }
+        }
+
+        Ok((lo, window))
+    }
+
+    /// The total of each `to_columns()` key of `context` (or of every key of
+    /// the `View`, if `all`), which is the first row of a `View` with row
+    /// paths or the sum of the column otherwise, from the cache if the `View`
+    /// has not updated since they were read.
+    async fn get_totals(
+        &self,
+        has_total_row: bool,
+        cols: ColumnWindow,
+        context: &RowBlock,
+        all: bool,
+    ) -> ApiResult<HashMap<String, Option<f64>>> {
+        let is_cached = self.cache.has_all_totals.get()
+            || (!all && {
+                let totals = self.cache.totals.borrow();
+                context
+                    .columns
+                    .iter()
+                    .all(|(key, _)| totals.contains_key(key))
+            });
+
+        if is_cached {
+            return Ok(self.cache.totals.borrow().clone());
+        }
+
+        let generation = self.cache.generation.get();
+        let cols = if all { ColumnWindow::default() } else { cols };
+        let end = has_total_row.then_some(1);
+        let block = self.fetch_rows(0, end, cols).await?;
+        let totals = block.columns.into_iter().map(|(key, column)| {
+            let total = if has_total_row {
+                column.first().copied().flatten()
+            } else {
+                Some(column.iter().flatten().sum())
+            };
+
+            (key, total)
+        });
+
+        if self.cache.generation.get() != generation {
+            return Ok(totals.collect());
+        }
+
+        self.cache.totals.borrow_mut().extend(totals);
+        self.cache.has_all_totals.set(all);
+        Ok(self.cache.totals.borrow().clone())
+    }
+
+    /// Load the transformed (and windowed) columns into a temporary flat
+    /// `Table` on the `View`'s worker, so the engine can encode them as CSV or
+    /// Arrow with the same column names and types as the `View` would.  The
+    /// caller must `delete()` the returned `View` and `Table`.
+    async fn to_flat_view(
+        &self,
+        options: &JsValue,
+    ) -> ApiResult<(JsPerspectiveTable, JsPerspectiveView)> {
+        let worker = js_sys::Reflect::get(&self.view, &"_worker".into())?;
+        if worker.is_undefined() {
+            return Err("Exporting \"show values as\" columns requires a worker".into());
+        }
+
+        let worker = worker.unchecked_into::<JsPerspectiveWorker>();
+        let columns = self.to_columns(options).await?;
+        let view_schema = self.schema().await?;
+        let row_paths = get_row_paths(&columns)?;
+        let schema = js_sys::Object::new();
+        let data = js_sys::Object::new();
+        let names = js_sys::Array::new();
+        if let Some(row_paths) = &row_paths {
+            for (idx, (name, ty)) in self.group_by.iter().enumerate() {
+                let name = format!("{} (Group by {})", name, idx + 1);
+                let values = row_paths
+                    .iter()
+                    .map(|x| x.get(idx as u32))
+                    .map(|x| if x.is_undefined() { JsValue::NULL } else { x })
+                    .collect::<js_sys::Array>();
+
+                js_sys::Reflect::set(&schema, &name.clone().into(), &ty.to_string().into())?;
+                js_sys::Reflect::set(&data, &name.clone().into(), &values)?;
+                names.push(&name.into());
+            }
+        }
+
+        for key in js_sys::Object::keys(&columns).iter() {
+            let key_str = key.as_string().unwrap_or_default();
+            let Some(name) = self.leaf_name(&key_str).filter(|_| !key_str.starts_with("__")) else {
+                continue;
+            };
+
+            let ty = js_sys::Reflect::get(&view_schema, &name.into())?;
+            js_sys::Reflect::set(&schema, &key, &ty)?;
+            js_sys::Reflect::set(&data, &key, &js_sys::Reflect::get(&columns, &key)?)?;
+            names.push(&key);
+        }
+
+        let table = worker.table(schema).await?;
+        table.update(&data)?;
+        let config = js_sys::Object::new();
+        js_sys::Reflect::set(&config, &"columns".into(), &names)?;
+        let view = table.view(config.unchecked_ref()).await?;
+        Ok((table, view))
+    }
+
+    /// The `[min, max]` of a transformed column name in the window last read
+    /// by the plugin, excluding the total row.
+    fn get_min_max(&self, name: &str) -> JsValue {
+        let cache = self.cache.min_max.borrow();
+        let (min, max) = cache.get(name).copied().unwrap_or((0.0, 0.0));
+        js_sys::Array::of2(&min.into(), &max.into()).into()
+    }
+
+    fn leaf_name(&self, key: &str) -> Option<&str> {
+        leaf_name(key, &self.columns)
+    }
+}
+
+impl RowBlock {
+    fn from_columns(columns: &js_sys::Object) -> ApiResult<Self> {
+        let depths = get_row_paths(columns)?.map(|x| x.iter().map(|x| x.length()).collect());
+        let mut values = vec![];
+        for key in js_sys::Object::keys(columns).iter() {
+            let key = key.as_string().unwrap_or_default();
+            if !key.starts_with("__") {
+                let column = js_sys::Reflect::get(columns, &key.clone().into())?
+                    .unchecked_into::<js_sys::Array>();
+
+                values.push((key, column.iter().map(|x| x.as_f64()).collect()));
+            }
+        }
+
+        Ok(Self {
+            depths,
+            columns: values,
+        })
+    }
+
+    fn len(&self) -> usize {
+        match &self.depths {
+            Some(depths) => depths.len(),
+            None => self.columns.first().map_or(0, |(_, x)| x.len()),
+        }
+    }
+
+    /// Split this block at row `idx`, returning the rows from `idx` on.
+    fn split_off(&mut self, idx: usize) -> Self {
+        Self {
+            depths: self.depths.as_mut().map(|x| x.split_off(idx)),
+            columns: self
+                .columns
+                .iter_mut()
+                .map(|(key, column)| (key.clone(), column.split_off(idx.min(column.len()))))
+                .collect(),
+        }
+    }
+
+    /// Append the rows of `other`, a block of the same columns.
+    fn append(&mut self, other: Self) {
+        if self.columns.is_empty() && self.depths.is_none() {
+            *self = other;
+            return;
+        }
+
+        if let (Some(depths), Some(other)) = (&mut self.depths, other.depths) {
+            depths.extend(other);
+        }
+
+        for ((_, column), (_, other)) in self.columns.iter_mut().zip(other.columns) {
+            column.extend(other);
+        }
+    }
+
+    /// Transform the column `key` of this block.
+    ///
+    /// # Arguments
+    /// - `transform` The transform to apply.
+    /// - `key` The `to_columns()` key of the column.
+    /// - `totals` The total of each `to_columns()` key, if `transform` depends
+    ///   on them.
+    /// - `is_same_leaf` Whether a `to_columns()` key is for column `name`.
+    fn transform(
+        &self,
+        transform: ShowValuesAs,
+        key: &str,
+        totals: &HashMap<String, Option<f64>>,
+        is_same_leaf: impl Fn(&str) -> bool,
+    ) -> Vec<Option<f64>> {
+        let parents = match &self.depths {
+            Some(depths) => get_parents(depths),
+            None => vec![None; self.len()],
+        };
+
+        let depths = self.depths.clone().unwrap_or_else(|| vec![0; self.len()]);
+        let parent_keys = parents.iter().copied().zip(depths).collect::<Vec<_>>();
+        let siblings = get_sibling_groups(&parent_keys);
+        let same_leaf = self
+            .columns
+            .iter()
+            .filter(|(x, _)| is_same_leaf(x))
+            .map(|(_, x)| x.as_slice())
+            .collect::<Vec<_>>();
+
+        let grand_total = totals
+            .iter()
+            .filter(|(x, _)| is_same_leaf(x))
+            .filter_map(|(_, x)| *x)
+            .sum();
+
+        let column = self
+            .columns
+            .iter()
+            .find(|(x, _)| x == key)
+            .map(|(_, x)| x.as_slice())
+            .unwrap_or_default();
+
+        let column_total = totals.get(key).copied().flatten();
+        transform_column(
+            transform,
+            column,
+            &same_leaf,
+            &siblings,
+            &parents,
+            column_total,
+            Some(grand_total),
+        )
+    }
+}
+
+/// The column name for a `to_columns()` key, which is prefixed by its
+/// `split_by` path.  Column names may themselves contain the `|` separator,
+/// so this is the longest of `columns` which `key` is or ends with.
+fn leaf_name<'a>(key: &str, columns: &'a [String]) -> Option<&'a str> {
+    columns
+        .iter()
+        .filter(|name| {
+            key.strip_suffix(name.as_str())
+                .map_or(false, |path| path.is_empty() || path.ends_with('|'))
+        })
+        .max_by_key(|name| name.len())
+        .map(|name| name.as_str())
+}
+
+fn get_row_paths(columns: &js_sys::Object) -> ApiResult<Option<Vec<js_sys::Array>>> {
+    let row_paths = js_sys::Reflect::get(columns, &"__ROW_PATH__".into())?;
+    Ok(row_paths.dyn_into::<js_sys::Array>().ok().map(|row_paths| {
+        row_paths
+            .iter()
+            .map(|x| x.unchecked_into::<js_sys::Array>())
+            .collect()
+    }))
+}
+
+/// Transform one column of a block of rows.
+///
+/// # Arguments
+/// - `transform` The transform to apply.
+/// - `column` The column's values.
+/// - `same_leaf` Every column (including `column`) for the same column name,
+///   one per `split_by` path.
+/// - `siblings` The row indices of each group of rows with the same parent.
+/// - `parents` The row index of each row's parent, if it is in the block.
+/// - `column_total` The total of `column`.
+/// - `grand_total` The total of every column in `same_leaf`.
+fn transform_column(
+    transform: ShowValuesAs,
+    column: &[Option<f64>],
+    same_leaf: &[&[Option<f64>]],
+    siblings: &[Vec<usize>],
+    parents: &[Option<usize>],
+    column_total: Option<f64>,
+    grand_total: Option<f64>,
+) -> Vec<Option<f64>> {
+    let pct = |value: Option<f64>, denom: Option<f64>| match (value, denom) {
+        (Some(value), Some(denom)) if denom != 0.0 => Some(value / denom * 100.0),
+        _ => None,
+    };
+
+    let mut output = vec![None; column.len()];
+    match transform {
+        ShowValuesAs::PctOfRow => {
+            for (idx, value) in column.iter().enumerate() {
+                let denom = same_leaf.iter().filter_map(|x| x[idx]).sum();
+                output[idx] = pct(*value, Some(denom));
+            }
+        }
+        ShowValuesAs::PctOfColumn => {
+            for (idx, value) in column.iter().enumerate() {
+                output[idx] = pct(*value, column_total);
+            }
+        }
+        ShowValuesAs::PctOfGrandTotal => {
+            for (idx, value) in column.iter().enumerate() {
+                output[idx] = pct(*value, grand_total);
+            }
+        }
+        ShowValuesAs::PctOfParent => {
+            for (idx, value) in column.iter().enumerate() {
+                let denom = parents[idx].map_or(column_total, |parent| column[parent]);
+                output[idx] = pct(*value, denom);
+            }
+        }
+        ShowValuesAs::RunningTotal => {
+            for group in siblings.iter() {
+                let mut acc = None;
+                for idx in group {
+                    if let Some(value) = column[*idx] {
+                        acc = Some(acc.unwrap_or(0.0) + value);
+                    }
+
+                    output[*idx] = acc;
+                }
+            }
+        }
+        ShowValuesAs::Rank => {
+            for group in siblings.iter() {
+                let mut ranked = group
+                    .iter()
+                    .filter_map(|idx| column[*idx].map(|value| (*idx, value)))
+                    .collect::<Vec<_>>();
+
+                ranked.sort_by(|(_, x), (_, y)| y.total_cmp(x));
+                let mut prev: Option<(f64, f64)> = None;
+                for (position, (idx, value)) in ranked.into_iter().enumerate() {
+                    let rank = match prev {
+                        Some((prev_value, prev_rank)) if prev_value == value => prev_rank,
+                        _ => (position + 1) as f64,
+                    };
+
+                    output[idx] = Some(rank);
+                    prev = Some((value, rank));
+                }
+            }
+        }
+        ShowValuesAs::Difference | ShowValuesAs::PctChange => {
+            for group in siblings.iter() {
+                for (prev, idx) in group.iter().zip(group.iter().skip(1)) {
+                    let diff = column[*idx].zip(column[*prev]).map(|(x, y)| x - y);
+                    output[*idx] = match transform {
+                        ShowValuesAs::Difference => diff,
+                        _ => pct(diff, column[*prev].map(f64::abs)),
+                    };
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// The index of each row's parent in a block of rows, or `None` if it
+/// precedes the block.
+///
+/// # Arguments
+/// - `depths` The row path length of each row.
+fn get_parents(depths: &[u32]) -> Vec<Option<usize>> {
+    let mut ancestors: Vec<usize> = vec![];
+    depths
+        .iter()
+        .enumerate()
+        .map(|(idx, depth)| {
+            while let Some(last) = ancestors.last() && depths[*last] >= *depth {
+                ancestors.pop();
+            }
+
+            let parent = ancestors.last().copied();
+            ancestors.push(idx);
+            parent
+        })
+        .collect()
+}
+
+/// Group row indices by their parent, in row order.
+///
+/// # Arguments
+/// - `parents` A key for each row's parent, which includes its depth.
+fn get_sibling_groups<T: Eq + Hash>(parents: &[T]) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = vec![];
+    let mut lookup: HashMap<&T, usize> = HashMap::new();
+    for (idx, parent) in parents.iter().enumerate() {
+        let group = *lookup.entry(parent).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+
+        groups[group].push(idx);
+    }
+
+    groups
+}
+
+/// Convert a `to_columns()` result to the row-oriented `to_json()` format.
+fn columns_to_rows(columns: &js_sys::Object) -> JsValue {
+    let keys = js_sys::Object::keys(columns);
+    let arrays = keys
+        .iter()
+        .map(|key| {
+            let column = js_sys::Reflect::get(columns, &key).unwrap();
+            (key, column.unchecked_into::<js_sys::Array>())
+        })
+        .collect::<Vec<_>>();
+
+    let num_rows = arrays.first().map(|(_, x)| x.length()).unwrap_or(0);
+    (0..num_rows)
+        .map(|idx| {
+            let row = js_sys::Object::new();
+            for (key, column) in arrays.iter() {
+                js_sys::Reflect::set(&row, key, &column.get(idx)).unwrap();
+            }
+
+            row
+        })
+        .collect::<js_sys::Array>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn transform(
+        transform: ShowValuesAs,
+        columns: &[&[Option<f64>]],
+        parents: &[&str],
+    ) -> Vec<Option<f64>> {
+        let siblings = get_sibling_groups(parents);
+        let has_total_row = parents.first().map_or(false, |x| x.starts_with("0:"));
+        let total = |column: &[Option<f64>]| match has_total_row {
+            true => column[0],
+            false => Some(column.iter().flatten().sum()),
+        };
+
+        let grand_total = columns.iter().filter_map(|x| total(x)).sum();
+        let no_parents = vec![None; columns[0].len()];
+        transform_column(
+            transform,
+            columns[0],
+            columns,
+            &siblings,
+            &no_parents,
+            total(columns[0]),
+            Some(grand_total),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    fn test_leaf_name() {
+        let columns = vec!["Sales".to_owned(), "a|b".to_owned(), "b".to_owned()];
+        assert_eq!(leaf_name("Sales", &columns), Some("Sales"));
+        assert_eq!(leaf_name("West|Sales", &columns), Some("Sales"));
+        assert_eq!(leaf_name("West|a|b", &columns), Some("a|b"));
+        assert_eq!(leaf_name("a|b", &columns), Some("a|b"));
+        assert_eq!(leaf_name("West|xb", &columns), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_parents() {
+        let depths = [0, 1, 2, 2, 1, 2, 3, 1];
+        assert_eq!(get_parents(&depths), vec![
+            None,
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(0),
+            Some(4),
+            Some(5),
+            Some(0)
+        ]);
+
+        // A block which starts within a group.
+        assert_eq!(get_parents(&[2, 3, 2, 1, 2]), vec![
+            None,
+            Some(0),
+            None,
+            None,
+            Some(3)
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_sibling_groups() {
+        let parents = [
+            "0:[]",
+            "1:[]",
+            "2:[\"a\"]",
+            "2:[\"a\"]",
+            "1:[]",
+            "2:[\"b\"]",
+        ]
+        .map(|x| x.to_owned());
+
+        assert_eq!(get_sibling_groups(&parents), vec![
+            vec![0],
+            vec![1, 4],
+            vec![2, 3],
+            vec![5]
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pct_of_row() {
+        let x = [Some(1.0), Some(3.0)];
+        let y = [Some(3.0), None];
+        let result = transform(ShowValuesAs::PctOfRow, &[&x, &y], &["1:[]", "1:[]"]);
+        assert_eq!(result, vec![Some(25.0), Some(100.0)]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pct_of_column() {
+        let x = [Some(4.0), Some(1.0), Some(3.0)];
+        let result = transform(ShowValuesAs::PctOfColumn, &[&x], &["0:[]", "1:[]", "1:[]"]);
+        assert_eq!(result, vec![Some(100.0), Some(25.0), Some(75.0)]);
+
+        let flat = [Some(1.0), Some(3.0), None];
+        let result = transform(ShowValuesAs::PctOfColumn, &[&flat], &[
+            "1:[]", "1:[]", "1:[]",
+        ]);
+        assert_eq!(result, vec![Some(25.0), Some(75.0), None]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pct_of_grand_total() {
+        let x = [Some(4.0), Some(1.0), Some(3.0)];
+        let y = [Some(4.0), Some(4.0), None];
+        let result = transform(ShowValuesAs::PctOfGrandTotal, &[&x, &y], &[
+            "0:[]", "1:[]", "1:[]",
+        ]);
+
+        assert_eq!(result, vec![Some(50.0), Some(12.5), Some(37.5)]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pct_of_parent() {
+        let x = [Some(8.0), Some(2.0), Some(1.0), Some(1.0), Some(6.0)];
+        let depths = [0, 1, 2, 2, 1];
+        let parents = get_parents(&depths);
+        let siblings = get_sibling_groups(&parents.iter().zip(depths).collect::<Vec<_>>());
+        let result = transform_column(
+            ShowValuesAs::PctOfParent,
+            &x,
+            &[&x],
+            &siblings,
+            &parents,
+            Some(8.0),
+            Some(8.0),
+        );
+
+        assert_eq!(result, vec![
+            Some(100.0),
+            Some(25.0),
+            Some(50.0),
+            Some(50.0),
+            Some(75.0)
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_running_total() {
+        let x = [Some(10.0), Some(1.0), Some(2.0), None, Some(3.0)];
+        let parents = ["0:[]", "1:[]", "1:[]", "1:[]", "1:[]"];
+        let result = transform(ShowValuesAs::RunningTotal, &[&x], &parents);
+        assert_eq!(result, vec![
+            Some(10.0),
+            Some(1.0),
+            Some(3.0),
+            Some(3.0),
+            Some(6.0)
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_rank() {
+        let x = [Some(10.0), Some(2.0), Some(5.0), None, Some(2.0), Some(1.0)];
+        let parents = ["0:[]", "1:[]", "1:[]", "1:[]", "1:[]", "1:[]"];
+        let result = transform(ShowValuesAs::Rank, &[&x], &parents);
+        assert_eq!(result, vec![
+            Some(1.0),
+            Some(2.0),
+            Some(1.0),
+            None,
+            Some(2.0),
+            Some(4.0)
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_rank_within_parent() {
+        let x = [Some(1.0), Some(3.0), Some(2.0), Some(5.0)];
+        let parents = ["2:[\"a\"]", "2:[\"a\"]", "2:[\"b\"]", "2:[\"b\"]"];
+        let result = transform(ShowValuesAs::Rank, &[&x], &parents);
+        assert_eq!(result, vec![Some(2.0), Some(1.0), Some(2.0), Some(1.0)]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_difference() {
+        let x = [Some(2.0), Some(5.0), None, Some(4.0)];
+        let parents = ["1:[]", "1:[]", "1:[]", "1:[]"];
+        let result = transform(ShowValuesAs::Difference, &[&x], &parents);
+        assert_eq!(result, vec![None, Some(3.0), None, None]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_pct_change() {
+        let x = [Some(-2.0), Some(1.0), Some(0.0), Some(4.0)];
+        let parents = ["1:[]", "1:[]", "1:[]", "1:[]"];
+        let result = transform(ShowValuesAs::PctChange, &[&x], &parents);
+        assert_eq!(result, vec![None, Some(150.0), Some(-100.0), None]);
+    }
+}
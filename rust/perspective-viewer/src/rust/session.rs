@@ -10,15 +10,31 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod aggregate_update;
 mod column_defaults_update;
+mod column_values;
+mod custom_order_update;
+mod custom_orders;
 mod drag_drop_update;
+mod drill_down;
+mod drill_down_update;
+mod drill_through;
+mod having_filters;
+mod linked_filters;
 mod metadata;
+mod pause_updates;
+mod pivot_bucket_update;
+mod pivot_buckets;
+mod relative_filters;
 mod replace_expression_update;
+mod show_values_as;
+mod top_n_filters;
+mod update_rate;
 mod view;
 mod view_subscription;
 
 use std::cell::{Ref, RefCell};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::iter::IntoIterator;
 use std::ops::Deref;
 use std::rc::Rc;
@@ -28,7 +44,12 @@ use wasm_bindgen::JsCast;
 use yew::html::ImplicitClone;
 use yew::prelude::*;
 
+pub use self::column_values::{ColumnValue, ColumnValues, ColumnValuesQuery};
+pub use self::drill_through::{DrillThrough, MAX_DRILL_THROUGH_ROWS};
 use self::metadata::*;
+pub use self::relative_filters::RELATIVE_FILTER_INTERVAL_MS;
+pub use self::update_rate::UPDATE_RATE_WINDOW_MS;
+use self::update_rate::*;
 use self::view::{PerspectiveOwned, View};
 pub use self::view_subscription::ViewStats;
 use self::view_subscription::*;
@@ -56,6 +77,8 @@ pub struct SessionHandle {
     pub view_created: PubSub<()>,
     pub view_config_changed: PubSub<()>,
     pub stats_changed: PubSub<()>,
+    pub linked_filters_changed: PubSub<()>,
+    pub pending_updates_changed: PubSub<Option<u32>>,
 }
 
 /// Mutable state for `Session`.
@@ -65,8 +88,27 @@ pub struct SessionData {
     metadata: SessionMetadata,
     config: ViewConfig,
     view_sub: Option<ViewSubscription>,
+    engine_filter: Option<Vec<Filter>>,
     stats: Option<ViewStats>,
+    time_zone: Option<String>,
+    drill_down: Vec<DrillDownLevel>,
+    linked_filters: Vec<(usize, Vec<Filter>)>,
+    pending_updates: PendingUpdates,
+    update_rate: SharedUpdateRate,
     is_clean: bool,
+
+    /// When `refresh_resolved_filters()` last resolved filters, and whether a
+    /// throttled refresh is waiting to do so.
+    last_filter_refresh: f64,
+    is_filter_refresh_pending: bool,
+
+    /// The posix time relative date filters are resolved as of, see
+    /// `relative_filter_time()`.
+    relative_filter_time: Option<f64>,
+
+    /// The `show_values_as` transform of each column, some of which are
+    /// compiled into the `View`'s aggregates, see `set_show_values_as()`.
+    show_values_as: HashMap<String, ShowValuesAs>,
 }
 
 impl Deref for Session {
@@ -110,6 +152,8 @@ impl Session {
     pub fn reset(&self, reset_expressions: bool) {
         self.borrow_mut().is_clean = false;
         self.borrow_mut().view_sub = None;
+        self.borrow_mut().drill_down.clear();
+        self.borrow_mut().linked_filters.clear();
         self.borrow_mut().config.reset(reset_expressions);
     }
 
@@ -121,6 +165,7 @@ impl Session {
         self.reset(false);
         self.borrow_mut().metadata = SessionMetadata::default();
         self.borrow_mut().table = None;
+        self.borrow().update_rate.take();
         false
     }
 
@@ -140,6 +185,7 @@ impl Session {
         self.borrow_mut().view_sub = None;
         self.borrow_mut().metadata = metadata;
         self.borrow_mut().table = Some(table);
+        self.borrow().update_rate.take();
         self.table_loaded.emit_all(());
         Ok(JsValue::UNDEFINED)
     }
@@ -173,8 +219,80 @@ impl Session {
         drag: DragEffect,
         requirements: &ViewConfigRequirements,
     ) -> ViewConfigUpdate {
-        self.get_view_config()
-            .create_drag_drop_update(column, index, drop, drag, requirements)
+        let config = self.get_view_config();
+        let is_duplicate = drop == DragTarget::Active
+            && drag == DragEffect::Copy
+            && config.columns.contains(&Some(column.clone()));
+
+        match self
+            .create_aggregate_instance(&config, &column)
+            .filter(|_| is_duplicate)
+        {
+            Some((instance, aggregate)) => {
+                config.create_aggregate_instance_update(&instance, index, aggregate)
+            }
+            None => config.create_drag_drop_update(column, index, drop, drag, requirements),
+        }
+    }
+
+    /// Choose an alias and aggregate for a new `AggregateInstance` of
+    /// `column`, preferring the first aggregate not already shown for its
+    /// source column.
+    fn create_aggregate_instance(
+        &self,
+        config: &ViewConfig,
+        column: &str,
+    ) -> Option<(AggregateInstance, Aggregate)> {
+        let metadata = self.metadata();
+        let source = metadata
+            .get_expression_by_alias(column)
+            .and_then(|x| AggregateInstance::parse(&x))
+            .map(|x| x.source)
+            .unwrap_or_else(|| column.to_owned());
+
+        let source_default = metadata.get_column_default_aggregate(&source)?;
+        let in_use = config
+            .columns
+            .iter()
+            .flatten()
+            .filter_map(|x| match config.aggregates.get(x) {
+                Some(agg) => Some(agg.clone()),
+                None if *x == source => Some(source_default.clone()),
+                None => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut aggregates = metadata.get_column_aggregates(&source)?;
+        let aggregate = aggregates.find(|agg| {
+            let instance = AggregateInstance::new(&source, agg);
+            !in_use.contains(agg) && metadata.get_column_table_type(&instance.alias).is_none()
+        })?;
+
+        Some((AggregateInstance::new(&source, &aggregate), aggregate))
+    }
+
+    /// Create an update which sets the aggregate of `column`, renaming it if
+    /// it is an `AggregateInstance` whose alias was generated for its
+    /// previous aggregate.
+    pub fn create_aggregate_update(&self, column: &str, aggregate: Aggregate) -> ViewConfigUpdate {
+        let config = self.get_view_config();
+        let metadata = self.metadata();
+        let renamed = maybe!({
+            let expression = metadata.get_expression_by_alias(column)?;
+            let instance = AggregateInstance::parse(&expression)?;
+            let old_aggregate = config
+                .aggregates
+                .get(column)
+                .cloned()
+                .or_else(|| metadata.get_column_default_aggregate(column))?;
+
+            let renamed = AggregateInstance::new(&instance.source, &aggregate);
+            let is_free = metadata.get_column_table_type(&renamed.alias).is_none();
+            (instance.is_named_for(&old_aggregate) && is_free).then_some((expression, renamed))
+        });
+
+        let renamed = renamed.as_ref().map(|(x, y)| (x.as_str(), y));
+        config.create_aggregate_update(column, aggregate, renamed)
     }
 
     /// An async task which replaces a `column` aliased expression with another.
@@ -215,27 +333,6 @@ impl Session {
         }
     }
 
-    pub async fn arrow_as_vec(&self, flat: bool) -> Result<Vec<u8>, JsValue> {
-        let arrow = self.flat_as_jsvalue(flat).await?.to_arrow().await?;
-        Ok(js_sys::Uint8Array::new(&arrow).to_vec())
-    }
-
-    pub async fn arrow_as_jsvalue(self, flat: bool) -> Result<js_sys::ArrayBuffer, ApiError> {
-        self.flat_as_jsvalue(flat).await?.to_arrow().await
-    }
-
-    pub async fn json_as_jsvalue(self, flat: bool) -> Result<js_sys::Object, ApiError> {
-        self.flat_as_jsvalue(flat).await?.to_columns().await
-    }
-
-    pub async fn csv_as_jsvalue(&self, flat: bool) -> Result<js_sys::JsString, ApiError> {
-        let opts = json!({"formatted": true});
-        self.flat_as_jsvalue(flat)
-            .await?
-            .to_csv(opts.unchecked_into())
-            .await
-    }
-
     pub fn get_view(&self) -> Option<View> {
         self.borrow()
             .view_sub
@@ -251,50 +348,35 @@ impl Session {
         Ref::map(self.borrow(), |x| &x.config)
     }
 
-    /// Get all unique column values for a given column name.
-    ///
-    /// Use the `.to_csv()` method, as I suspected copying this large string
-    /// once was more efficient than copying many smaller strings, and
-    /// string copying shows up frequently when doing performance analysis.
-    ///
-    /// TODO Does not work with expressions yet.
+    /// The IANA time zone in which datetimes are entered and relative date
+    /// filters are resolved, or `None` for the browser's time zone.
+    pub fn get_time_zone(&self) -> Option<String> {
+        self.borrow().time_zone.clone()
+    }
+
+    /// Set this `Session`'s time zone, invalidating the current `View` if it
+    /// has relative date filters which must be re-resolved.  Returns `true`
+    /// if the time zone changed.
     ///
     /// # Arguments
-    /// - `column` The name of the column (or expression).
-    pub async fn get_column_values(&self, column: String) -> Result<Vec<String>, JsValue> {
-        let expressions = self.borrow().config.expressions.clone();
-        let config = ViewConfig {
-            group_by: vec![column],
-            columns: vec![],
-            expressions,
-            ..ViewConfig::default()
-        };
-
-        let js_config = config.as_jsvalue()?;
-        let table = self.borrow().table.clone().unwrap();
-        let view = table.view(&js_config).await?;
-        let csv = view
-            .to_csv(json!({}))
-            .await?
-            .as_string()
-            .ok_or_else(|| JsValue::from("Bad CSV"))?;
-
-        ApiFuture::spawn(async move {
-            view.delete().await?;
-            Ok(())
-        });
+    /// - `time_zone` An IANA time zone name, or `None` for the browser's time
+    ///   zone.
+    pub fn set_time_zone(&self, time_zone: Option<String>) -> ApiResult<bool> {
+        if let Some(tz) = &time_zone {
+            validate_time_zone(tz)?;
+        }
 
-        Ok(csv
-            .lines()
-            .map(|x| {
-                if x.len() > 1 {
-                    str::replace(&x[1..x.len() - 1], "\"\"", "\"")
-                } else {
-                    x.to_owned()
-                }
-            })
-            .skip(2)
-            .collect::<Vec<String>>())
+        if self.borrow().time_zone == time_zone {
+            return Ok(false);
+        }
+
+        self.borrow_mut().time_zone = time_zone;
+        if self.has_relative_filters() {
+            self.borrow_mut().view_sub = None;
+            self.borrow_mut().is_clean = false;
+        }
+
+        Ok(true)
     }
 
     pub fn set_update_column_defaults(
@@ -315,6 +397,7 @@ impl Session {
         if self.borrow_mut().config.apply_update(config_update) {
             self.borrow_mut().view_sub = None;
             self.0.borrow_mut().is_clean = false;
+            self.invalidate_drill_down();
             self.view_config_changed.emit_all(());
         }
     }
@@ -353,7 +436,9 @@ impl Session {
         schema_keys.get(0).as_string().into_apierror()
     }
 
-    async fn flat_as_jsvalue(&self, flat: bool) -> ApiResult<View> {
+    /// The `View` to export, which is either the current `View` or, if `flat`,
+    /// a new `View` of the whole `Table`.
+    pub async fn get_export_view(&self, flat: bool) -> ApiResult<View> {
         if flat {
             let table = self.borrow().table.clone().into_apierror()?;
             table
@@ -413,10 +498,25 @@ impl Session {
         }
 
         for column in config.columns.iter().flatten() {
-            if all_columns.contains(column) || expression_names.contains(column) {
-                let _existed = view_columns.insert(column);
-            } else {
+            if !(all_columns.contains(column) || expression_names.contains(column)) {
                 return Err(format!("Unknown \"{}\" in `columns`", column).into());
+            } else if !view_columns.insert(column) {
+                return Err(format!("Duplicate \"{}\" in `columns`", column).into());
+            }
+        }
+
+        for instance in config
+            .expressions
+            .iter()
+            .filter_map(|x| AggregateInstance::parse(x))
+        {
+            let metadata = self.metadata();
+            let source_type = metadata.get_column_table_type(&instance.source);
+            if let (Some(agg), Some(ty)) = (config.aggregates.get(&instance.alias), source_type) {
+                if !ty.is_aggregate_valid(agg) {
+                    let msg = format!("Invalid aggregate \"{}\" for \"{}\"", agg, instance.alias);
+                    return Err(msg.into());
+                }
             }
         }
 
@@ -452,6 +552,49 @@ impl Session {
             }
         }
 
+        for having in config.having.iter() {
+            if having.1.is_top_n() {
+                let msg = format!("Unsupported `having` filter \"{}\"", having.1);
+                return Err(msg.into());
+            } else if all_columns.contains(&having.0) || expression_names.contains(&having.0) {
+                let _existed = view_columns.insert(&having.0);
+            } else {
+                return Err(format!("Unknown \"{}\" in `having`", &having.0).into());
+            }
+        }
+
+        for filter in config.filter.iter().chain(config.having.iter()) {
+            if let (FilterOp::Matches, FilterTerm::Scalar(Scalar::String(pattern))) =
+                (filter.1, &filter.2)
+                && let Err(err) = regex::Regex::new(pattern)
+            {
+                let msg = format!("Invalid \"{}\" pattern \"{}\": {}", filter.1, pattern, err);
+                return Err(msg.into());
+            }
+        }
+
+        for filter in config.filter.iter() {
+            match &filter.2 {
+                FilterTerm::Relative(RelativeDateTerm(relative))
+                    if !relative.is_supported_op(filter.1) =>
+                {
+                    let msg = format!("Unsupported relative date `filter` \"{}\"", filter.1);
+                    return Err(msg.into());
+                }
+                FilterTerm::TopN(top_n) if filter.1.is_top_n() => {
+                    if !(all_columns.contains(&top_n.by) || expression_names.contains(&top_n.by)) {
+                        let msg = format!("Unknown \"{}\" in `filter` \"{}\"", top_n.by, filter.1);
+                        return Err(msg.into());
+                    }
+                }
+                _ if filter.1.is_top_n() => {
+                    let msg = format!("Missing term for `filter` \"{}\"", filter.1);
+                    return Err(msg.into());
+                }
+                _ => {}
+            }
+        }
+
         config
             .aggregates
             .retain(|column, _| view_columns.contains(column.as_str()));
@@ -460,6 +603,151 @@ impl Session {
         Ok(())
     }
 
+    /// The `ViewConfig` to create a `View` from, which sets explicitly the
+    /// default aggregate of any column whose default differs from the one
+    /// the engine would choose, translates aggregates the engine knows by
+    /// another name, replaces the aggregates of columns whose `show_values_as`
+    /// transform the engine computes, and resolves "top n" and `having`
+    /// filters.
+    async fn get_engine_view_config(&self) -> ApiResult<ViewConfig> {
+        let mut config = self.borrow().config.clone();
+        let show_values_as = self.get_engine_show_values_as(&config);
+        config.filter = self.resolve_filters(&config).await?;
+
+        if !config.group_by.is_empty() {
+            let metadata = self.metadata();
+            let defaults = config
+                .columns
+                .iter()
+                .flatten()
+                .filter(|x| !config.aggregates.contains_key(*x))
+                .filter_map(|x| {
+                    let ty = metadata.get_column_table_type(x)?;
+                    let agg = metadata.get_column_default_aggregate(x)?;
+                    (agg != ty.engine_default_aggregate()).then(|| (x.clone(), agg))
+                })
+                .collect::<Vec<_>>();
+
+            config.aggregates.extend(defaults);
+            config.aggregates = config
+                .aggregates
+                .iter()
+                .map(|(name, agg)| (name.clone(), metadata.get_engine_aggregate(name, agg)))
+                .collect();
+
+            config.aggregates.extend(show_values_as);
+        }
+
+        Ok(config)
+    }
+
+    /// The `filter` of `config` as the engine will apply it, with the filters
+    /// of this `Session`'s link group merged, and relative date, "top n" and
+    /// `having` filters resolved.
+    async fn resolve_filters(&self, config: &ViewConfig) -> ApiResult<Vec<Filter>> {
+        let mut config = config.clone();
+        for filter in self.get_linked_filters() {
+            if !config.filter.contains(&filter) {
+                config.filter.push(filter);
+            }
+        }
+
+        if config.filter.iter().any(|x| x.2.is_relative()) {
+            config.filter = self.resolve_relative_filters(&config.filter);
+        }
+
+        if config.filter.iter().any(|x| x.1.is_top_n()) {
+            config.filter = self.resolve_top_n_filters(&config).await?;
+        }
+
+        if let Some(having) = self.resolve_having_filter(&config, &config.filter).await? {
+            config.filter.push(having);
+        }
+
+        Ok(config.filter)
+    }
+
+    /// Does this `Session`'s `ViewConfig` have relative date filters, which
+    /// must be periodically re-resolved via `refresh_resolved_filters()`?
+    pub fn has_relative_filters(&self) -> bool {
+        self.borrow()
+            .config
+            .filter
+            .iter()
+            .any(|x| x.2.is_relative())
+    }
+
+    /// Re-resolve this `Session`'s "top n" and `having` filters, e.g. after
+    /// the `Table` has been updated, and invalidate the current `View` if
+    /// their resolved values have changed.  Returns `true` if the `View` must
+    /// be re-created.  Relative date filters keep the time they were resolved
+    /// as of, see `refresh_relative_filters()`.
+    ///
+    /// Resolving "top n" and `having` filters queries helper `View`s, so this
+    /// runs at most once per `interval` milliseconds;  calls within the
+    /// interval are coalesced into one refresh at its end, and return `false`.
+    ///
+    /// # Arguments
+    /// - `interval` The minimum time between refreshes, e.g. the render time.
+    pub async fn refresh_resolved_filters(&self, interval: i32) -> ApiResult<bool> {
+        let config = self.borrow().config.clone();
+        let is_resolved = config.filter.iter().any(|x| x.1.is_top_n())
+            || (!config.having.is_empty() && !config.group_by.is_empty());
+
+        if !is_resolved {
+            return Ok(false);
+        }
+
+        self.refresh_filters(interval).await
+    }
+
+    /// Re-resolve this `Session`'s filters as of the current time, which
+    /// should be called every `RELATIVE_FILTER_INTERVAL_MS`.  Returns `true`
+    /// if the `View` must be re-created, as `refresh_resolved_filters()`.
+    ///
+    /// # Arguments
+    /// - `interval` The minimum time between refreshes, e.g. the render time.
+    pub async fn refresh_relative_filters(&self, interval: i32) -> ApiResult<bool> {
+        self.borrow_mut().relative_filter_time = None;
+        if !self.has_relative_filters() {
+            return Ok(false);
+        }
+
+        self.refresh_filters(interval).await
+    }
+
+    async fn refresh_filters(&self, interval: i32) -> ApiResult<bool> {
+        if self.borrow().view_sub.is_none() {
+            return Ok(false);
+        }
+
+        if self.borrow().is_filter_refresh_pending {
+            return Ok(false);
+        }
+
+        let elapsed = js_sys::Date::now() - self.borrow().last_filter_refresh;
+        if elapsed < interval as f64 {
+            self.borrow_mut().is_filter_refresh_pending = true;
+            let result = set_timeout(interval - elapsed as i32).await;
+            self.borrow_mut().is_filter_refresh_pending = false;
+            result?;
+            if self.borrow().view_sub.is_none() {
+                return Ok(false);
+            }
+        }
+
+        self.borrow_mut().last_filter_refresh = js_sys::Date::now();
+        let config = self.borrow().config.clone();
+        let filter = self.resolve_filters(&config).await?;
+        let is_stale = self.borrow().engine_filter.as_ref() != Some(&filter);
+        if is_stale {
+            self.borrow_mut().view_sub = None;
+            self.borrow_mut().is_clean = false;
+        }
+
+        Ok(is_stale)
+    }
+
     fn reset_clean(&self) -> bool {
         let mut is_clean = true;
         std::mem::swap(&mut is_clean, &mut self.0.borrow_mut().is_clean);
@@ -475,8 +763,9 @@ impl<'a> ValidSession<'a> {
     /// `update()` subscription, consuming this `ValidSession<'_>` and returning
     /// the original `&Session`.
     pub async fn create_view(&self) -> Result<&'a Session, ApiError> {
-        let js_config = self.0.borrow().config.as_jsvalue()?;
         if !self.0.reset_clean() {
+            let engine_config = self.0.get_engine_view_config().await?;
+            let js_config = engine_config.as_jsvalue()?;
             let table = self
                 .0
                 .borrow()
@@ -493,15 +782,31 @@ impl<'a> ValidSession<'a> {
                 move |stats| this.update_stats(stats)
             });
 
+            let on_pending = Callback::from({
+                let this = self.0.clone();
+                move |count| this.pending_updates_changed.emit_all(Some(count))
+            });
+
             let sub = {
                 let config = self.0.borrow().config.clone();
+                let pending = self.0.borrow().pending_updates.clone();
+                let update_rate = self.0.borrow().update_rate.clone();
                 let on_update = self.0.table_updated.callback();
-                ViewSubscription::new(view, config, on_stats, on_update)
+                ViewSubscription::new(
+                    view,
+                    config,
+                    pending,
+                    update_rate,
+                    on_stats,
+                    on_update,
+                    on_pending,
+                )
             };
 
             // self.0.borrow_mut().metadata.as_mut().unwrap().view_schema =
             // Some(view_schema);
             self.0.borrow_mut().view_sub = Some(sub);
+            self.0.borrow_mut().engine_filter = Some(engine_config.filter);
         }
 
         Ok(self.0)
@@ -0,0 +1,199 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::config::*;
+
+impl ViewConfig {
+    /// Create an update for this `ViewConfig` which adds an `AggregateInstance`
+    /// to `columns` at `index`, e.g. when a user drops a copy of an already
+    /// active column into the active columns list.
+    ///
+    /// This method is designed to be called from `crate::session` which can
+    /// choose an `aggregate` not already in use for the instance's source.
+    pub(super) fn create_aggregate_instance_update(
+        &self,
+        instance: &AggregateInstance,
+        index: usize,
+        aggregate: Aggregate,
+    ) -> ViewConfigUpdate {
+        let Self {
+            mut columns,
+            mut expressions,
+            mut aggregates,
+            ..
+        } = self.clone();
+
+        let expression = instance.to_expression();
+        if !expressions.contains(&expression) {
+            expressions.push(expression);
+        }
+
+        let column = Some(instance.alias.clone());
+        match columns.get_mut(index) {
+            Some(x @ None) => *x = column,
+            _ => columns.insert(std::cmp::min(index, columns.len()), column),
+        }
+
+        aggregates.insert(instance.alias.clone(), aggregate);
+        ViewConfigUpdate {
+            columns: Some(columns),
+            expressions: Some(expressions),
+            aggregates: Some(aggregates),
+            ..ViewConfigUpdate::default()
+        }
+    }
+
+    /// Create an update for this `ViewConfig` which sets the aggregate of
+    /// `column`.  If `column` is an `AggregateInstance` whose alias was
+    /// generated for its previous aggregate, its expression is `renamed` (from
+    /// the old expression string to the new instance) as well.
+    pub(super) fn create_aggregate_update(
+        &self,
+        column: &str,
+        aggregate: Aggregate,
+        renamed: Option<(&str, &AggregateInstance)>,
+    ) -> ViewConfigUpdate {
+        match renamed {
+            Some((old_expression, new)) => {
+                let mut update = self.create_replace_expression_update(
+                    column,
+                    old_expression,
+                    &new.alias,
+                    &new.to_expression(),
+                );
+
+                let aggregates = update.aggregates.get_or_insert_with(Default::default);
+                aggregates.insert(new.alias.clone(), aggregate);
+                update
+            }
+            None => {
+                let mut aggregates = self.aggregates.clone();
+                aggregates.insert(column.to_owned(), aggregate);
+                ViewConfigUpdate {
+                    aggregates: Some(aggregates),
+                    ..ViewConfigUpdate::default()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn sum() -> Aggregate {
+        Aggregate::SingleAggregate(SingleAggregate::Sum)
+    }
+
+    fn avg() -> Aggregate {
+        Aggregate::SingleAggregate(SingleAggregate::Avg)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_create_aggregate_instance_update() {
+        let config = ViewConfig {
+            columns: vec![Some("Sales".to_owned()), Some("Profit".to_owned())],
+            ..ViewConfig::default()
+        };
+
+        let instance = AggregateInstance::new("Sales", &avg());
+        let update = config.create_aggregate_instance_update(&instance, 1, avg());
+        assert_eq!(
+            update.columns,
+            Some(vec![
+                Some("Sales".to_owned()),
+                Some("Sales (avg)".to_owned()),
+                Some("Profit".to_owned()),
+            ])
+        );
+
+        assert_eq!(
+            update.expressions,
+            Some(vec!["// Sales (avg)\n\"Sales\"".to_owned()])
+        );
+
+        assert_eq!(update.aggregates.unwrap().get("Sales (avg)"), Some(&avg()));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_create_aggregate_instance_update_fills_empty_slot() {
+        let instance = AggregateInstance::new("Sales", &avg());
+        let config = ViewConfig {
+            columns: vec![Some("Sales".to_owned()), None],
+            expressions: vec![instance.to_expression()],
+            ..ViewConfig::default()
+        };
+
+        let update = config.create_aggregate_instance_update(&instance, 1, avg());
+        assert_eq!(
+            update.columns,
+            Some(vec![
+                Some("Sales".to_owned()),
+                Some("Sales (avg)".to_owned()),
+            ])
+        );
+
+        assert_eq!(update.expressions, Some(vec![instance.to_expression()]));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_create_aggregate_update_renames_instance() {
+        let old = AggregateInstance::new("Sales", &avg());
+        let config = ViewConfig {
+            columns: vec![Some("Sales".to_owned()), Some(old.alias.clone())],
+            expressions: vec![old.to_expression()],
+            aggregates: HashMap::from([(old.alias.clone(), avg())]),
+            ..ViewConfig::default()
+        };
+
+        let max = Aggregate::SingleAggregate(SingleAggregate::Max);
+        let new = AggregateInstance::new("Sales", &max);
+        let expression = old.to_expression();
+        let update =
+            config.create_aggregate_update(&old.alias, max.clone(), Some((&expression, &new)));
+
+        assert_eq!(
+            update.columns,
+            Some(vec![
+                Some("Sales".to_owned()),
+                Some("Sales (max)".to_owned()),
+            ])
+        );
+
+        assert_eq!(update.expressions, Some(vec![new.to_expression()]));
+        let aggregates = update.aggregates.unwrap();
+        assert_eq!(aggregates.get("Sales (max)"), Some(&max));
+        assert_eq!(aggregates.get("Sales (avg)"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_create_aggregate_update_without_rename() {
+        let config = ViewConfig {
+            columns: vec![Some("Sales".to_owned())],
+            aggregates: HashMap::from([("Sales".to_owned(), avg())]),
+            ..ViewConfig::default()
+        };
+
+        let update = config.create_aggregate_update("Sales", sum(), None);
+        assert_eq!(update.columns, None);
+        assert_eq!(update.expressions, None);
+        assert_eq!(
+            update.aggregates,
+            Some(HashMap::from([("Sales".to_owned(), sum())]))
+        );
+    }
+}
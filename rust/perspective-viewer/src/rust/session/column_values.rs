@@ -0,0 +1,297 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use chrono::{TimeZone, Utc};
+use wasm_bindgen::JsCast;
+
+use super::view::{PerspectiveOwned, View};
+use super::Session;
+use crate::config::*;
+use crate::utils::*;
+use crate::*;
+
+/// A distinct value of a column, and the number of rows which contain it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnValue {
+    pub value: Scalar,
+    pub count: u32,
+
+    /// `value` formatted for display (and search).
+    pub label: String,
+}
+
+/// One page of the distinct values of a column, as returned by
+/// `Session::get_column_values()`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnValues {
+    pub values: Vec<ColumnValue>,
+
+    /// The number of distinct values which match the search, across all
+    /// pages.
+    pub total: usize,
+}
+
+/// Which of a column's distinct values to fetch.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnValuesQuery {
+    /// Only fetch values whose label contains this (case-insensitive) term.
+    pub search: String,
+
+    /// The index of the first value to fetch.
+    pub offset: usize,
+
+    /// The maximum number of values to fetch, or all of them if `None`.
+    pub limit: Option<usize>,
+}
+
+impl Session {
+    /// Get the distinct non-null values of `column` (a table or expression
+    /// column), in ascending order, with the number of rows for each, using a
+    /// temporary `View` grouped by `column`.  Values are typed by the column's
+    /// type, e.g. `Scalar::DateTime` for a date column.
+    ///
+    /// # Arguments
+    /// - `column` The name of the column (or expression).
+    /// - `query` The search term and page of values to fetch.
+    pub async fn get_column_values(
+        &self,
+        column: &str,
+        query: &ColumnValuesQuery,
+    ) -> ApiResult<ColumnValues> {
+        let col_type = self.metadata().get_column_table_type(column);
+        let is_string = col_type == Some(Type::String);
+        let mut filter = vec![Filter(
+            column.to_owned(),
+            FilterOp::IsNotNull,
+            FilterTerm::Scalar(Scalar::Null),
+        )];
+
+        // String columns are searched by the engine, which allows paging
+        // through their values without fetching them all.
+        if is_string && !query.search.is_empty() {
+            filter.push(Filter(
+                column.to_owned(),
+                FilterOp::Contains,
+                FilterTerm::Scalar(Scalar::String(query.search.clone())),
+            ));
+        }
+
+        let helper_config = ViewConfig {
+            group_by: vec![column.to_owned()],
+            columns: vec![Some(column.to_owned())],
+            aggregates: [(
+                column.to_owned(),
+                Aggregate::SingleAggregate(SingleAggregate::Count),
+            )]
+            .into_iter()
+            .collect(),
+            filter,
+            expressions: self.borrow().config.expressions.clone(),
+            ..ViewConfig::default()
+        };
+
+        let js_config = helper_config.as_jsvalue()?;
+        let table = self.borrow().table.clone().into_apierror()?;
+        let view: View = PerspectiveOwned::new(table.view(&js_config).await?);
+
+        // The first row is the total row.
+        let num_values = (view.dimensions().await?.num_view_rows() as usize).saturating_sub(1);
+        let is_paged = is_string || query.search.is_empty();
+        let window = match (is_paged, query.limit) {
+            (true, Some(limit)) => json!({
+                "start_row": query.offset + 1,
+                "end_row": query.offset + limit + 1
+            }),
+            (true, None) => json!({ "start_row": query.offset + 1 }),
+            (false, _) => json!({ "start_row": 1 }),
+        };
+
+        let columns = view.to_columns_with_options(window).await?;
+        let row_paths = js_sys::Reflect::get(&columns, &"__ROW_PATH__".into())?
+            .dyn_into::<js_sys::Array>()
+            .map(|x| x.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let counts = js_sys::Reflect::get(&columns, &column.into())?
+            .dyn_into::<js_sys::Array>()
+            .map(|x| x.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let time_zone = self.get_time_zone();
+        let values = row_paths.into_iter().zip(counts).map(|(row_path, count)| {
+            let value = Scalar::from_jsvalue(&row_path.unchecked_into::<js_sys::Array>().get(0));
+            let value = match (col_type, value) {
+                (Some(Type::Date | Type::Datetime), Scalar::Float(x)) => Scalar::DateTime(x),
+                (_, value) => value,
+            };
+
+            ColumnValue {
+                label: format_column_value(&value, col_type, time_zone.as_deref()),
+                count: count.as_f64().unwrap_or_default() as u32,
+                value,
+            }
+        });
+
+        if is_paged {
+            Ok(ColumnValues {
+                values: values.collect(),
+                total: num_values,
+            })
+        } else {
+            let search = query.search.to_lowercase();
+            let values = values
+                .filter(|x| x.label.to_lowercase().contains(&search))
+                .collect::<Vec<_>>();
+
+            let total = values.len();
+            let values = values
+                .into_iter()
+                .skip(query.offset)
+                .take(query.limit.unwrap_or(usize::MAX))
+                .collect();
+
+            Ok(ColumnValues { values, total })
+        }
+    }
+}
+
+/// Format a value of a column of type `col_type` for display, with datetimes
+/// in `time_zone`.
+pub(super) fn format_column_value(
+    value: &Scalar,
+    col_type: Option<Type>,
+    time_zone: Option<&str>,
+) -> String {
+    match (col_type, value) {
+        (Some(Type::Date), Scalar::DateTime(x)) => Utc
+            .timestamp_millis_opt(*x as i64)
+            .earliest()
+            .map(|x| x.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| value.to_string()),
+        (Some(Type::Datetime), Scalar::DateTime(x)) => posix_to_utc_str(*x, time_zone)
+            .map(|x| x.replace('T', " "))
+            .unwrap_or_else(|_| value.to_string()),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::js::get_mock_table_with_data;
+
+    async fn get_session() -> Session {
+        let session = Session::default();
+        let table = get_mock_table_with_data(json!({
+            "City": ["St. Paul, MN", "\"Quoted\"", "St. Paul, MN", JsValue::NULL],
+            "x": [1.5, 2.5, 1.5, 3.0]
+        }))
+        .await;
+
+        session.set_table(table).await.unwrap();
+        session
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_get_column_values_with_quotes_and_commas() {
+        let session = get_session().await;
+        let values = session
+            .get_column_values("City", &ColumnValuesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(values, ColumnValues {
+            values: vec![
+                ColumnValue {
+                    value: Scalar::String("\"Quoted\"".to_owned()),
+                    count: 1,
+                    label: "\"Quoted\"".to_owned(),
+                },
+                ColumnValue {
+                    value: Scalar::String("St. Paul, MN".to_owned()),
+                    count: 2,
+                    label: "St. Paul, MN".to_owned(),
+                },
+            ],
+            total: 2,
+        });
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_get_column_values_search() {
+        let session = get_session().await;
+        let query = ColumnValuesQuery {
+            search: "Paul, ".to_owned(),
+            ..ColumnValuesQuery::default()
+        };
+
+        let values = session.get_column_values("City", &query).await.unwrap();
+        assert_eq!(values.total, 1);
+        assert_eq!(
+            values.values[0].value,
+            Scalar::String("St. Paul, MN".to_owned())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_get_column_values_typed_and_paged() {
+        let session = get_session().await;
+        let query = ColumnValuesQuery {
+            offset: 1,
+            limit: Some(1),
+            ..ColumnValuesQuery::default()
+        };
+
+        let values = session.get_column_values("x", &query).await.unwrap();
+        assert_eq!(values.total, 3);
+        assert_eq!(values.values, vec![ColumnValue {
+            value: Scalar::Float(2.5),
+            count: 1,
+            label: Scalar::Float(2.5).to_string(),
+        }]);
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_checked_values_filter_numeric_column() {
+        let session = get_session().await;
+        let values = session
+            .get_column_values("x", &ColumnValuesQuery::default())
+            .await
+            .unwrap();
+
+        // Keep 1.5 and 3.0, as the checklist's `in` filter would.
+        let checked = vec![
+            values.values[0].value.clone(),
+            values.values[2].value.clone(),
+        ];
+        session.update_view_config(ViewConfigUpdate {
+            filter: Some(vec![Filter(
+                "x".to_owned(),
+                FilterOp::In,
+                FilterTerm::Array(checked),
+            )]),
+            ..ViewConfigUpdate::default()
+        });
+
+        session
+            .validate()
+            .await
+            .unwrap()
+            .create_view()
+            .await
+            .unwrap();
+        let view = session.get_view().unwrap();
+        assert_eq!(view.dimensions().await.unwrap().num_view_rows(), 3.0);
+    }
+}
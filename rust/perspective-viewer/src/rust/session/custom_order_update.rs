@@ -0,0 +1,58 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::config::*;
+
+impl ViewConfig {
+    /// Create an update for this `ViewConfig` which sorts the `sort` entry at
+    /// `index` by `order` instead, keeping its direction, and adds (or
+    /// replaces) the expression for `order`.
+    ///
+    /// This method is designed to be called from `crate::session`.
+    pub(super) fn create_custom_order_update(
+        &self,
+        index: usize,
+        order: &CustomOrder,
+    ) -> ViewConfigUpdate {
+        let mut config = self.clone();
+        let Some(Sort(_, sort_dir)) = config.sort.get(index).cloned() else {
+            return ViewConfigUpdate::default();
+        };
+
+        let sort_dir = match sort_dir {
+            SortDir::None => SortDir::Asc,
+            sort_dir => sort_dir,
+        };
+
+        config.sort[index] = Sort(order.alias.clone(), sort_dir);
+        config.sort = config
+            .sort
+            .iter()
+            .enumerate()
+            .filter(|(idx, sort)| *idx == index || sort.0 != order.alias)
+            .map(|(_, sort)| sort.clone())
+            .collect();
+
+        config.expressions.retain(|expression| {
+            CustomOrder::parse(expression)
+                .map(|x| x.alias != order.alias)
+                .unwrap_or(true)
+        });
+
+        config.expressions.push(order.to_expression());
+        ViewConfigUpdate {
+            sort: Some(config.sort),
+            expressions: Some(config.expressions),
+            ..ViewConfigUpdate::default()
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::column_values::ColumnValuesQuery;
+use super::Session;
+use crate::config::*;
+use crate::utils::*;
+
+impl Session {
+    /// The `CustomOrder` of a `sort` entry `column`, if it is a generated
+    /// order expression.
+    pub fn get_custom_order(&self, column: &str) -> Option<CustomOrder> {
+        let expression = self.metadata().get_expression_by_alias(column)?;
+        CustomOrder::parse(&expression)
+    }
+
+    /// Can the `sort` entry `column` be sorted in a custom order, e.g. is it a
+    /// string column?
+    pub fn is_custom_orderable(&self, column: &str) -> bool {
+        self.metadata().get_column_table_type(column) == Some(Type::String)
+    }
+
+    /// The values of `order` as they are listed in its editor:  its explicit
+    /// order, followed by any values of its source column which it does not
+    /// list yet (e.g. which have been added to the table since), in their
+    /// natural order.
+    pub async fn get_custom_order_values(&self, order: &CustomOrder) -> ApiResult<Vec<String>> {
+        let values = self.get_string_values(&order.source).await?;
+        let new_values = values.into_iter().filter(|x| !order.values.contains(x));
+        Ok(order
+            .values
+            .iter()
+            .cloned()
+            .chain(new_values)
+            .take(MAX_CUSTOM_ORDER_VALUES)
+            .collect())
+    }
+
+    /// A new `CustomOrder` for the string column `source`, which lists its
+    /// values in their natural order.
+    pub async fn get_default_custom_order(&self, source: &str) -> ApiResult<CustomOrder> {
+        let values = self.get_string_values(source).await?;
+        if values.is_empty() {
+            return Err(format!("Column \"{}\" has no values to order", source).into());
+        }
+
+        Ok(CustomOrder::new(source, values))
+    }
+
+    /// Create an update which sorts the `sort` entry at `index` by `order`.
+    pub fn create_custom_order_update(
+        &self,
+        index: usize,
+        order: &CustomOrder,
+    ) -> ViewConfigUpdate {
+        self.get_view_config()
+            .create_custom_order_update(index, order)
+    }
+
+    /// Create an update which replaces the values of an existing
+    /// `CustomOrder` column, e.g. when reordered from the column settings.
+    pub fn create_replace_custom_order_update(&self, order: &CustomOrder) -> ViewConfigUpdate {
+        let Some(old_expression) = self.metadata().get_expression_by_alias(&order.alias) else {
+            return ViewConfigUpdate::default();
+        };
+
+        self.get_view_config().create_replace_expression_update(
+            &order.alias,
+            &old_expression,
+            &order.alias,
+            &order.to_expression(),
+        )
+    }
+
+    async fn get_string_values(&self, column: &str) -> ApiResult<Vec<String>> {
+        let query = ColumnValuesQuery {
+            limit: Some(MAX_CUSTOM_ORDER_VALUES),
+            ..ColumnValuesQuery::default()
+        };
+
+        let values = self.get_column_values(column, &query).await?;
+        Ok(values
+            .values
+            .into_iter()
+            .filter_map(|x| match x.value {
+                Scalar::String(x) => Some(x),
+                _ => None,
+            })
+            .collect())
+    }
+}
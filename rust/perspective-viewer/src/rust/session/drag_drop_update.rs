@@ -91,6 +91,11 @@ impl ViewConfig {
                 config.filter.retain(|x| x.0 != column);
                 update.filter = Some(config.filter.clone());
             }
+            DragEffect::Move(DragTarget::Having) => {
+                config.having.retain(|x| x.0 != column);
+                update.having = Some(config.having.clone());
+            }
+            DragEffect::Move(DragTarget::CustomOrder) => (),
         }
 
         match drop {
@@ -162,6 +167,15 @@ impl ViewConfig {
                 );
                 update.filter = Some(config.filter);
             }
+            DragTarget::Having => {
+                let index = std::cmp::min(index, config.having.len());
+                config.having.insert(
+                    index,
+                    Filter(column, FilterOp::LT, FilterTerm::Scalar(Scalar::Null)),
+                );
+                update.having = Some(config.having);
+            }
+            DragTarget::CustomOrder => (),
         }
 
         update
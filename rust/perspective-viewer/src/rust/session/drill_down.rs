@@ -0,0 +1,206 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::column_values::format_column_value;
+use super::Session;
+use crate::config::*;
+
+impl Session {
+    /// The levels this `Session` has drilled down through, outermost first.
+    pub fn get_drill_down(&self) -> Vec<DrillDownLevel> {
+        self.borrow().drill_down.clone()
+    }
+
+    /// Replace the drill-down levels, e.g. when restoring a saved
+    /// `ViewerConfig` whose `ViewConfig` is already drilled down.
+    pub fn set_drill_down(&self, drill_down: Vec<DrillDownLevel>) {
+        self.borrow_mut().drill_down = drill_down;
+    }
+
+    /// Drill into the grouped row `row_path` (e.g. the `__ROW_PATH__` of a
+    /// plugin's `"perspective-click"` event), returning the update which
+    /// applies it, or `None` if there is nothing to drill into.  The caller
+    /// is expected to apply the update.
+    pub fn drill_down(&self, row_path: &[Scalar]) -> Option<ViewConfigUpdate> {
+        let group_by = self.borrow().config.group_by.clone();
+        let row_path = self.type_row_path(&group_by, row_path);
+        let (level, update) = self.get_view_config().create_drill_down_update(&row_path)?;
+
+        self.borrow_mut().drill_down.push(level);
+        Some(update)
+    }
+
+    /// Clear the drill-down levels if this `Session`'s `group_by` or `filter`
+    /// is no longer the one the innermost level drilled into, e.g. because it
+    /// was edited (or restored) outside of `drill_down()` and `drill_up()`,
+    /// as the levels would otherwise return to a stale `ViewConfig`.
+    pub(super) fn invalidate_drill_down(&self) {
+        let mut data = self.borrow_mut();
+        let Some(level) = data.drill_down.last() else {
+            return;
+        };
+
+        let base = ViewConfig {
+            group_by: level.group_by.clone(),
+            filter: level.filter.clone(),
+            ..ViewConfig::default()
+        };
+
+        let is_valid =
+            base.create_drill_down_update(&level.row_path)
+                .map_or(false, |(_, update)| {
+                    update.group_by.as_ref() == Some(&data.config.group_by)
+                        && update.filter.as_ref() == Some(&data.config.filter)
+                });
+
+        if !is_valid {
+            data.drill_down.clear();
+        }
+    }
+
+    /// Type the values of a row (or column) path from a plugin by the columns
+    /// they group, as plugins report date and datetime values as numbers.
+    pub(super) fn type_row_path(&self, group_by: &[String], row_path: &[Scalar]) -> Vec<Scalar> {
+        let metadata = self.metadata();
+        row_path
+            .iter()
+            .zip(group_by)
+            .map(
+                |(value, column)| match (metadata.get_column_table_type(column), value) {
+                    (Some(Type::Date | Type::Datetime), Scalar::Float(x)) => Scalar::DateTime(*x),
+                    (_, value) => value.clone(),
+                },
+            )
+            .collect()
+    }
+
+    /// Return to the drill-down level at `depth`, where `0` is the view before
+    /// any drill-down, returning the update which applies it or `None` if
+    /// this `Session` is not drilled down that far.  The caller is expected
+    /// to apply the update.
+    pub fn drill_up(&self, depth: usize) -> Option<ViewConfigUpdate> {
+        let mut data = self.borrow_mut();
+        if depth >= data.drill_down.len() {
+            return None;
+        }
+
+        let level = data.drill_down.drain(depth..).next()?;
+        Some(ViewConfigUpdate {
+            group_by: Some(level.group_by),
+            filter: Some(level.filter),
+            ..ViewConfigUpdate::default()
+        })
+    }
+
+    /// A label for each drill-down level, its `row_path` values formatted by
+    /// the type of the column they group.
+    pub fn get_drill_down_labels(&self) -> Vec<String> {
+        self.get_drill_down()
+            .iter()
+            .map(|level| self.format_row_path(&level.group_by, &level.row_path))
+            .collect()
+    }
+
+    /// Format a (typed) row path for display, e.g. `"East / 2023-01-01"`.
+    pub(super) fn format_row_path(&self, group_by: &[String], row_path: &[Scalar]) -> String {
+        let time_zone = self.get_time_zone();
+        let metadata = self.metadata();
+        row_path
+            .iter()
+            .zip(group_by)
+            .map(|(value, column)| match value {
+                Scalar::Null => "-".to_owned(),
+                value => format_column_value(
+                    value,
+                    metadata.get_column_table_type(column),
+                    time_zone.as_deref(),
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::js::get_mock_table_with_data;
+    use crate::*;
+
+    async fn get_drilled_session() -> Session {
+        let session = Session::default();
+        let table = get_mock_table_with_data(json!({
+            "A": ["x", "y"],
+            "B": ["u", "v"]
+        }))
+        .await;
+
+        session.set_table(table).await.unwrap();
+        session.update_view_config(ViewConfigUpdate {
+            group_by: Some(vec!["A".to_owned(), "B".to_owned()]),
+            ..ViewConfigUpdate::default()
+        });
+
+        let update = session
+            .drill_down(&[Scalar::String("x".to_owned())])
+            .unwrap();
+
+        session.update_view_config(update);
+        session
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_drill_down_survives_drill_updates() {
+        let session = get_drilled_session().await;
+        assert_eq!(session.get_drill_down().len(), 1);
+        assert_eq!(session.get_view_config().group_by, vec!["B".to_owned()]);
+
+        let update = session.drill_up(0).unwrap();
+        session.update_view_config(update);
+        assert!(session.get_drill_down().is_empty());
+        assert_eq!(session.get_view_config().group_by, vec![
+            "A".to_owned(),
+            "B".to_owned()
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_drill_down_invalidated_by_filter() {
+        let session = get_drilled_session().await;
+        let mut filter = session.get_view_config().filter.clone();
+        filter.push(Filter(
+            "B".to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Scalar(Scalar::String("u".to_owned())),
+        ));
+
+        session.update_view_config(ViewConfigUpdate {
+            filter: Some(filter),
+            ..ViewConfigUpdate::default()
+        });
+
+        assert!(session.get_drill_down().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_drill_down_invalidated_by_group_by() {
+        let session = get_drilled_session().await;
+        session.update_view_config(ViewConfigUpdate {
+            group_by: Some(vec!["A".to_owned()]),
+            ..ViewConfigUpdate::default()
+        });
+
+        assert!(session.get_drill_down().is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::config::*;
+
+impl ViewConfig {
+    /// Create an update for this `ViewConfig` which drills into the grouped
+    /// row `row_path`, filtering to its `group_by` values and grouping by the
+    /// remaining levels, along with the `DrillDownLevel` to return from it.
+    /// Returns `None` if `row_path` is the total row or a leaf row, which have
+    /// nothing to drill into.
+    ///
+    /// This method is designed to be called from `crate::session`.
+    pub(super) fn create_drill_down_update(
+        &self,
+        row_path: &[Scalar],
+    ) -> Option<(DrillDownLevel, ViewConfigUpdate)> {
+        if row_path.is_empty() || row_path.len() >= self.group_by.len() {
+            return None;
+        }
+
+        let (drilled, remaining) = self.group_by.split_at(row_path.len());
+        let filter = self
+            .filter
+            .iter()
+            .cloned()
+            .chain(row_path_filters(drilled, row_path))
+            .collect();
+
+        let level = DrillDownLevel {
+            row_path: row_path.to_vec(),
+            group_by: self.group_by.clone(),
+            filter: self.filter.clone(),
+        };
+
+        let update = ViewConfigUpdate {
+            group_by: Some(remaining.to_vec()),
+            filter: Some(filter),
+            ..ViewConfigUpdate::default()
+        };
+
+        Some((level, update))
+    }
+}
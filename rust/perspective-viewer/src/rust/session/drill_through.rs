@@ -0,0 +1,134 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen::JsCast;
+
+use super::column_values::format_column_value;
+use super::view::{PerspectiveOwned, View};
+use super::Session;
+use crate::config::*;
+use crate::utils::*;
+use crate::*;
+
+/// The maximum number of rows shown in a drill-through.  All of its rows can
+/// still be exported or copied.
+pub const MAX_DRILL_THROUGH_ROWS: usize = 1000;
+
+/// The underlying rows of an aggregated cell, from a flat `View` of all of the
+/// `Table`'s columns filtered to the cell's row and column groups.  The `View`
+/// is deleted when the last clone of this `DrillThrough` is dropped.
+#[derive(Clone)]
+pub struct DrillThrough {
+    pub view: View,
+
+    /// The cell's row and column groups, formatted for display.
+    pub title: String,
+    pub columns: Vec<String>,
+
+    /// The first `MAX_DRILL_THROUGH_ROWS` rows, formatted for display.
+    pub rows: Vec<Vec<String>>,
+
+    /// The number of rows in `view`.
+    pub num_rows: usize,
+}
+
+impl Session {
+    /// Create a `DrillThrough` of the rows which make up the aggregated cell
+    /// at `row_path` and `column_path`, i.e. the rows of the current `View`'s
+    /// (resolved) `filter` which are in the cell's `group_by` and `split_by`
+    /// groups.
+    ///
+    /// # Arguments
+    /// - `row_path` The cell's `__ROW_PATH__`, or `[]` for the total row.
+    /// - `column_path` The cell's `split_by` values.  Any trailing values, such
+    ///   as the aggregated column's name, are ignored.
+    pub async fn create_drill_through(
+        &self,
+        row_path: &[Scalar],
+        column_path: &[Scalar],
+    ) -> ApiResult<DrillThrough> {
+        let config = self.borrow().config.clone();
+        let row_path = self.type_row_path(&config.group_by, row_path);
+        let column_path = self.type_row_path(&config.split_by, column_path);
+        let engine_filter = self.borrow().engine_filter.clone();
+        let mut filter = match engine_filter {
+            Some(filter) => filter,
+            None => self.resolve_filters(&config).await?,
+        };
+
+        filter.extend(row_path_filters(&config.group_by, &row_path));
+        filter.extend(row_path_filters(&config.split_by, &column_path));
+        let columns = self
+            .metadata()
+            .get_table_columns()
+            .cloned()
+            .unwrap_or_default();
+
+        let helper_config = ViewConfig {
+            columns: columns.iter().cloned().map(Some).collect(),
+            filter,
+            expressions: config.expressions.clone(),
+            ..ViewConfig::default()
+        };
+
+        let js_config = helper_config.as_jsvalue()?;
+        let table = self.borrow().table.clone().into_apierror()?;
+        let view: View = PerspectiveOwned::new(table.view(&js_config).await?);
+        let num_rows = view.dimensions().await?.num_view_rows() as usize;
+        let data = view
+            .to_columns_with_options(json!({ "end_row": MAX_DRILL_THROUGH_ROWS }))
+            .await?;
+
+        let time_zone = self.get_time_zone();
+        let mut rows = vec![vec![]; num_rows.min(MAX_DRILL_THROUGH_ROWS)];
+        for column in columns.iter() {
+            let col_type = self.metadata().get_column_table_type(column);
+            let values = js_sys::Reflect::get(&data, &column.into())?
+                .dyn_into::<js_sys::Array>()
+                .map(|x| x.iter().collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            for (row, value) in rows.iter_mut().zip(values) {
+                let value = match (col_type, Scalar::from_jsvalue(&value)) {
+                    (_, Scalar::Null) => String::new(),
+                    (Some(Type::Date | Type::Datetime), Scalar::Float(x)) => {
+                        format_column_value(&Scalar::DateTime(x), col_type, time_zone.as_deref())
+                    }
+                    (_, value) => format_column_value(&value, col_type, time_zone.as_deref()),
+                };
+
+                row.push(value);
+            }
+        }
+
+        let title = [
+            self.format_row_path(&config.group_by, &row_path),
+            self.format_row_path(&config.split_by, &column_path),
+        ]
+        .into_iter()
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ");
+
+        Ok(DrillThrough {
+            view,
+            title: if title.is_empty() {
+                "Total".to_owned()
+            } else {
+                title
+            },
+            columns,
+            rows,
+            num_rows,
+        })
+    }
+}
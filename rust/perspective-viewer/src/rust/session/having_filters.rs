@@ -0,0 +1,197 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+
+use wasm_bindgen::JsCast;
+
+use super::view::{PerspectiveOwned, View};
+use super::Session;
+use crate::config::*;
+use crate::utils::*;
+
+impl Session {
+    /// Resolve `config.having` into a `filter` on the first `group_by` column,
+    /// which keeps only the groups whose aggregates satisfy every `having`
+    /// filter.  The groups are calculated from a temporary `View` with the
+    /// same (already resolved) source `filter`.
+    ///
+    /// # Arguments
+    /// - `config` The `ViewConfig` whose `having` filters to resolve.
+    /// - `filter` The resolved source filters of `config`.
+    pub(super) async fn resolve_having_filter(
+        &self,
+        config: &ViewConfig,
+        filter: &[Filter],
+    ) -> ApiResult<Option<Filter>> {
+        let Some(group_by) = config.group_by.first() else {
+            return Ok(None);
+        };
+
+        if config.having.is_empty() {
+            return Ok(None);
+        }
+
+        let mut columns: Vec<String> = vec![];
+        for having in config.having.iter() {
+            if !columns.contains(&having.0) {
+                columns.push(having.0.clone());
+            }
+        }
+
+        let aggregates = columns
+            .iter()
+            .filter_map(|name| {
+                let metadata = self.metadata();
+                let aggregate = config
+                    .aggregates
+                    .get(name)
+                    .cloned()
+                    .or_else(|| metadata.get_column_default_aggregate(name))?;
+
+                Some((
+                    name.clone(),
+                    metadata.get_engine_aggregate(name, &aggregate),
+                ))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let helper_config = ViewConfig {
+            group_by: vec![group_by.clone()],
+            columns: columns.iter().cloned().map(Some).collect(),
+            aggregates,
+            filter: filter.to_vec(),
+            expressions: config.expressions.clone(),
+            ..ViewConfig::default()
+        };
+
+        let js_config = helper_config.as_jsvalue()?;
+        let table = self.borrow().table.clone().into_apierror()?;
+        let view: View = PerspectiveOwned::new(table.view(&js_config).await?);
+        let data = view.to_columns().await?;
+        let get_column = |name: &str| -> ApiResult<js_sys::Array> {
+            Ok(js_sys::Reflect::get(&data, &name.into())?.unchecked_into())
+        };
+
+        let row_paths = get_column("__ROW_PATH__")?;
+        let values = columns
+            .iter()
+            .map(|name| Ok((name.as_str(), get_column(name)?)))
+            .collect::<ApiResult<HashMap<_, _>>>()?;
+
+        let keys = row_paths
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, row_path)| {
+                let row_path = row_path.unchecked_into::<js_sys::Array>();
+                if row_path.length() != 1 {
+                    return None;
+                }
+
+                let is_match = config.having.iter().all(|having| {
+                    let value = values[having.0.as_str()].get(idx as u32);
+                    having.matches(&Scalar::from_jsvalue(&value))
+                });
+
+                is_match.then(|| Scalar::from_jsvalue(&row_path.get(0)))
+            })
+            .filter(|x| *x != Scalar::Null)
+            .collect();
+
+        Ok(Some(Filter(
+            group_by.clone(),
+            FilterOp::In,
+            FilterTerm::Array(keys),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::js::get_mock_table;
+
+    fn having_gt(value: f64) -> Filter {
+        Filter(
+            "A".to_owned(),
+            FilterOp::GT,
+            FilterTerm::Scalar(Scalar::Float(value)),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_resolve_having_filter() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        let config = ViewConfig {
+            group_by: vec!["A".to_owned()],
+            having: vec![having_gt(1.0)],
+            ..ViewConfig::default()
+        };
+
+        let filter = session.resolve_having_filter(&config, &[]).await.unwrap();
+        assert_eq!(
+            filter,
+            Some(Filter(
+                "A".to_owned(),
+                FilterOp::In,
+                FilterTerm::Array(vec![Scalar::Float(2.0), Scalar::Float(3.0)])
+            ))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_resolve_having_filter_with_source_filter() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        let config = ViewConfig {
+            group_by: vec!["A".to_owned()],
+            having: vec![having_gt(1.0)],
+            ..ViewConfig::default()
+        };
+
+        let filter = [Filter(
+            "A".to_owned(),
+            FilterOp::LT,
+            FilterTerm::Scalar(Scalar::Float(3.0)),
+        )];
+
+        let filter = session
+            .resolve_having_filter(&config, &filter)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            filter,
+            Some(Filter(
+                "A".to_owned(),
+                FilterOp::In,
+                FilterTerm::Array(vec![Scalar::Float(2.0)])
+            ))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_resolve_having_filter_without_group_by() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        let config = ViewConfig {
+            having: vec![having_gt(1.0)],
+            ..ViewConfig::default()
+        };
+
+        let filter = session.resolve_having_filter(&config, &[]).await.unwrap();
+        assert_eq!(filter, None);
+    }
+}
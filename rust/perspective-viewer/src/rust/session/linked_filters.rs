@@ -0,0 +1,181 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::column_values::format_column_value;
+use super::Session;
+use crate::config::*;
+
+impl Session {
+    /// The filters applied to this `Session` by the other members of its link
+    /// group.
+    pub fn get_linked_filters(&self) -> Vec<Filter> {
+        self.borrow()
+            .linked_filters
+            .iter()
+            .flat_map(|(_, filters)| filters.iter().cloned())
+            .collect()
+    }
+
+    /// A label for each of the `get_linked_filters()`, e.g.
+    /// `"Region == East"`.
+    pub fn get_linked_filter_labels(&self) -> Vec<String> {
+        let time_zone = self.get_time_zone();
+        let metadata = self.metadata();
+        self.get_linked_filters()
+            .iter()
+            .map(|Filter(column, op, term)| match (op, term) {
+                (FilterOp::IsNull | FilterOp::IsNotNull, _) => format!("{} {}", column, op),
+                (_, FilterTerm::Scalar(value)) => {
+                    let col_type = metadata.get_column_table_type(column);
+                    let value = format_column_value(value, col_type, time_zone.as_deref());
+                    format!("{} {} {}", column, op, value)
+                }
+                (_, term) => format!("{} {} {}", column, op, term),
+            })
+            .collect()
+    }
+
+    /// Replace the filters applied by the link group member `source` with
+    /// `filters`, ignoring those on columns this `Session` does not have.
+    /// Linked filters are kept apart from this `Session`'s `ViewConfig`, and
+    /// only merged into the engine's `filter` when the `View` is created, so
+    /// they are never saved or edited as this `Session`'s own.  Returns
+    /// `true` if they changed, in which case the `View` must be re-created.
+    pub fn set_linked_filters(&self, source: usize, filters: Vec<Filter>) -> bool {
+        let filters = filters
+            .into_iter()
+            .filter(|x| self.metadata().get_column_table_type(&x.0).is_some())
+            .collect::<Vec<_>>();
+
+        let old = self
+            .borrow()
+            .linked_filters
+            .iter()
+            .find(|(id, _)| *id == source)
+            .map(|(_, filters)| filters.clone())
+            .unwrap_or_default();
+
+        if old == filters {
+            return false;
+        }
+
+        {
+            let linked_filters = &mut self.borrow_mut().linked_filters;
+            linked_filters.retain(|(id, _)| *id != source);
+            if !filters.is_empty() {
+                linked_filters.push((source, filters));
+            }
+        }
+
+        self.invalidate_linked_filters();
+        true
+    }
+
+    /// Remove all of the filters applied by other link group members, e.g.
+    /// when leaving the link group.  Returns `true` if there were any, in
+    /// which case the `View` must be re-created.
+    pub fn clear_linked_filters(&self) -> bool {
+        if self.borrow().linked_filters.is_empty() {
+            return false;
+        }
+
+        self.borrow_mut().linked_filters.clear();
+        self.invalidate_linked_filters();
+        true
+    }
+
+    fn invalidate_linked_filters(&self) {
+        self.borrow_mut().view_sub = None;
+        self.borrow_mut().is_clean = false;
+        self.linked_filters_changed.emit_all(());
+    }
+
+    /// The filters which select a clicked cell, to publish to this
+    /// `Session`'s link group:  the cell's row and column groups or, for a
+    /// `View` with no `group_by` or `split_by`, the cell's own value.
+    ///
+    /// # Arguments
+    /// - `row_path` The cell's `__ROW_PATH__`.
+    /// - `column_path` The cell's `split_by` values, followed by its column.
+    /// - `value` The cell's value.
+    pub fn get_selection_filters(
+        &self,
+        row_path: &[Scalar],
+        column_path: &[Scalar],
+        value: Scalar,
+    ) -> Vec<Filter> {
+        let config = self.get_view_config().clone();
+        if config.group_by.is_empty() && config.split_by.is_empty() {
+            return match column_path.last() {
+                Some(Scalar::String(column)) => {
+                    let column = column.clone();
+                    let value = self.type_row_path(&[column.clone()], &[value]);
+                    row_path_filters(&[column], &value)
+                }
+                _ => vec![],
+            };
+        }
+
+        let row_path = self.type_row_path(&config.group_by, row_path);
+        let column_path = self.type_row_path(&config.split_by, column_path);
+        let mut filters = row_path_filters(&config.group_by, &row_path);
+        filters.extend(row_path_filters(&config.split_by, &column_path));
+        filters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::js::get_mock_table;
+
+    fn a_eq(value: f64) -> Filter {
+        Filter(
+            "A".to_owned(),
+            FilterOp::EQ,
+            FilterTerm::Scalar(Scalar::Float(value)),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_linked_filters_are_not_saved() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        assert!(session.set_linked_filters(1, vec![a_eq(1.0)]));
+        assert!(!session.set_linked_filters(1, vec![a_eq(1.0)]));
+        assert!(session.get_view_config().filter.is_empty());
+        assert_eq!(session.get_linked_filters(), vec![a_eq(1.0)]);
+
+        let config = session.get_engine_view_config().await.unwrap();
+        assert_eq!(config.filter, vec![a_eq(1.0)]);
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_linked_filters_keep_identical_user_filters() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        session.update_view_config(ViewConfigUpdate {
+            filter: Some(vec![a_eq(1.0)]),
+            ..ViewConfigUpdate::default()
+        });
+
+        assert!(session.set_linked_filters(1, vec![a_eq(1.0)]));
+        let config = session.get_engine_view_config().await.unwrap();
+        assert_eq!(config.filter, vec![a_eq(1.0)]);
+
+        assert!(session.clear_linked_filters());
+        assert!(!session.clear_linked_filters());
+        assert_eq!(session.get_view_config().filter, vec![a_eq(1.0)]);
+    }
+}
@@ -14,6 +14,8 @@ use std::collections::{HashMap, HashSet};
 use std::iter::IntoIterator;
 use std::ops::{Deref, DerefMut};
 
+use serde::Deserialize;
+
 use crate::components::viewer::ColumnLocator;
 use crate::config::*;
 use crate::js::perspective::*;
@@ -26,6 +28,14 @@ struct SessionViewExpressionMetadata {
     edited: HashMap<String, String>,
 }
 
+/// The capabilities of a `Table`'s engine, as reported by `get_features()`.
+#[derive(Default, Deserialize)]
+struct TableFeatures {
+    /// Supported aggregate names, by column type name.
+    #[serde(default)]
+    aggregates: HashMap<String, HashSet<String>>,
+}
+
 /// Metadata state reflects data we could fetch from a `View`, but would like to
 /// do so without `async`.  It must be recreated by any `async` method which
 /// changes the `View` and may temporarily be out-of-sync with the
@@ -56,6 +66,10 @@ pub struct SessionMetadataState {
     edit_port: f64,
     view_schema: Option<HashMap<String, Type>>,
     expr_meta: Option<SessionViewExpressionMetadata>,
+
+    /// `None` for engines which predate `get_features()`, in which case every
+    /// aggregate is assumed to be supported.
+    features: Option<TableFeatures>,
 }
 
 impl SessionMetadata {
@@ -74,10 +88,16 @@ impl SessionMetadata {
 
         let table_schema = table.schema().await?.into_serde_ext()?;
         let edit_port = table.make_port().await?;
+        let features = match table.get_features().await {
+            Ok(features) => Some(features.into_serde_ext()?),
+            Err(_) => None,
+        };
+
         Ok(Self(Some(SessionMetadataState {
             column_names,
             table_schema,
             edit_port,
+            features,
             ..SessionMetadataState::default()
         })))
     }
@@ -238,6 +258,52 @@ impl SessionMetadata {
         maybe!(self.as_ref()?.view_schema.as_ref()?.get(name)).cloned()
     }
 
+    /// Whether the `Table`'s engine can compute `agg` for columns of type `ty`.
+    /// Engines which do not report a feature list for `ty` are assumed to
+    /// support every aggregate.
+    pub fn is_aggregate_supported(&self, ty: Type, agg: &Aggregate) -> bool {
+        maybe!({
+            let features = self.as_ref()?.features.as_ref()?;
+            let aggregates = features.aggregates.get(&ty.to_string())?;
+            Some(aggregates.contains(&agg.to_engine(ty).engine_name()))
+        })
+        .unwrap_or(true)
+    }
+
+    /// The aggregate the engine computes `agg` with for the column `name`,
+    /// see `Aggregate::to_engine()`.
+    ///
+    /// # Arguments
+    /// - `name` The column name (or expression alias).
+    /// - `agg` The aggregate as set in the `ViewConfig`.
+    pub fn get_engine_aggregate(&self, name: &str, agg: &Aggregate) -> Aggregate {
+        match self.get_column_table_type(name) {
+            Some(ty) => agg.to_engine(ty),
+            None => agg.clone(),
+        }
+    }
+
+    /// Returns the aggregate used for a column with no aggregate set, which is
+    /// its type's `default_aggregate()` when the engine supports it.
+    ///
+    /// # Arguments
+    /// - `name` The column name (or expression alias).
+    pub fn get_column_default_aggregate(&self, name: &str) -> Option<Aggregate> {
+        let ty = self.get_column_table_type(name)?;
+        let agg = ty.default_aggregate();
+        if self.is_aggregate_supported(ty, &agg) {
+            Some(agg)
+        } else {
+            Some(ty.engine_default_aggregate())
+        }
+    }
+
+    /// Returns the aggregates available for a column, including one
+    /// `MultiAggregate` per valid parameter for aggregates parameterized by
+    /// another column, and the default parameter for the rest.
+    ///
+    /// # Arguments
+    /// - `name` The column name (or expression alias) to aggregate.
     pub fn get_column_aggregates<'a>(
         &'a self,
         name: &str,
@@ -245,26 +311,86 @@ impl SessionMetadata {
         maybe!({
             let coltype = self.get_column_table_type(name)?;
             let aggregates = coltype.aggregates_iter();
-            Some(match coltype {
-                Type::Float | Type::Integer => {
-                    let num_cols = self
-                        .get_expression_columns()
-                        .cloned()
-                        .chain(self.get_table_columns()?.clone().into_iter())
-                        .map(move |name| {
-                            self.get_column_table_type(&name)
-                                .map(|coltype| (name, coltype))
-                        })
-                        .collect::<Option<Vec<_>>>()?
-                        .into_iter()
-                        .filter(|(_, coltype)| *coltype == Type::Integer || *coltype == Type::Float)
-                        .map(|(name, _)| {
-                            Aggregate::MultiAggregate(MultiAggregate::WeightedMean, name)
-                        });
-                    Box::new(aggregates.chain(num_cols))
-                }
-                _ => aggregates,
-            })
+            let columns = self
+                .get_expression_columns()
+                .cloned()
+                .chain(self.get_table_columns()?.clone().into_iter())
+                .map(|name| {
+                    self.get_column_table_type(&name)
+                        .map(|coltype| (name, coltype))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            let multi_aggregates = MULTI_AGGREGATES
+                .iter()
+                .filter(move |x| coltype.is_multi_aggregate_valid(x))
+                .flat_map(move |x| match x.default_param() {
+                    Some(param) => vec![Aggregate::MultiAggregate(*x, param.to_owned())],
+                    None => columns
+                        .iter()
+                        .filter(|(_, param_type)| param_type.is_multi_aggregate_param_valid(x))
+                        .map(|(name, _)| Aggregate::MultiAggregate(*x, name.clone()))
+                        .collect(),
+                });
+
+            let aggregates = aggregates
+                .chain(multi_aggregates)
+                .filter(move |x| self.is_aggregate_supported(coltype, x));
+
+            Some(Box::new(aggregates) as Box<dyn Iterator<Item = Aggregate>>)
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn metadata(features: Option<TableFeatures>) -> SessionMetadata {
+        SessionMetadata(Some(SessionMetadataState {
+            features,
+            ..SessionMetadataState::default()
+        }))
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_aggregates_supported_without_features() {
+        let sum = Aggregate::SingleAggregate(SingleAggregate::Sum);
+        assert!(metadata(None).is_aggregate_supported(Type::Float, &sum));
+        let features = TableFeatures::default();
+        assert!(metadata(Some(features)).is_aggregate_supported(Type::Float, &sum));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_aggregates_unsupported_when_omitted() {
+        let sum = Aggregate::SingleAggregate(SingleAggregate::Sum);
+        let count = Aggregate::SingleAggregate(SingleAggregate::Count);
+        let features = TableFeatures {
+            aggregates: [("float".to_owned(), ["count".to_owned()].into())].into(),
+        };
+
+        let metadata = metadata(Some(features));
+        assert!(metadata.is_aggregate_supported(Type::Float, &count));
+        assert!(!metadata.is_aggregate_supported(Type::Float, &sum));
+        assert!(metadata.is_aggregate_supported(Type::Integer, &sum));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_aggregates_supported_by_engine_name() {
+        let features = TableFeatures {
+            aggregates: [
+                ("date".to_owned(), ["high".to_owned()].into()),
+                ("boolean".to_owned(), ["count".to_owned()].into()),
+            ]
+            .into(),
+        };
+
+        let metadata = metadata(Some(features));
+        let max = Aggregate::SingleAggregate(SingleAggregate::Max);
+        let pct_true = Aggregate::SingleAggregate(SingleAggregate::PctTrue);
+        assert!(metadata.is_aggregate_supported(Type::Date, &max));
+        assert!(!metadata.is_aggregate_supported(Type::Bool, &pct_true));
+    }
+}
@@ -0,0 +1,116 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::Session;
+
+impl Session {
+    /// The number of `View` updates which have arrived since updates were
+    /// paused, or `None` if they are not paused.
+    pub fn get_pending_updates(&self) -> Option<u32> {
+        self.borrow().pending_updates.get()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.get_pending_updates().is_some()
+    }
+
+    /// Pause updates, so `View` updates are counted instead of rendered (or
+    /// reflected in the stats) until `resume_updates()` is called.
+    pub fn pause_updates(&self) {
+        if !self.is_paused() {
+            self.borrow().pending_updates.set(Some(0));
+            self.pending_updates_changed.emit_all(Some(0));
+        }
+    }
+
+    /// Resume updates, handling the latest state of the `View` once if any
+    /// updates arrived while paused.
+    pub fn resume_updates(&self) {
+        let pending = self.borrow().pending_updates.take();
+        if let Some(count) = pending {
+            self.pending_updates_changed.emit_all(None);
+            if count > 0 && let Some(sub) = self.borrow().view_sub.as_ref() {
+                sub.refresh();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::js::get_mock_table;
+    use crate::utils::*;
+    use crate::*;
+
+    #[wasm_bindgen_test]
+    pub async fn test_pause_count_resume() {
+        let session = Session::default();
+        let table = get_mock_table().await;
+        session.set_table(table.clone()).await.unwrap();
+        session
+            .validate()
+            .await
+            .unwrap()
+            .create_view()
+            .await
+            .unwrap();
+
+        let redraws = Rc::new(Cell::new(0));
+        let _sub = session.table_updated.add_listener({
+            let redraws = redraws.clone();
+            move |_| redraws.set(redraws.get() + 1)
+        });
+
+        session.pause_updates();
+        assert_eq!(session.get_pending_updates(), Some(0));
+        for x in 4..7 {
+            table.update(&json!({ "A": [x] })).unwrap();
+        }
+
+        while session.get_pending_updates() != Some(3) {
+            session.pending_updates_changed.listen_once().await.unwrap();
+        }
+
+        assert_eq!(redraws.get(), 0);
+        session.resume_updates();
+        assert!(!session.is_paused());
+        if redraws.get() == 0 {
+            session.table_updated.listen_once().await.unwrap();
+        }
+
+        set_timeout(50).await.unwrap();
+        assert_eq!(redraws.get(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_resume_without_pending_updates() {
+        let session = Session::default();
+        let redraws = Rc::new(Cell::new(0));
+        let _sub = session.table_updated.add_listener({
+            let redraws = redraws.clone();
+            move |_| redraws.set(redraws.get() + 1)
+        });
+
+        session.pause_updates();
+        session.pause_updates();
+        assert_eq!(session.get_pending_updates(), Some(0));
+        session.resume_updates();
+        assert_eq!(session.get_pending_updates(), None);
+        assert_eq!(redraws.get(), 0);
+    }
+}
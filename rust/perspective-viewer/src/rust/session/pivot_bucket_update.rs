@@ -0,0 +1,217 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::config::*;
+use crate::dragdrop::DragTarget;
+
+/// A generated expression column of a `source` column, e.g. a `DateBucket`
+/// or `NumericBins`, which replaces it in `group_by` or `split_by`.
+pub struct PivotBucket {
+    pub alias: String,
+    pub expression: String,
+
+    /// The `(alias, expression)` of a companion expression column which
+    /// sorts this bucket's values, if their labels do not sort correctly as
+    /// strings, e.g. `NumericBins`.
+    pub order: Option<(String, String)>,
+}
+
+impl From<DateBucket> for PivotBucket {
+    fn from(bucket: DateBucket) -> Self {
+        Self {
+            expression: bucket.to_expression(),
+            alias: bucket.alias,
+            order: None,
+        }
+    }
+}
+
+impl From<NumericBins> for PivotBucket {
+    fn from(bins: NumericBins) -> Self {
+        Self {
+            expression: bins.to_expression(),
+            order: Some((bins.order_alias.clone(), bins.to_order_expression())),
+            alias: bins.alias,
+        }
+    }
+}
+
+/// The alias of the bucket `expression` is generated for, if it is a
+/// generated bucket of `source` or the companion order of one.
+fn is_bucket_of(expression: &str, source: &str) -> Option<String> {
+    match (
+        DateBucket::parse(expression),
+        NumericBins::parse(expression).or_else(|| NumericBins::parse_order(expression)),
+    ) {
+        (Some(bucket), _) if bucket.source == source => Some(bucket.alias),
+        (_, Some(bins)) if bins.source == source => Some(bins.alias),
+        _ => None,
+    }
+}
+
+impl ViewConfig {
+    /// Create an update for this `ViewConfig` which replaces the `group_by` or
+    /// `split_by` entry at `index` with `buckets` of `source` in order, or
+    /// with `source` itself if `buckets` is empty.  Generated buckets of
+    /// `source` which are no longer in use are removed from `expressions`.
+    ///
+    /// A bucket's companion order column is added to the end of `sort`
+    /// (`"col asc"` for `split_by`), so it only breaks the ties of any
+    /// existing sort, with a `low` aggregate, so a parent group sorts by the
+    /// first of its buckets.  It is removed with its bucket.
+    ///
+    /// This method is designed to be called from `crate::session` which can
+    /// resolve the `source` column of a bucketed entry.
+    pub(super) fn create_pivot_bucket_update(
+        &self,
+        target: DragTarget,
+        index: usize,
+        source: &str,
+        buckets: Vec<PivotBucket>,
+    ) -> ViewConfigUpdate {
+        let mut config = self.clone();
+        let pivots = match target {
+            DragTarget::GroupBy => &mut config.group_by,
+            DragTarget::SplitBy => &mut config.split_by,
+            _ => return ViewConfigUpdate::default(),
+        };
+
+        let columns = if buckets.is_empty() {
+            vec![source.to_owned()]
+        } else {
+            buckets.iter().map(|x| x.alias.clone()).collect()
+        };
+
+        let index = std::cmp::min(index, pivots.len());
+        let (head, tail) = pivots.split_at(index);
+        *pivots = head
+            .iter()
+            .filter(|x| !columns.contains(x))
+            .chain(columns.iter())
+            .chain(tail.iter().skip(1).filter(|x| !columns.contains(x)))
+            .cloned()
+            .collect();
+
+        let sort_dir = match target {
+            DragTarget::SplitBy => SortDir::ColAsc,
+            _ => SortDir::Asc,
+        };
+
+        // Older versions of these buckets, e.g. with other breakpoints.
+        config
+            .expressions
+            .retain(|x| match is_bucket_of(x, source) {
+                Some(alias) => !columns.contains(&alias),
+                None => true,
+            });
+
+        for PivotBucket {
+            expression, order, ..
+        } in buckets.into_iter()
+        {
+            if !config.expressions.contains(&expression) {
+                config.expressions.push(expression);
+            }
+
+            if let Some((order_alias, order_expression)) = order {
+                if !config.expressions.contains(&order_expression) {
+                    config.expressions.push(order_expression);
+                }
+
+                if !config.sort.iter().any(|x| x.0 == order_alias) {
+                    config.sort.push(Sort(order_alias.clone(), sort_dir));
+                }
+
+                config.aggregates.insert(
+                    order_alias,
+                    Aggregate::SingleAggregate(SingleAggregate::Low),
+                );
+            }
+        }
+
+        let (expressions, unused): (Vec<_>, Vec<_>) = std::mem::take(&mut config.expressions)
+            .into_iter()
+            .partition(|expression| match is_bucket_of(expression, source) {
+                Some(alias) => config.is_column_expression_in_use(&alias),
+                None => true,
+            });
+
+        let unused_orders = unused
+            .iter()
+            .filter_map(|x| NumericBins::parse_order(x))
+            .map(|x| x.order_alias)
+            .collect::<Vec<_>>();
+
+        config.sort.retain(|x| !unused_orders.contains(&x.0));
+        config
+            .aggregates
+            .retain(|alias, _| !unused_orders.contains(alias));
+
+        ViewConfigUpdate {
+            group_by: Some(config.group_by),
+            split_by: Some(config.split_by),
+            expressions: Some(expressions),
+            sort: Some(config.sort),
+            aggregates: Some(config.aggregates),
+            ..ViewConfigUpdate::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_numeric_bins_sort_by_order() {
+        let config = ViewConfig {
+            group_by: vec!["Sales".to_owned()],
+            sort: vec![Sort("Profit".to_owned(), SortDir::Desc)],
+            ..ViewConfig::default()
+        };
+
+        let bins = NumericBins::new("Sales", [0.0, 100.0]);
+        let update = config
+            .create_pivot_bucket_update(DragTarget::GroupBy, 0, "Sales", vec![bins.clone().into()]);
+
+        assert_eq!(update.group_by, Some(vec![bins.alias.clone()]));
+        assert_eq!(
+            update.expressions,
+            Some(vec![bins.to_expression(), bins.to_order_expression()])
+        );
+        assert_eq!(
+            update.sort,
+            Some(vec![
+                Sort("Profit".to_owned(), SortDir::Desc),
+                Sort(bins.order_alias.clone(), SortDir::Asc)
+            ])
+        );
+        assert_eq!(
+            update.aggregates.unwrap().get(&bins.order_alias),
+            Some(&Aggregate::SingleAggregate(SingleAggregate::Low))
+        );
+
+        let config = ViewConfig {
+            group_by: vec![bins.alias.clone()],
+            expressions: vec![bins.to_expression(), bins.to_order_expression()],
+            sort: vec![Sort(bins.order_alias, SortDir::Asc)],
+            ..ViewConfig::default()
+        };
+
+        let update = config.create_pivot_bucket_update(DragTarget::GroupBy, 0, "Sales", vec![]);
+        assert_eq!(update.group_by, Some(vec!["Sales".to_owned()]));
+        assert_eq!(update.expressions, Some(vec![]));
+        assert_eq!(update.sort, Some(vec![]));
+    }
+}
@@ -0,0 +1,158 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::view::{PerspectiveOwned, View};
+use super::Session;
+use crate::config::*;
+use crate::dragdrop::DragTarget;
+use crate::utils::*;
+
+impl Session {
+    /// The `DateBucket` of a `group_by` or `split_by` entry `column`, if it is
+    /// a generated bucket expression.
+    pub fn get_date_bucket(&self, column: &str) -> Option<DateBucket> {
+        let expression = self.metadata().get_expression_by_alias(column)?;
+        DateBucket::parse(&expression)
+    }
+
+    /// The `NumericBins` of a `group_by` or `split_by` entry `column`, if it
+    /// is a generated bins expression.
+    pub fn get_numeric_bins(&self, column: &str) -> Option<NumericBins> {
+        let expression = self.metadata().get_expression_by_alias(column)?;
+        NumericBins::parse(&expression)
+    }
+
+    /// The column a `group_by` or `split_by` entry `column` buckets, which is
+    /// `column` itself if it is not a generated bucket.
+    fn get_pivot_source(&self, column: &str) -> String {
+        self.get_date_bucket(column)
+            .map(|x| x.source)
+            .or_else(|| self.get_numeric_bins(column).map(|x| x.source))
+            .unwrap_or_else(|| column.to_owned())
+    }
+
+    /// The type of the column a `group_by` or `split_by` entry `column`
+    /// buckets, e.g. `Type::Datetime` for `"Order Date (month)"`.
+    pub fn get_pivot_source_type(&self, column: &str) -> Option<Type> {
+        let source = self.get_pivot_source(column);
+        self.metadata().get_column_table_type(&source)
+    }
+
+    /// The `(min, max)` of a numeric `column` across the whole `Table`,
+    /// or `None` if it is empty, via a helper `View`.
+    pub async fn get_column_range(&self, column: &str) -> ApiResult<Option<(f64, f64)>> {
+        let helper_config = ViewConfig {
+            columns: vec![Some(column.to_owned())],
+            expressions: self.borrow().config.expressions.clone(),
+            ..ViewConfig::default()
+        };
+
+        let js_config = helper_config.as_jsvalue()?;
+        let table = self.borrow().table.clone().into_apierror()?;
+        let view: View = PerspectiveOwned::new(table.view(&js_config).await?);
+        let range = view.get_min_max(column.into()).await?;
+        Ok(range
+            .get(0)
+            .as_f64()
+            .zip(range.get(1).as_f64())
+            .filter(|(min, max)| min.is_finite() && max.is_finite()))
+    }
+
+    /// Create an update which replaces the (possibly bucketed) `group_by` or
+    /// `split_by` entry at `index` with its unbucketed source column.
+    pub fn create_remove_bucket_update(
+        &self,
+        target: DragTarget,
+        index: usize,
+    ) -> ViewConfigUpdate {
+        self.create_date_bucket_update(target, index, &[])
+    }
+
+    /// Create an update which buckets the `group_by` or `split_by` entry at
+    /// `index` by `units`, replacing any bucket it already has.  Multiple
+    /// units create a hierarchy, e.g. year → month → day.
+    pub fn create_date_bucket_update(
+        &self,
+        target: DragTarget,
+        index: usize,
+        units: &[DateBucketUnit],
+    ) -> ViewConfigUpdate {
+        let Some(column) = self.get_pivot(target, index) else {
+            return ViewConfigUpdate::default();
+        };
+
+        let source = self.get_pivot_source(&column);
+        let buckets = units
+            .iter()
+            .map(|unit| DateBucket::new(&source, *unit).into())
+            .collect();
+
+        self.get_view_config()
+            .create_pivot_bucket_update(target, index, &source, buckets)
+    }
+
+    /// Create an update which bins the numeric `group_by` or `split_by` entry
+    /// at `index` into `count` bins of equal width, spanning the range of its
+    /// values when the update is created.
+    pub async fn create_numeric_bins_update(
+        &self,
+        target: DragTarget,
+        index: usize,
+        count: usize,
+    ) -> ApiResult<ViewConfigUpdate> {
+        let Some(column) = self.get_pivot(target, index) else {
+            return Ok(ViewConfigUpdate::default());
+        };
+
+        let source = self.get_pivot_source(&column);
+        let range = self.get_column_range(&source).await?.unwrap_or_default();
+        let bins = NumericBins::with_count(&source, range, count)?;
+        Ok(self
+            .get_view_config()
+            .create_pivot_bucket_update(target, index, &source, vec![bins.into()]))
+    }
+
+    /// Create an update which replaces the breakpoints of an existing
+    /// `NumericBins` column and its companion order column, e.g. when edited
+    /// from the column settings.
+    pub fn create_replace_numeric_bins_update(&self, bins: &NumericBins) -> ViewConfigUpdate {
+        let Some(old_expression) = self.metadata().get_expression_by_alias(&bins.alias) else {
+            return ViewConfigUpdate::default();
+        };
+
+        let mut update = self.get_view_config().create_replace_expression_update(
+            &bins.alias,
+            &old_expression,
+            &bins.alias,
+            &bins.to_expression(),
+        );
+
+        for expression in update.expressions.iter_mut().flatten() {
+            if NumericBins::parse_order(expression).map(|x| x.order_alias)
+                == Some(bins.order_alias.clone())
+            {
+                *expression = bins.to_order_expression();
+            }
+        }
+
+        update
+    }
+
+    fn get_pivot(&self, target: DragTarget, index: usize) -> Option<String> {
+        let config = self.get_view_config();
+        match target {
+            DragTarget::GroupBy => config.group_by.get(index).cloned(),
+            DragTarget::SplitBy => config.split_by.get(index).cloned(),
+            _ => None,
+        }
+    }
+}
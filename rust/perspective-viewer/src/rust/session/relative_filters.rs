@@ -0,0 +1,202 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::Session;
+use crate::config::*;
+use crate::utils::*;
+
+/// How often relative date filters are re-resolved, in milliseconds.  Resolved
+/// instants are rounded down to a multiple of this, so the resolved filters
+/// (and hence the `View`) only change once per interval.
+pub const RELATIVE_FILTER_INTERVAL_MS: i32 = 60_000;
+
+impl Session {
+    /// The posix time relative date filters are resolved as of, rounded down
+    /// to `RELATIVE_FILTER_INTERVAL_MS`.  This is fixed until the next call to
+    /// `refresh_relative_filters()`, so re-resolving other filters on `Table`
+    /// updates does not move it.
+    fn relative_filter_time(&self) -> f64 {
+        let now = js_sys::Date::now();
+        let rounded = now - now.rem_euclid(RELATIVE_FILTER_INTERVAL_MS as f64);
+        *self
+            .borrow_mut()
+            .relative_filter_time
+            .get_or_insert(rounded)
+    }
+
+    /// Resolve the `RelativeDate` terms of `filter` to absolute posix values,
+    /// as of `relative_filter_time()` in this `Session`'s time zone.  A period
+    /// (e.g. `"today"`) is lowered to range filters, see `period_bounds()`.
+    ///
+    /// # Arguments
+    /// - `filter` The filters to resolve.
+    pub(super) fn resolve_relative_filters(&self, filter: &[Filter]) -> Vec<Filter> {
+        let time_zone = self.get_time_zone();
+        let time_zone = time_zone.as_deref();
+        let now_posix = self.relative_filter_time();
+        let now = posix_to_local(now_posix, time_zone).unwrap();
+        let mut resolved = vec![];
+        for Filter(column, op, term) in filter.iter() {
+            let FilterTerm::Relative(RelativeDateTerm(relative)) = term else {
+                resolved.push(Filter(column.clone(), *op, term.clone()));
+                continue;
+            };
+
+            let is_date = self.metadata().get_column_table_type(column) == Some(Type::Date);
+            let to_term = |posix: f64| {
+                let posix = if is_date {
+                    let date = posix_to_local(posix, time_zone).unwrap().date();
+                    date.and_hms_opt(0, 0, 0).unwrap().timestamp_millis() as f64
+                } else {
+                    posix
+                };
+
+                FilterTerm::Scalar(Scalar::DateTime(posix))
+            };
+
+            let (start, end) = match relative.elapsed() {
+                Some(elapsed) => {
+                    let start = now_posix - elapsed.num_milliseconds() as f64;
+                    (start, relative.is_instant().then_some(start))
+                }
+                None => {
+                    let (start, end) = relative.resolve(now);
+                    let end = end.map(|x| local_to_posix(x, time_zone));
+                    (local_to_posix(start, time_zone), end)
+                }
+            };
+
+            let bounds = if relative.is_instant() {
+                vec![(*op, start)]
+            } else {
+                period_bounds(*op, start, end, now_posix)
+            };
+
+            if bounds.is_empty() {
+                tracing::warn!("Unsupported relative filter \"{}\" \"{}\"", op, relative);
+            }
+
+            for (op, x) in bounds {
+                resolved.push(Filter(column.clone(), op, to_term(x)));
+            }
+        }
+
+        resolved
+    }
+}
+
+/// The range filters a comparison `op` with a period `[start, end)` is
+/// lowered to.  `==` is within the period, `>=` and `<` compare to its start,
+/// and `>` and `<=` to its end, so e.g. `> yesterday` starts at midnight
+/// today.
+///
+/// # Arguments
+/// - `op` The filter's comparison.
+/// - `start` The start of the period.
+/// - `end` The end of the period, or `None` for periods which end `now` (e.g.
+///   `"last 7 days"`).
+/// - `now` The time the period is resolved as of.
+fn period_bounds<T: Copy>(op: FilterOp, start: T, end: Option<T>, now: T) -> Vec<(FilterOp, T)> {
+    let end = end.unwrap_or(now);
+    match op {
+        FilterOp::EQ => vec![(FilterOp::GTE, start), (FilterOp::LT, end)],
+        FilterOp::GTE => vec![(FilterOp::GTE, start)],
+        FilterOp::GT => vec![(FilterOp::GTE, end)],
+        FilterOp::LTE => vec![(FilterOp::LT, end)],
+        FilterOp::LT => vec![(FilterOp::LT, start)],
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    fn datetime(d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    }
+
+    /// The range filters `op` of a `relative` date resolves to, as of noon on
+    /// 2024-03-15.
+    fn resolve(op: FilterOp, relative: &str) -> Vec<(FilterOp, NaiveDateTime)> {
+        let relative = relative.parse::<RelativeDate>().unwrap();
+        let now = datetime(15, 12);
+        let (start, end) = relative.resolve(now);
+        period_bounds(op, start, end, now)
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_period_bounds_today() {
+        let (start, end) = (datetime(15, 0), datetime(16, 0));
+        assert_eq!(resolve(FilterOp::EQ, "today"), vec![
+            (FilterOp::GTE, start),
+            (FilterOp::LT, end)
+        ]);
+
+        assert_eq!(resolve(FilterOp::GTE, "today"), vec![(
+            FilterOp::GTE,
+            start
+        )]);
+        assert_eq!(resolve(FilterOp::GT, "today"), vec![(FilterOp::GTE, end)]);
+        assert_eq!(resolve(FilterOp::LTE, "today"), vec![(FilterOp::LT, end)]);
+        assert_eq!(resolve(FilterOp::LT, "today"), vec![(FilterOp::LT, start)]);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_period_bounds_open_ended() {
+        let (start, now) = (datetime(8, 12), datetime(15, 12));
+        assert_eq!(resolve(FilterOp::EQ, "last 7 days"), vec![
+            (FilterOp::GTE, start),
+            (FilterOp::LT, now)
+        ]);
+
+        assert_eq!(resolve(FilterOp::GTE, "last 7 days"), vec![(
+            FilterOp::GTE,
+            start
+        )]);
+        assert_eq!(resolve(FilterOp::GT, "last 7 days"), vec![(
+            FilterOp::GTE,
+            now
+        )]);
+        assert_eq!(resolve(FilterOp::LTE, "last 7 days"), vec![(
+            FilterOp::LT,
+            now
+        )]);
+        assert_eq!(resolve(FilterOp::LT, "last 7 days"), vec![(
+            FilterOp::LT,
+            start
+        )]);
+
+        let new_year = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        assert_eq!(resolve(FilterOp::EQ, "ytd"), vec![
+            (FilterOp::GTE, new_year),
+            (FilterOp::LT, now)
+        ]);
+
+        assert_eq!(resolve(FilterOp::GT, "ytd"), vec![(FilterOp::GTE, now)]);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_period_bounds_unsupported_op() {
+        assert_eq!(resolve(FilterOp::NE, "today"), vec![]);
+    }
+}
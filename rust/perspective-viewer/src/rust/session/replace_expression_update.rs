@@ -38,7 +38,7 @@ impl ViewConfig {
             sort,
             filter,
             aggregates,
-            ..
+            having,
         } = self.clone();
 
         let expressions = expressions
@@ -55,8 +55,8 @@ impl ViewConfig {
         let aggregates = aggregates
             .into_iter()
             .map(|x| {
-                if x.0 == old_expression {
-                    (expression.to_owned(), x.1)
+                if x.0 == old_alias {
+                    (new_alias.to_owned(), x.1)
                 } else {
                     x
                 }
@@ -105,16 +105,22 @@ impl ViewConfig {
             .collect::<Vec<_>>();
 
         // TODO expression editing can change type, which may invalidate filters
-        let filter = filter
-            .into_iter()
-            .map(|x| {
-                if x.0 == old_alias {
-                    Filter(new_alias.to_owned(), x.1, x.2)
-                } else {
-                    x
+        let rename_filter = |Filter(column, op, mut term): Filter| {
+            if let FilterTerm::TopN(top_n) = &mut term {
+                if top_n.by == old_alias {
+                    top_n.by = new_alias.to_owned();
                 }
-            })
-            .collect::<Vec<_>>();
+            }
+
+            if column == old_alias {
+                Filter(new_alias.to_owned(), op, term)
+            } else {
+                Filter(column, op, term)
+            }
+        };
+
+        let filter = filter.into_iter().map(rename_filter).collect::<Vec<_>>();
+        let having = having.into_iter().map(rename_filter).collect::<Vec<_>>();
 
         ViewConfigUpdate {
             columns: Some(columns),
@@ -124,6 +130,7 @@ impl ViewConfig {
             split_by: Some(split_by),
             sort: Some(sort),
             filter: Some(filter),
+            having: Some(having),
         }
     }
 }
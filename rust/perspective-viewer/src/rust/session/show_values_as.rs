@@ -0,0 +1,80 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+use std::collections::HashMap;
+
+use super::Session;
+use crate::config::*;
+
+impl Session {
+    /// Set the `show_values_as` transform of each column, invalidating the
+    /// current `View` if this changes the transforms compiled into its
+    /// aggregates.  Returns `true` if the `View` must be re-created.
+    ///
+    /// # Arguments
+    /// - `show_values_as` The transform of each column which has one.
+    pub fn set_show_values_as(&self, show_values_as: HashMap<String, ShowValuesAs>) -> bool {
+        let config = self.get_view_config().clone();
+        let old = self.get_engine_show_values_as(&config);
+        self.borrow_mut().show_values_as = show_values_as;
+        if self.get_engine_show_values_as(&config) == old {
+            return false;
+        }
+
+        self.borrow_mut().view_sub = None;
+        self.borrow_mut().is_clean = false;
+        true
+    }
+
+    /// The `show_values_as` transforms the engine does not compute for the
+    /// current `ViewConfig`, which must be applied to the `View`'s output.
+    pub fn get_client_show_values_as(&self) -> HashMap<String, ShowValuesAs> {
+        let config = self.get_view_config().clone();
+        let compiled = self.get_engine_show_values_as(&config);
+        self.borrow()
+            .show_values_as
+            .iter()
+            .filter(|(name, _)| !compiled.contains_key(*name))
+            .map(|(name, transform)| (name.clone(), *transform))
+            .collect()
+    }
+
+    /// The aggregate which computes each column's `show_values_as` transform
+    /// in the engine, for the columns of `config` whose transform it can.
+    ///
+    /// # Arguments
+    /// - `config` The `ViewConfig` the `View` is created from.
+    pub(super) fn get_engine_show_values_as(
+        &self,
+        config: &ViewConfig,
+    ) -> HashMap<String, Aggregate> {
+        if config.group_by.is_empty() {
+            return HashMap::new();
+        }
+
+        let metadata = self.metadata();
+        let has_split_by = !config.split_by.is_empty();
+        self.borrow()
+            .show_values_as
+            .iter()
+            .filter(|(name, _)| config.columns.iter().flatten().any(|x| x == *name))
+            .filter_map(|(name, transform)| {
+                let aggregate = match config.aggregates.get(name) {
+                    Some(aggregate) => aggregate.clone(),
+                    None => metadata.get_column_default_aggregate(name)?,
+                };
+
+                let aggregate = transform.engine_aggregate(&aggregate, has_split_by)?;
+                Some((name.clone(), aggregate))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,180 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use wasm_bindgen::JsCast;
+
+use super::view::{PerspectiveOwned, View};
+use super::Session;
+use crate::config::*;
+use crate::utils::*;
+use crate::*;
+
+impl Session {
+    /// The `filter` of `config` as the engine will apply it, with each "top n"
+    /// filter resolved into an `in` filter of the qualifying values.
+    ///
+    /// # Arguments
+    /// - `config` The `ViewConfig` whose filters to resolve.
+    pub(super) async fn resolve_top_n_filters(
+        &self,
+        config: &ViewConfig,
+    ) -> ApiResult<Vec<Filter>> {
+        let simple_filters = config
+            .filter
+            .iter()
+            .filter(|x| !x.1.is_top_n())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut filters = vec![];
+        for filter in config.filter.iter() {
+            match filter {
+                Filter(column, op, FilterTerm::TopN(top_n)) if op.is_top_n() => {
+                    let values = self
+                        .get_top_n_values(config, &simple_filters, column, *op, top_n)
+                        .await?;
+
+                    filters.push(Filter(
+                        column.clone(),
+                        FilterOp::In,
+                        FilterTerm::Array(values),
+                    ));
+                }
+                Filter(_, op, _) if op.is_top_n() => {}
+                filter => filters.push(filter.clone()),
+            }
+        }
+
+        Ok(filters)
+    }
+
+    /// Calculate the values of `column` which satisfy a "top n" filter, using a
+    /// temporary `View` grouped by `column` and sorted by the `by` column.
+    async fn get_top_n_values(
+        &self,
+        config: &ViewConfig,
+        filter: &[Filter],
+        column: &str,
+        op: FilterOp,
+        top_n: &TopNFilter,
+    ) -> ApiResult<Vec<Scalar>> {
+        let aggregate = {
+            let metadata = self.metadata();
+            top_n
+                .aggregate
+                .clone()
+                .or_else(|| metadata.get_column_default_aggregate(&top_n.by))
+                .map(|x| metadata.get_engine_aggregate(&top_n.by, &x))
+        };
+
+        let sort_dir = match op {
+            FilterOp::BottomN => SortDir::Asc,
+            _ => SortDir::Desc,
+        };
+
+        let helper_config = ViewConfig {
+            group_by: vec![column.to_owned()],
+            columns: vec![Some(top_n.by.clone())],
+            aggregates: aggregate
+                .into_iter()
+                .map(|x| (top_n.by.clone(), x))
+                .collect(),
+            sort: vec![Sort(top_n.by.clone(), sort_dir)],
+            filter: filter.to_vec(),
+            expressions: config.expressions.clone(),
+            ..ViewConfig::default()
+        };
+
+        let js_config = helper_config.as_jsvalue()?;
+        let table = self.borrow().table.clone().into_apierror()?;
+        let view: View = PerspectiveOwned::new(table.view(&js_config).await?);
+
+        // The first row is the total row.
+        let end_row = top_n.n + 1;
+        let window = json!({"start_row": 1, "end_row": end_row});
+        let columns = view.to_columns_with_options(window).await?;
+        let row_paths = js_sys::Reflect::get(&columns, &"__ROW_PATH__".into())?;
+        Ok(row_paths
+            .dyn_into::<js_sys::Array>()
+            .map(|x| x.iter().collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|row_path| {
+                Scalar::from_jsvalue(&row_path.unchecked_into::<js_sys::Array>().get(0))
+            })
+            .filter(|x| *x != Scalar::Null)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::js::get_mock_table;
+
+    fn top_n(op: FilterOp, n: u32) -> Filter {
+        Filter(
+            "A".to_owned(),
+            op,
+            FilterTerm::TopN(TopNFilter {
+                n,
+                by: "A".to_owned(),
+                aggregate: None,
+            }),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_resolve_top_n_filters() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        let config = ViewConfig {
+            filter: vec![top_n(FilterOp::TopN, 2)],
+            ..ViewConfig::default()
+        };
+
+        let filters = session.resolve_top_n_filters(&config).await.unwrap();
+        assert_eq!(filters, vec![Filter(
+            "A".to_owned(),
+            FilterOp::In,
+            FilterTerm::Array(vec![Scalar::Float(3.0), Scalar::Float(2.0)])
+        )]);
+    }
+
+    #[wasm_bindgen_test]
+    pub async fn test_resolve_bottom_n_filters() {
+        let session = Session::default();
+        session.set_table(get_mock_table().await).await.unwrap();
+        let simple = Filter(
+            "A".to_owned(),
+            FilterOp::GT,
+            FilterTerm::Scalar(Scalar::Float(1.0)),
+        );
+
+        let config = ViewConfig {
+            filter: vec![top_n(FilterOp::BottomN, 1), simple.clone()],
+            ..ViewConfig::default()
+        };
+
+        let filters = session.resolve_top_n_filters(&config).await.unwrap();
+        assert_eq!(filters, vec![
+            Filter(
+                "A".to_owned(),
+                FilterOp::In,
+                FilterTerm::Array(vec![Scalar::Float(2.0)])
+            ),
+            simple
+        ]);
+    }
+}
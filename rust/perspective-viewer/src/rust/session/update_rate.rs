@@ -0,0 +1,113 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// The window over which `UpdateRate::updates_per_sec()` is measured.
+pub const UPDATE_RATE_WINDOW_MS: f64 = 5000.0;
+
+/// The arrival times of recent `View` updates, and the change in the `Table`
+/// row count they caused.  Shared by a `Session` and its `ViewSubscription`,
+/// so the rate survives the `View` being re-created for a new config.
+pub type SharedUpdateRate = Rc<RefCell<UpdateRate>>;
+
+#[derive(Default)]
+pub struct UpdateRate {
+    times: VecDeque<f64>,
+    last_update: Option<f64>,
+    num_rows: Option<u32>,
+    rows_changed: Option<i64>,
+    is_dirty: bool,
+}
+
+impl UpdateRate {
+    /// Record an update which arrived at `now`, in milliseconds since the
+    /// epoch.
+    pub fn record(&mut self, now: f64) {
+        self.times.push_back(now);
+        self.last_update = Some(now);
+        self.is_dirty = true;
+        while self
+            .times
+            .front()
+            .map(|x| *x <= now - UPDATE_RATE_WINDOW_MS)
+            .unwrap_or_default()
+        {
+            self.times.pop_front();
+        }
+    }
+
+    /// Record the `Table`'s row count after the updates `record()`-ed since
+    /// the last call, which are credited with the difference.
+    pub fn set_num_rows(&mut self, num_rows: u32) {
+        if let Some(prev) = self.num_rows.replace(num_rows) && self.is_dirty {
+            self.rows_changed = Some(num_rows as i64 - prev as i64);
+        }
+
+        self.is_dirty = false;
+    }
+
+    /// The mean rate of updates over the `UPDATE_RATE_WINDOW_MS` before
+    /// `now`, or `None` if there have been no updates at all.
+    pub fn updates_per_sec(&self, now: f64) -> Option<f64> {
+        self.last_update?;
+        let count = self
+            .times
+            .iter()
+            .filter(|x| **x > now - UPDATE_RATE_WINDOW_MS)
+            .count();
+
+        Some(count as f64 * 1000.0 / UPDATE_RATE_WINDOW_MS)
+    }
+
+    pub fn last_update(&self) -> Option<f64> {
+        self.last_update
+    }
+
+    /// The (signed) change in the `Table`'s row count caused by the most
+    /// recent updates, which is `0` for updates to existing rows of an
+    /// indexed `Table`.
+    pub fn rows_changed(&self) -> Option<i64> {
+        self.rows_changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_update_rate() {
+        let mut rate = UpdateRate::default();
+        rate.set_num_rows(10);
+        assert_eq!(rate.updates_per_sec(0.0), None);
+        assert_eq!(rate.rows_changed(), None);
+        for i in 0..10 {
+            rate.record(1000.0 + i as f64 * 100.0);
+        }
+
+        rate.set_num_rows(15);
+        assert_eq!(rate.updates_per_sec(2000.0), Some(2.0));
+        assert_eq!(rate.updates_per_sec(10000.0), Some(0.0));
+        assert_eq!(rate.last_update(), Some(1900.0));
+        assert_eq!(rate.rows_changed(), Some(5));
+        rate.set_num_rows(20);
+        assert_eq!(rate.rows_changed(), Some(5));
+        rate.record(2000.0);
+        rate.set_num_rows(12);
+        assert_eq!(rate.rows_changed(), Some(-8));
+    }
+}
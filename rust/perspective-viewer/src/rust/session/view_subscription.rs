@@ -10,10 +10,14 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::cell::Cell;
+use std::rc::Rc;
+
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
+use super::update_rate::*;
 use super::view::*;
 use crate::config::*;
 use crate::js::perspective::*;
@@ -22,21 +26,45 @@ use crate::*;
 
 /// Metadata snapshot of the current `Table()`/`View()` state which may be of
 /// interest to components.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ViewStats {
     pub is_group_by: bool,
     pub is_split_by: bool,
     pub is_filtered: bool,
     pub num_table_cells: Option<(u32, u32)>,
     pub num_view_cells: Option<(u32, u32)>,
+
+    /// Updates per second, as of the last update.
+    pub updates_per_sec: Option<f64>,
+
+    /// When the last update arrived, in milliseconds since the epoch.
+    pub last_update: Option<f64>,
+
+    /// The change in the `Table()`'s row count caused by the updates since
+    /// the previous stats, which is `0` for updates to existing rows of an
+    /// indexed `Table()`.
+    pub rows_changed: Option<i64>,
+
+    /// When the `View()` these stats describe was created, in milliseconds
+    /// since the epoch.
+    pub view_created: Option<f64>,
 }
 
+/// The number of `View` updates which have arrived since updates were paused,
+/// or `None` if they are not paused.  Shared by a `Session` and its
+/// `ViewSubscription`.
+pub type PendingUpdates = Rc<Cell<Option<u32>>>;
+
 #[derive(Clone)]
 struct ViewSubscriptionData {
     view: View,
     config: ViewConfig,
+    pending: PendingUpdates,
+    update_rate: SharedUpdateRate,
     on_stats: Callback<ViewStats>,
     on_update: Callback<()>,
+    on_pending: Callback<u32>,
+    created: f64,
 }
 
 /// A subscription to `on_update()` events from a Perspective `View()`, managing
@@ -47,8 +75,21 @@ pub struct ViewSubscription {
 }
 
 impl ViewSubscriptionData {
-    /// Main handler when underlying `View()` calls `on_update()`.
+    /// Main handler when underlying `View()` calls `on_update()`.  While
+    /// updates are paused, they are only counted, so neither the plugin nor
+    /// the stats change until they are resumed.
     async fn on_view_update(self) -> ApiResult<JsValue> {
+        self.update_rate.borrow_mut().record(js_sys::Date::now());
+        if let Some(count) = self.pending.get() {
+            self.pending.set(Some(count + 1));
+            self.on_pending.emit(count + 1);
+            return Ok(JsValue::UNDEFINED);
+        }
+
+        self.render_update().await
+    }
+
+    async fn render_update(self) -> ApiResult<JsValue> {
         self.on_update.emit(());
         self.clone().update_view_stats().await?;
         Ok(JsValue::UNDEFINED)
@@ -62,14 +103,22 @@ impl ViewSubscriptionData {
         let num_cols = dimensions.num_table_columns() as u32;
         let virtual_rows = dimensions.num_view_rows() as u32;
         let virtual_cols = dimensions.num_view_columns() as u32;
+        let mut update_rate = self.update_rate.borrow_mut();
+        update_rate.set_num_rows(num_rows);
         let stats = ViewStats {
             num_table_cells: Some((num_rows, num_cols)),
             num_view_cells: Some((virtual_rows, virtual_cols)),
             is_filtered: virtual_rows != num_rows,
             is_group_by: !self.config.group_by.is_empty(),
             is_split_by: !self.config.split_by.is_empty(),
+            updates_per_sec: update_rate.updates_per_sec(js_sys::Date::now()),
+            last_update: update_rate.last_update(),
+            rows_changed: update_rate.rows_changed(),
+            view_created: Some(self.created),
         };
 
+        drop(update_rate);
+
         self.on_stats.emit(stats);
         Ok(JsValue::UNDEFINED)
     }
@@ -84,19 +133,32 @@ impl ViewSubscription {
     /// # Arguments
     /// * `table` - a Perspective `Table()`
     /// * `view` - a Perspective `View()` on this `table`.
+    /// * `pending` - whether updates are paused, and the count of updates
+    ///   since.
+    /// * `update_rate` - the arrival times of updates, for `ViewStats`.
     /// * `on_stats` - a callback for metadata notifications, from Perspective's
     ///   `View.on_update()`.
+    /// * `on_update` - a callback for each update, unless paused.
+    /// * `on_pending` - a callback for each update while paused, with the count
+    ///   of updates since pausing.
     pub fn new(
         view: JsPerspectiveView,
         config: ViewConfig,
+        pending: PendingUpdates,
+        update_rate: SharedUpdateRate,
         on_stats: Callback<ViewStats>,
         on_update: Callback<()>,
+        on_pending: Callback<u32>,
     ) -> Self {
         let data = ViewSubscriptionData {
             view: View::new(view),
             config,
+            pending,
+            update_rate,
             on_stats,
             on_update,
+            on_pending,
+            created: js_sys::Date::now(),
         };
 
         let fun = {
@@ -110,6 +172,12 @@ impl ViewSubscription {
         Self { data, closure }
     }
 
+    /// Render the latest state of the underlying `View()`, e.g. after updates
+    /// arrived while paused.
+    pub fn refresh(&self) {
+        ApiFuture::spawn(self.data.clone().render_update());
+    }
+
     /// Getter for the underlying `View()`.
     pub const fn get_view(&self) -> &View {
         &self.data.view
@@ -12,7 +12,9 @@
 
 mod blob;
 mod download;
+mod event_listener;
 mod focus;
+mod interval;
 mod request_animation_frame;
 mod selection;
 
@@ -21,7 +23,9 @@ mod tests;
 
 pub use blob::*;
 pub use download::*;
+pub use event_listener::*;
 pub use focus::*;
+pub use interval::*;
 pub use request_animation_frame::*;
 pub use selection::*;
 
@@ -51,4 +55,10 @@ pub mod global {
     pub fn clipboard() -> web_sys::Clipboard {
         navigator().clipboard().unwrap()
     }
+
+    /// `localStorage` may be unavailable (e.g. in a sandboxed `<iframe>`) or
+    /// disabled by the user.
+    pub fn local_storage() -> Option<web_sys::Storage> {
+        window().local_storage().ok().flatten()
+    }
 }
@@ -10,10 +10,55 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+//! Conversions between posix timestamps and wall clock date times in an IANA
+//! time zone.
+//!
+//! Time zone rules come from the browser's `Intl.DateTimeFormat`, as
+//! `chrono-tz` is not available to this crate's (offline, vendored) build,
+//! which pins `chrono` 0.4.19.  `Intl` only converts posix to wall clock
+//! time, so the reverse conversion in `local_to_posix()` is solved from the
+//! offsets on either side of the instant.  `formatToParts()` is slow, so
+//! offsets are cached per hour, see `time_zone_offset()`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
 use wasm_bindgen::prelude::*;
 
 use crate::utils::*;
+use crate::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `Intl.DateTimeFormat` may be called as a function, which (unlike
+    /// `js_sys`'s constructor binding) lets a `RangeError` be caught.
+    #[wasm_bindgen(catch, js_namespace = Intl, js_name = DateTimeFormat)]
+    fn try_date_time_format(
+        locales: &JsValue,
+        options: &JsValue,
+    ) -> Result<js_sys::Intl::DateTimeFormat, JsValue>;
+}
+
+/// The span of the time zone offsets cached by `time_zone_offset()`.
+const OFFSET_CACHE_MS: f64 = 3_600_000_f64;
+
+/// The number of cached time zone offsets beyond which the cache is cleared.
+const MAX_CACHED_OFFSETS: usize = 4096;
+
+thread_local! {
+    /// `Intl.DateTimeFormat` instances are expensive to construct, so one is
+    /// cached per time zone.
+    static TIME_ZONE_FORMATS: RefCell<HashMap<String, js_sys::Intl::DateTimeFormat>> =
+        RefCell::new(HashMap::default());
+
+    /// The offset of each time zone by hour since the epoch, for hours which
+    /// contain no transition.
+    static TIME_ZONE_OFFSETS: RefCell<HashMap<(String, i64), f64>> =
+        RefCell::new(HashMap::default());
+
+    static BROWSER_TIME_ZONE: RefCell<Option<String>> = RefCell::new(None);
+}
 
 fn input_value_format(x: &str) -> Result<&str, JsValue> {
     match x.len() {
@@ -24,18 +69,159 @@ fn input_value_format(x: &str) -> Result<&str, JsValue> {
     }
 }
 
-fn get_local_tz() -> FixedOffset {
-    FixedOffset::west_opt(js_sys::Date::new(&0.into()).get_timezone_offset() as i32 * 60).unwrap()
+/// An `Intl.DateTimeFormat` which formats the numeric wall clock fields of an
+/// instant in the IANA time zone `tz`.  `Intl` throws a `RangeError` for
+/// unknown time zones, which is returned as an error.
+fn time_zone_format(tz: &str) -> ApiResult<js_sys::Intl::DateTimeFormat> {
+    if let Some(format) = TIME_ZONE_FORMATS.with(|x| x.borrow().get(tz).cloned()) {
+        return Ok(format);
+    }
+
+    let options = json!({
+        "timeZone": tz,
+        "hourCycle": "h23",
+        "year": "numeric",
+        "month": "numeric",
+        "day": "numeric",
+        "hour": "numeric",
+        "minute": "numeric",
+        "second": "numeric"
+    });
+
+    let format = try_date_time_format(&json!(["en-US"]), &options)
+        .map_err(|_| format!("Unknown time zone \"{}\"", tz))?;
+
+    TIME_ZONE_FORMATS.with(|x| x.borrow_mut().insert(tz.to_owned(), format.clone()));
+    Ok(format)
+}
+
+/// Validate an IANA time zone name (e.g. `"America/New_York"`).
+pub fn validate_time_zone(tz: &str) -> ApiResult<()> {
+    time_zone_format(tz).map(|_| ())
 }
 
-pub fn posix_to_utc_str(x: f64) -> ApiResult<String> {
-    let tz = get_local_tz();
+/// The browser's IANA time zone name, which is read once per page.
+pub fn browser_time_zone() -> String {
+    BROWSER_TIME_ZONE.with(|cache| {
+        cache
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                js_sys::Reflect::get(
+                    &js_sys::Intl::DateTimeFormat::new(&json!([]), &json!({})).resolved_options(),
+                    &JsValue::from("timeZone"),
+                )
+                .ok()
+                .and_then(|x| x.as_string())
+                .unwrap_or_else(|| "UTC".to_owned())
+            })
+            .clone()
+    })
+}
+
+/// The offset in milliseconds from UTC of the wall clock in `tz` (or the
+/// browser's time zone if `None`) at the posix instant `posix`.  Unlike a
+/// fixed offset, this accounts for DST and historical changes to `tz`.
+///
+/// The offset of an hour is cached when it is the same at either end of the
+/// hour, so most conversions need no call to `formatToParts()`.
+fn time_zone_offset(tz: Option<&str>, posix: f64) -> f64 {
+    let Some(tz) = tz else {
+        let date = js_sys::Date::new(&posix.into());
+        return -date.get_timezone_offset() * 60_000_f64;
+    };
+
+    let hour = (posix / OFFSET_CACHE_MS).floor();
+    let key = (tz.to_owned(), hour as i64);
+    if let Some(offset) = TIME_ZONE_OFFSETS.with(|x| x.borrow().get(&key).copied()) {
+        return offset;
+    }
+
+    let start = hour * OFFSET_CACHE_MS;
+    let offset = format_time_zone_offset(tz, start);
+    if format_time_zone_offset(tz, start + OFFSET_CACHE_MS - 1_000_f64) != offset {
+        return format_time_zone_offset(tz, posix);
+    }
+
+    TIME_ZONE_OFFSETS.with(|x| {
+        let mut offsets = x.borrow_mut();
+        if offsets.len() >= MAX_CACHED_OFFSETS {
+            offsets.clear();
+        }
+
+        offsets.insert(key, offset);
+    });
+
+    offset
+}
+
+/// The offset in milliseconds from UTC of the wall clock in `tz` at the posix
+/// instant `posix`, as formatted by `Intl`.
+fn format_time_zone_offset(tz: &str, posix: f64) -> f64 {
+    let date = js_sys::Date::new(&posix.into());
+    let Ok(format) = time_zone_format(tz) else {
+        return -date.get_timezone_offset() * 60_000_f64;
+    };
+
+    let mut fields = [0_u32; 6];
+    for part in format.format_to_parts(&date).iter() {
+        let field = js_sys::Reflect::get(&part, &"type".into()).ok();
+        let value = js_sys::Reflect::get(&part, &"value".into()).ok();
+        let idx = match field.and_then(|x| x.as_string()).as_deref() {
+            Some("year") => 0,
+            Some("month") => 1,
+            Some("day") => 2,
+            Some("hour") => 3,
+            Some("minute") => 4,
+            Some("second") => 5,
+            _ => continue,
+        };
+
+        fields[idx] = value
+            .and_then(|x| x.as_string())
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_default();
+    }
+
+    let [year, month, day, hour, minute, second] = fields;
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+        .and_then(|x| x.and_hms_opt(hour % 24, minute, second))
+        .map(|wall| wall.timestamp_millis() as f64 - (posix / 1000_f64).floor() * 1000_f64)
+        .unwrap_or_default()
+}
+
+/// Convert a posix timestamp in milliseconds to the wall clock date time in
+/// `tz`, or the browser's time zone if `None`.
+pub fn posix_to_local(posix: f64, tz: Option<&str>) -> Option<NaiveDateTime> {
+    let local = (posix + time_zone_offset(tz, posix)) as i64;
+    NaiveDateTime::from_timestamp_opt(
+        local.div_euclid(1000),
+        (local.rem_euclid(1000) * 1_000_000) as u32,
+    )
+}
+
+/// Convert a wall clock date time in `tz` (or the browser's time zone if
+/// `None`) to a posix timestamp in milliseconds.  Wall times skipped by a DST
+/// transition resolve to after the transition (shifted forward by the length
+/// of the gap), and wall times repeated by one resolve to the earlier
+/// instant.
+pub fn local_to_posix(x: NaiveDateTime, tz: Option<&str>) -> f64 {
+    const DAY_MS: f64 = 86_400_000_f64;
+    let naive = x.timestamp_millis() as f64;
+    let before = naive - time_zone_offset(tz, naive - DAY_MS);
+    let after = naive - time_zone_offset(tz, naive + DAY_MS);
+    let is_valid = |posix: f64| posix + time_zone_offset(tz, posix) == naive;
+    match (is_valid(before), is_valid(after)) {
+        (true, true) => before.min(after),
+        (true, false) => before,
+        (false, true) => after,
+        (false, false) => before,
+    }
+}
+
+pub fn posix_to_utc_str(x: f64, tz: Option<&str>) -> ApiResult<String> {
     if x > 0_f64 {
-        Ok(Utc
-            .timestamp_opt(x as i64 / 1000, ((x as i64 % 1000) * 1000000) as u32)
-            .earliest()
+        Ok(posix_to_local(x, tz)
             .into_apierror()?
-            .with_timezone(&tz)
             .format("%Y-%m-%dT%H:%M:%S%.3f")
             .to_string())
     } else {
@@ -43,8 +229,123 @@ pub fn posix_to_utc_str(x: f64) -> ApiResult<String> {
     }
 }
 
-pub fn str_to_utc_posix(val: &str) -> Result<f64, ApiError> {
-    let tz = get_local_tz();
+pub fn str_to_utc_posix(val: &str, tz: Option<&str>) -> Result<f64, ApiError> {
     let posix = NaiveDateTime::parse_from_str(val, input_value_format(val)?)?;
-    Ok(DateTime::<Utc>::from(tz.from_local_datetime(&posix).unwrap()).timestamp_millis() as f64)
+    Ok(local_to_posix(posix, tz))
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    const NEW_YORK: Option<&str> = Some("America/New_York");
+
+    fn datetime(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> f64 {
+        datetime(y, m, d, h, min).timestamp_millis() as f64
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_local_to_posix_dst_gap() {
+        // 2023-03-12 02:00 EST is followed by 03:00 EDT.
+        let posix = local_to_posix(datetime(2023, 3, 12, 2, 30), NEW_YORK);
+        assert_eq!(posix, utc(2023, 3, 12, 7, 30));
+        assert_eq!(
+            posix_to_local(posix, NEW_YORK),
+            Some(datetime(2023, 3, 12, 3, 30))
+        );
+
+        let posix = local_to_posix(datetime(2023, 3, 12, 1, 59), NEW_YORK);
+        assert_eq!(posix, utc(2023, 3, 12, 6, 59));
+        let posix = local_to_posix(datetime(2023, 3, 12, 3, 0), NEW_YORK);
+        assert_eq!(posix, utc(2023, 3, 12, 7, 0));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_local_to_posix_dst_overlap() {
+        // 2023-11-05 01:00 - 02:00 occurs twice, in EDT and then EST.
+        assert_eq!(
+            posix_to_local(utc(2023, 11, 5, 5, 30), NEW_YORK),
+            Some(datetime(2023, 11, 5, 1, 30))
+        );
+
+        assert_eq!(
+            posix_to_local(utc(2023, 11, 5, 6, 30), NEW_YORK),
+            Some(datetime(2023, 11, 5, 1, 30))
+        );
+
+        let posix = local_to_posix(datetime(2023, 11, 5, 1, 30), NEW_YORK);
+        assert_eq!(posix, utc(2023, 11, 5, 5, 30));
+        let posix = local_to_posix(datetime(2023, 11, 5, 2, 0), NEW_YORK);
+        assert_eq!(posix, utc(2023, 11, 5, 7, 0));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_posix_round_trip_across_dst() {
+        for tz in [NEW_YORK, Some("Europe/London"), Some("Australia/Lord_Howe")] {
+            for (start, end) in [
+                (utc(2023, 3, 11, 0, 0), utc(2023, 3, 14, 0, 0)),
+                (utc(2023, 3, 25, 0, 0), utc(2023, 4, 4, 0, 0)),
+                (utc(2023, 9, 30, 0, 0), utc(2023, 10, 31, 0, 0)),
+                (utc(2023, 11, 4, 0, 0), utc(2023, 11, 7, 0, 0)),
+            ] {
+                let mut posix = start;
+                while posix < end {
+                    let local = posix_to_local(posix, tz).unwrap();
+                    let round_trip = local_to_posix(local, tz);
+
+                    // Only the second occurrence of a repeated wall time
+                    // does not round trip, and it resolves to the first.
+                    if round_trip != posix {
+                        assert!(round_trip < posix, "{:?} {}", tz, local);
+                        assert_eq!(posix_to_local(round_trip, tz), Some(local));
+                    }
+
+                    posix += 15.0 * 60_000_f64;
+                }
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_local_round_trip_across_dst() {
+        let mut local = datetime(2023, 3, 11, 0, 0);
+        while local < datetime(2023, 3, 14, 0, 0) {
+            let round_trip = posix_to_local(local_to_posix(local, NEW_YORK), NEW_YORK);
+            if local.date() == NaiveDate::from_ymd_opt(2023, 3, 12).unwrap()
+                && (2..3).contains(&chrono::Timelike::hour(&local))
+            {
+                assert_eq!(round_trip, Some(local + chrono::Duration::hours(1)));
+            } else {
+                assert_eq!(round_trip, Some(local));
+            }
+
+            local += chrono::Duration::minutes(15);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_cached_offsets_match_intl() {
+        // Lord Howe's transitions fall on the half hour in UTC.
+        for tz in ["America/New_York", "Australia/Lord_Howe"] {
+            let mut posix = utc(2023, 3, 31, 0, 0);
+            while posix < utc(2023, 4, 3, 0, 0) {
+                time_zone_offset(Some(tz), posix);
+                assert_eq!(
+                    time_zone_offset(Some(tz), posix),
+                    format_time_zone_offset(tz, posix)
+                );
+
+                posix += 15.0 * 60_000_f64;
+            }
+        }
+    }
 }
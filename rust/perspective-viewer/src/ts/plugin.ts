@@ -93,6 +93,23 @@ export interface IPerspectiveViewerPlugin {
      */
     get priority(): number | undefined;
 
+    /**
+     * The default column style values for each column type, keyed by type
+     * name.  Defining this property opts this plugin into column styles:
+     * `<perspective-viewer>` owns the styles (persisted in the `column_config`
+     * field of its `ViewerConfig`), and sends them to the plugin as the
+     * `columns` field of the token passed to `restore()`.
+     */
+    readonly default_config?: Record<string, any>;
+
+    /**
+     * Whether this plugin renders column attributes (display labels,
+     * descriptions and units).  If `true`, `<perspective-viewer>` sends them
+     * to the plugin as `label`, `description` and `units` keys of the
+     * `columns` field of the token passed to `restore()`.
+     */
+    readonly supports_column_attributes?: boolean;
+
     /**
      * Render this plugin using the provided `View`.  While there is no
      * provision to cancel a render in progress per se, calling a method on
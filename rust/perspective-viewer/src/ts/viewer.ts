@@ -15,7 +15,59 @@ import type * as perspective from "@finos/perspective";
 export type PerspectiveViewerConfig = perspective.ViewConfig & {
     plugin?: string;
     settings?: boolean;
+    time_zone?: string;
     plugin_config?: any;
+    column_config?: Record<string, any>;
+    having?: Array<perspective.Filter>;
+    drill_down?: Array<{
+        row_path: Array<string | number | boolean | null>;
+        group_by: Array<string>;
+        filter: Array<perspective.Filter>;
+    }>;
+    column_attributes?: Record<
+        string,
+        {
+            label?: string;
+            description?: string;
+            units?: string;
+            show_values_as?: ShowValuesAs;
+        }
+    >;
+};
+
+export type ShowValuesAs =
+    | "pct of row"
+    | "pct of column"
+    | "pct of grand total"
+    | "pct of parent"
+    | "running total"
+    | "rank"
+    | "difference"
+    | "pct change";
+
+export type RenderHistogram = {
+    count: number;
+    total_ms: number;
+    max_ms: number;
+    buckets: Array<number>;
+};
+
+export type RenderStats = {
+    view: RenderHistogram;
+    draw: RenderHistogram;
+    update: RenderHistogram;
+    resize: RenderHistogram;
+    debounced_updates: number;
+    dropped_updates: number;
+    plugins: Record<
+        string,
+        {
+            draw: RenderHistogram;
+            update: RenderHistogram;
+            resize: RenderHistogram;
+        }
+    >;
+    bucket_bounds_ms: Array<number>;
 };
 
 /**
@@ -308,6 +360,259 @@ export interface IPerspectiveViewerElement {
      */
     restyleElement(): Promise<void>;
 
+    /**
+     * Get the style of a column, as persisted in the `column_config` field of
+     * this viewer's `ViewerConfig`.
+     *
+     * @category Style
+     * @param name The column name.
+     * @returns The column's style record, or `null` if it is unstyled.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * const style = viewer.getColumnStyle("Sales");
+     * ```
+     */
+    getColumnStyle(name: string): Record<string, any> | null;
+
+    /**
+     * Set the style of a column, replacing its current style, and restyle.
+     * The style is validated against the column's type (its `View` type if
+     * the column is in the current `View`), and this method rejects on an
+     * unknown column, an unknown style key, or an invalid value.
+     *
+     * @category Style
+     * @param name The column name.
+     * @param style The style record, e.g. `{number_fg_mode: "bar"}`.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * await viewer.setColumnStyle("Sales", {
+     *     number_fg_mode: "bar",
+     *     fg_gradient: 1000,
+     * });
+     * ```
+     */
+    setColumnStyle(name: string, style: Record<string, any>): Promise<void>;
+
+    /**
+     * Clear the style of a column and restyle.
+     *
+     * @category Style
+     * @param name The column name.
+     */
+    resetColumnStyle(name: string): Promise<void>;
+
+    /**
+     * Set the style of every column in the current `View` of a type, and
+     * restyle.
+     *
+     * @category Style
+     * @param type The column type, one of `"integer"`, `"float"`, `"string"`,
+     * `"date"`, `"datetime"` or `"boolean"`.
+     * @param style The style record, which must be valid for `type`.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * await viewer.applyStyleToType("float", {fixed: 4});
+     * ```
+     */
+    applyStyleToType(type: string, style: Record<string, any>): Promise<void>;
+
+    /**
+     * Get the named column style presets, which can be saved and applied from
+     * the column settings sidebar.  Presets are persisted to `localStorage`
+     * when it is available, and shared by every `<perspective-viewer>` on the
+     * page.
+     *
+     * @category Style
+     * @returns An array of `{name, type, style}` preset records, suitable for
+     * `saveColumnStylePresets()`.
+     */
+    getColumnStylePresets(): Array<{
+        name: string;
+        type: string;
+        style: Record<string, any>;
+    }>;
+
+    /**
+     * Add named column style presets, replacing any existing presets of the
+     * same name.  Each preset's `style` is validated against its `type`, and
+     * no presets are added if any are invalid.
+     *
+     * @category Style
+     * @param presets An array of `{name, type, style}` preset records.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * viewer.saveColumnStylePresets([
+     *     {name: "PnL", type: "float", style: {fixed: 2, number_fg_mode: "bar"}},
+     * ]);
+     * ```
+     */
+    saveColumnStylePresets(
+        presets: Array<{
+            name: string;
+            type: string;
+            style: Record<string, any>;
+        }>
+    ): void;
+
+    /**
+     * Delete a named column style preset.
+     *
+     * @category Style
+     * @param name The name of the preset to delete.
+     * @returns Whether a preset named `name` existed.
+     */
+    deleteColumnStylePreset(name: string): boolean;
+
+    /**
+     * Drill into a grouped row, filtering this viewer to the row's group and
+     * grouping by the next `group_by` column.  The previous `group_by` and
+     * `filter` are kept on the drill-down stack, and shown as breadcrumbs
+     * which can be clicked to return to a previous level.
+     *
+     * @category Util
+     * @param row_path The row's `__ROW_PATH__`, as in the detail of a
+     * `"perspective-click"` event.
+     * @returns A promise which resolves to `false` if the row could not be
+     * drilled into, e.g. because it is a leaf row.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * await viewer.drillDown(["East"]);
+     * ```
+     */
+    drillDown(row_path: Array<string | number | boolean | null>): Promise<boolean>;
+
+    /**
+     * Return to a previous drill-down level, restoring its `group_by` and
+     * `filter`.
+     *
+     * @category Util
+     * @param depth The level to return to, where `0` (the default) is the
+     * view before any drill-down.
+     */
+    drillUp(depth?: number): Promise<void>;
+
+    /**
+     * Open a modal of the underlying rows of an aggregated cell, i.e. the rows
+     * of the `Table` within the cell's `group_by` and `split_by` groups (and
+     * this viewer's `filter`), with all of the `Table`'s columns.  The rows
+     * can be exported or copied as CSV from the modal.
+     *
+     * @category Util
+     * @param row_path The cell's `__ROW_PATH__`, or `[]` for the total row.
+     * @param column_path The cell's `split_by` values, if any.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * await viewer.drillThrough(["East", "Furniture"], ["2023"]);
+     * ```
+     */
+    drillThrough(
+        row_path: Array<string | number | boolean | null>,
+        column_path?: Array<string | number | boolean | null>
+    ): Promise<void>;
+
+    /**
+     * Join a link group, to cross-filter with the other `<perspective-viewer>`s
+     * on the page which have joined it, leaving any link group this viewer
+     * has already joined.  Cells clicked in a member (when its status bar
+     * click action is "Select") are published to the other members, which
+     * apply the cell's row and column groups on top of their own `filter`
+     * (these are not part of `save()`).  Clicking the same cell again clears
+     * the selection.  A
+     * `"perspective-link-filters-change"` event is dispatched when the filters
+     * applied by the other members change.
+     *
+     * @category Util
+     * @param name The name of the link group.
+     * @param column_map A map of the other members' column names to this
+     * viewer's, for columns which are not named the same.  Filters on columns
+     * this viewer does not have are ignored.
+     * @example
+     * ```javascript
+     * await orders.joinLinkGroup("regions");
+     * await returns.joinLinkGroup("regions", {Region: "Sales Region"});
+     * ```
+     */
+    joinLinkGroup(
+        name: string,
+        column_map?: Record<string, string>
+    ): Promise<void>;
+
+    /**
+     * Leave this viewer's link group, removing the filters applied by the
+     * other members.
+     *
+     * @category Util
+     */
+    leaveLinkGroup(): Promise<void>;
+
+    /**
+     * Get the name of this viewer's link group.
+     *
+     * @category Util
+     * @returns The link group's name, or `undefined` if this viewer has not
+     * joined one.
+     */
+    getLinkGroup(): string | undefined;
+
+    /**
+     * Pause rendering `Table` updates, freezing the plugin (and the status
+     * bar's row counts) while the data is read.  The status bar shows how
+     * many updates have arrived since pausing.
+     *
+     * @category Util
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * viewer.pause();
+     * ```
+     */
+    pause(): void;
+
+    /**
+     * Resume rendering `Table` updates after `pause()`, rendering the latest
+     * state of the `View` once if any updates arrived while paused.
+     *
+     * @category Util
+     */
+    resume(): void;
+
+    /**
+     * Whether `Table` updates are paused by `pause()` (or the status bar).
+     *
+     * @category Util
+     */
+    isPaused(): boolean;
+
+    /**
+     * Highlight this `<perspective-viewer>` (via the `stale` attribute) when
+     * its `Table` has not updated for `seconds`, e.g. to spot a dead feed.
+     * The status bar shows the update rate and the age of the last update
+     * regardless.
+     *
+     * @category Util
+     * @param seconds The stale threshold, or `undefined` to never highlight
+     * the viewer.
+     * @example
+     * ```javascript
+     * const viewer = document.querySelector("perspective-viewer");
+     * viewer.setStaleThreshold(30);
+     * ```
+     */
+    setStaleThreshold(seconds?: number): void;
+
+    /**
+     * The stale threshold set by `setStaleThreshold()`, in seconds.
+     *
+     * @category Util
+     */
+    getStaleThreshold(): number | undefined;
+
     /**
      * Sets the theme names available via the `<perspective-viewer>` status bar
      * UI.  Typically these will be auto-detected simply by including the
@@ -374,6 +679,44 @@ export interface IPerspectiveViewerElement {
      */
     setThrottle(value?: number): void;
 
+    /**
+     * Get the render timing statistics of this `<perspective-viewer>`, for
+     * diagnosing slow dashboards.  Each of `view` (creation), `draw`,
+     * `update` and `resize` is a histogram of durations in milliseconds, whose
+     * `buckets` are bounded by `bucket_bounds_ms` (the last bucket is
+     * unbounded).  `plugins` has the same `draw`, `update` and `resize`
+     * histograms per plugin name.  Each timing is also recorded as a
+     * `performance.measure()` entry named e.g. `"perspective-viewer:draw:12"`
+     * (the suffix is unique to the entry), of which each viewer keeps its
+     * most recent 100.
+     *
+     * @category Util
+     * @example
+     * ```javascript
+     * const stats = viewer.getRenderStats();
+     * console.log(stats.update.max_ms, stats.debounced_updates);
+     * ```
+     */
+    getRenderStats(): RenderStats;
+
+    /**
+     * Clear the render timing statistics returned by `getRenderStats()`.
+     *
+     * @category Util
+     */
+    resetRenderStats(): void;
+
+    /**
+     * Toggle (or force) a debug overlay of the `getRenderStats()` timings
+     * over the plugin.
+     *
+     * @category Util
+     * @param force Show (`true`) or hide (`false`) the overlay, or toggle it
+     * if `undefined`.
+     * @returns Whether the overlay is now shown.
+     */
+    toggleRenderStatsOverlay(force?: boolean): boolean;
+
     /**
      * Opens/closes the element's config menu, equivalent to clicking the
      * settings button in the UI.  This method is equivalent to